@@ -11,10 +11,12 @@ use erp_backend::core::configure::AppConfig;
 use erp_backend::core::error::AppResult;
 use erp_backend::infrastructure::middleware::map_response::handler_404;
 use erp_backend::infrastructure::persistence::postgres::migrate_database;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tower::ServiceBuilder;
 use tower_http::cors::CorsLayer;
+use tower_http::request_id::MakeRequestUuid;
 use tower_http::timeout::TimeoutLayer;
 use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer};
 use tower_http::ServiceBuilderExt;
@@ -41,6 +43,8 @@ impl AppServer {
         let sensitive_headers: Arc<[_]> = vec![header::AUTHORIZATION, header::COOKIE].into();
 
         let middleware = ServiceBuilder::new()
+            .set_x_request_id(MakeRequestUuid)
+            .propagate_x_request_id()
             .sensitive_request_headers(sensitive_headers.clone())
             .layer(
                 TraceLayer::new_for_http()
@@ -64,13 +68,13 @@ impl AppServer {
 
         let app = Router::new()
             .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
-            .merge(build_routes())
+            .merge(build_routes(&self.state))
             .layer(CorsLayer::new())
             .layer(middleware)
             .fallback(handler_404)
             .with_state(self.state);
 
-        axum::serve(self.tcp, app).await?;
+        axum::serve(self.tcp, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
         Ok(())
     }
 }
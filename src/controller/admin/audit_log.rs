@@ -0,0 +1,44 @@
+use crate::core::app_state::AppState;
+use crate::core::error::AppResult;
+use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::audit_log::request::AuditLogQueryParam;
+use crate::domain::audit_log::response::AuditLogResponse;
+use crate::domain::audit_log::services::service_get_list_audit_log;
+use crate::util::claim::UserClaims;
+use crate::util::filter_and_pagination::PageQueryParam;
+use axum::extract::State;
+use axum::Json;
+use axum_extra::extract::Query;
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/audit_log",
+    params(AuditLogQueryParam, PageQueryParam),
+    tags = ["audit_log_service"],
+    responses(
+        (status = 200, description = "Success get list of audit logs", body = [EntityResponse<Vec<AuditLogResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_audit_log(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(query): Query<AuditLogQueryParam>,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<AuditLogResponse>>>> {
+    tracing::info!(
+        "Get list of audit logs by user: {}, query: {query:?}, parameter: {param:?}.",
+        claims.uuid
+    );
+    match service_get_list_audit_log(&state, query, param).await {
+        Ok(results) => Ok(Json(EntityResponse::paginated("Get all audit logs success!", results))),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of audit logs: {err:?}.");
+            Err(err)
+        },
+    }
+}
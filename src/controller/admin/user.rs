@@ -1,12 +1,18 @@
 use crate::core::app_state::AppState;
 use crate::core::error::AppResult;
 use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::audit_log::AuditContext;
 use crate::domain::user::request::AdminCreateAccountRequest;
 use crate::domain::user::response::PublicProfileResponse;
-use crate::domain::user::services::{service_admin_create_account, service_admin_get_list};
+use crate::domain::user::services::{
+    service_admin_create_account, service_admin_export_users, service_admin_get_list,
+};
 use crate::util::claim::UserClaims;
+use crate::util::export::{export_response, EExportFormat, ExportQueryParam};
 use crate::util::filter_and_pagination::PageQueryParam;
 use axum::extract::State;
+use axum::http::{header, HeaderMap};
+use axum::response::Response;
 use axum::{Extension, Json};
 use axum_extra::extract::Query;
 
@@ -19,18 +25,18 @@ use axum_extra::extract::Query;
         (status = 200, description = "Success get list of users", body = [PublicProfileResponse]),
         (status = 400, description = "Invalid data input", body = [ClientResponseError]),
         (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
         (status = 500, description = "Internal server error", body = [ClientResponseError])
     ),
     security(("jwt" = []))
 )]
 pub async fn controller_admin_create_account(
     State(state): State<AppState>,
-    // claims: UserClaims,
+    context: AuditContext,
     Json(req): Json<AdminCreateAccountRequest>,
 ) -> AppResult<Json<PublicProfileResponse>> {
-    // tracing::info!("Create new account from: {}", claims.uuid);
-    // TODO: check claims is admin or not?
-    match service_admin_create_account(&state, req).await {
+    tracing::info!("Create new account from: {}", context.actor_uuid);
+    match service_admin_create_account(&state, &context, req).await {
         Ok(result) => Ok(Json(result)),
         Err(err) => {
             tracing::warn!("Unsuccessfully create new account failed: {err:?}.");
@@ -48,6 +54,7 @@ pub async fn controller_admin_create_account(
         (status = 200, description = "Success get list of users", body = [EntityResponse<Vec<PublicProfileResponse>>]),
         (status = 400, description = "Invalid data input", body = [ClientResponseError]),
         (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
         (status = 500, description = "Internal server error", body = [ClientResponseError])
     ),
     security(("jwt" = []))
@@ -57,17 +64,44 @@ pub async fn controller_admin_get_list(
     claims: UserClaims,
     Query(param): Query<PageQueryParam>,
 ) -> AppResult<Json<EntityResponse<Vec<PublicProfileResponse>>>> {
-    tracing::info!("Get list of parameter: {:?}.", param);
-    // TODO: check claims is admin or not?
+    tracing::info!("Get list of parameter: {:?} from: {}.", param, claims.uuid);
     match service_admin_get_list(&state, param).await {
-        Ok(results) => Ok(Json(EntityResponse {
-            message: "Get all users success!".to_string(),
-            total: results.iter().clone().len() as u16,
-            data: Some(results),
-        })),
+        Ok(results) => Ok(Json(EntityResponse::paginated("Get all users success!", results))),
         Err(err) => {
             tracing::warn!("Unsuccessfully get profile user: {err:?}.");
             Err(err)
         },
     }
 }
+
+#[utoipa::path(
+    get,
+    path = "/v1/admin/export",
+    params(ExportQueryParam),
+    tags = ["user_service"],
+    responses(
+        (status = 200, description = "Success export users as json, csv or xlsx", body = [Vec<PublicProfileResponse>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_admin_export(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    headers: HeaderMap,
+    Query(param): Query<ExportQueryParam>,
+) -> AppResult<Response> {
+    tracing::info!("Export users with parameter: {:?} from: {}.", param, claims.uuid);
+    let accept = headers.get(header::ACCEPT).and_then(|accept| accept.to_str().ok());
+    let format = EExportFormat::negotiate(param.format, accept);
+    match service_admin_export_users(&state, &param).await {
+        Ok((columns, rows)) => export_response(format, "users", columns, rows).await,
+        Err(err) => {
+            tracing::warn!("Unsuccessfully export users: {err:?}.");
+            Err(err)
+        },
+    }
+}
@@ -0,0 +1,293 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::core::response::ClientResponseError;
+use crate::domain::attendance::request::{
+    AttendanceSummaryQueryParam, ClockRequest, CreateAttendanceCorrectionRequest,
+    DeleteAttendanceCorrectionRequest, TimesheetQueryParam, UpdateAttendanceCorrectionRequest,
+};
+use crate::domain::attendance::response::{
+    AttendanceEventResponse, DepartmentAttendanceSummaryResponse,
+    EmployeeAttendanceSummaryResponse, EmployeeTimesheetResponse,
+};
+use crate::domain::attendance::services::{
+    service_clock, service_create_attendance_correction, service_delete_attendance_correction,
+    service_get_department_attendance_summary, service_get_employee_attendance_summary,
+    service_get_my_timesheet, service_get_timesheet, service_update_attendance_correction,
+};
+use crate::domain::audit_log::AuditContext;
+use crate::util::claim::UserClaims;
+use axum::extract::{Path, State};
+use axum::Json;
+use axum_extra::extract::Query;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/v1/attendance/clock",
+    request_body = ClockRequest,
+    tags = ["attendance_service"],
+    responses(
+        (status = 200, description = "Success record attendance event", body = [AttendanceEventResponse]),
+        (status = 400, description = "Event not expected now, e.g. a clock-out before the clock-in", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "User is not an employee", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_clock(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<ClockRequest>,
+) -> AppResult<Json<AttendanceEventResponse>> {
+    tracing::info!("Record attendance event by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_clock(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully record attendance event: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/attendance/timesheet",
+    params(TimesheetQueryParam),
+    tags = ["attendance_service"],
+    responses(
+        (status = 200, description = "Success get own timesheet", body = [EmployeeTimesheetResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "User is not an employee", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_my_timesheet(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Query(query): Query<TimesheetQueryParam>,
+) -> AppResult<Json<EmployeeTimesheetResponse>> {
+    tracing::info!("Get own timesheet by user: {}.", context.actor_uuid);
+    match service_get_my_timesheet(&state, &context, query).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get own timesheet: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/attendance/timesheet/{employee_uuid}",
+    params(
+        ("employee_uuid" = Uuid, Path, description = "uuid of the employee"),
+        TimesheetQueryParam
+    ),
+    tags = ["attendance_service"],
+    responses(
+        (status = 200, description = "Success get timesheet of the employee", body = [EmployeeTimesheetResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_timesheet(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(employee_uuid): Path<Uuid>,
+    Query(query): Query<TimesheetQueryParam>,
+) -> AppResult<Json<EmployeeTimesheetResponse>> {
+    tracing::info!("Get timesheet of {employee_uuid} by user: {}.", claims.uuid);
+    match service_get_timesheet(&state, employee_uuid, query).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get timesheet: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/attendance/correction/create",
+    request_body = CreateAttendanceCorrectionRequest,
+    tags = ["attendance_service"],
+    responses(
+        (status = 200, description = "Success add attendance event", body = [AttendanceEventResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Not the manager or a department head of the employee", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_attendance_correction(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<CreateAttendanceCorrectionRequest>,
+) -> AppResult<Json<AttendanceEventResponse>> {
+    tracing::info!("Add attendance event by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_create_attendance_correction(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully add attendance event: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/attendance/correction/update",
+    request_body = UpdateAttendanceCorrectionRequest,
+    tags = ["attendance_service"],
+    responses(
+        (status = 200, description = "Success correct attendance event", body = [AttendanceEventResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Not the manager or a department head of the employee", body = [ClientResponseError]),
+        (status = 404, description = "Attendance event not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_attendance_correction(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<UpdateAttendanceCorrectionRequest>,
+) -> AppResult<Json<AttendanceEventResponse>> {
+    tracing::info!("Correct attendance event by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_update_attendance_correction(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully correct attendance event: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/attendance/correction/delete",
+    request_body = DeleteAttendanceCorrectionRequest,
+    tags = ["attendance_service"],
+    responses(
+        (status = 200, description = "Success remove attendance event", body = [AttendanceEventResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Not the manager or a department head of the employee", body = [ClientResponseError]),
+        (status = 404, description = "Attendance event not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_delete_attendance_correction(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<DeleteAttendanceCorrectionRequest>,
+) -> AppResult<Json<AttendanceEventResponse>> {
+    tracing::info!("Remove attendance event by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_delete_attendance_correction(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully remove attendance event: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/attendance/summary/employee/{employee_uuid}",
+    params(
+        ("employee_uuid" = Uuid, Path, description = "uuid of the employee"),
+        AttendanceSummaryQueryParam
+    ),
+    tags = ["attendance_service"],
+    responses(
+        (status = 200, description = "Success get monthly summary of the employee", body = [EmployeeAttendanceSummaryResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_employee_attendance_summary(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(employee_uuid): Path<Uuid>,
+    Query(query): Query<AttendanceSummaryQueryParam>,
+) -> AppResult<Json<EmployeeAttendanceSummaryResponse>> {
+    tracing::info!("Get attendance summary of {employee_uuid} by user: {}.", claims.uuid);
+    if query.validate().is_err() {
+        return Err(AppError::BadRequestError(query.validate().unwrap_err().to_string()));
+    }
+    match service_get_employee_attendance_summary(&state, employee_uuid, query).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get attendance summary of employee: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/attendance/summary/department/{department_uuid}",
+    params(
+        ("department_uuid" = Uuid, Path, description = "uuid of the department"),
+        AttendanceSummaryQueryParam
+    ),
+    tags = ["attendance_service"],
+    responses(
+        (status = 200, description = "Success get monthly summary of the department", body = [DepartmentAttendanceSummaryResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_department_attendance_summary(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(department_uuid): Path<Uuid>,
+    Query(query): Query<AttendanceSummaryQueryParam>,
+) -> AppResult<Json<DepartmentAttendanceSummaryResponse>> {
+    tracing::info!("Get attendance summary of {department_uuid} by user: {}.", claims.uuid);
+    if query.validate().is_err() {
+        return Err(AppError::BadRequestError(query.validate().unwrap_err().to_string()));
+    }
+    match service_get_department_attendance_summary(&state, department_uuid, query).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get attendance summary of department: {err:?}.");
+            Err(err)
+        },
+    }
+}
@@ -1,13 +1,21 @@
 use crate::core::app_state::AppState;
 use crate::core::error::{AppError, AppResult};
 use crate::core::response::{ClientResponseError, EntityResponse, MessageResponse};
-use crate::domain::authenticate::request::{LoginByEmailRequest, RefreshTokenRequest};
-use crate::domain::authenticate::response::{LoginResponse, TokenResponse};
-use crate::domain::authenticate::services::{service_login_by_email, service_logout};
-use crate::infrastructure::third_party::token::service_refresh;
+use crate::domain::authenticate::request::{
+    ForgetPasswordQueryParam, LoginByEmailRequest, RefreshTokenRequest, ResetPasswordRequest,
+};
+use crate::domain::authenticate::response::{
+    ForgetPasswordResponse, LoginResponse, TokenResponse,
+};
+use crate::domain::authenticate::services::{
+    service_forget_password, service_login_by_email, service_logout, service_reset_password,
+};
+use crate::infrastructure::middleware::client_info::ClientInfo;
+use crate::infrastructure::third_party::token::{service_refresh, service_revoke_token};
 use crate::util::claim::UserClaims;
 use axum::extract::{Path, State};
 use axum::{Extension, Json};
+use axum_extra::extract::Query;
 use validator::Validate;
 
 #[utoipa::path(
@@ -24,13 +32,14 @@ use validator::Validate;
 )]
 pub async fn controller_login_by_email(
     State(state): State<AppState>,
+    client: ClientInfo,
     Json(req): Json<LoginByEmailRequest>,
 ) -> AppResult<Json<LoginResponse>> {
     tracing::info!("Login by email with request: {req:?}.");
     if req.validate().is_err() {
         return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
     }
-    match service_login_by_email(&state, req).await {
+    match service_login_by_email(&state, req, client).await {
         Ok(res) => {
             tracing::info!("Success login!");
             Ok(Json(LoginResponse::Token(res)))
@@ -73,3 +82,98 @@ pub async fn controller_refresh_token(
         },
     }
 }
+
+#[utoipa::path(
+    post,
+    path = "/v1/revoke_token",
+    request_body = RefreshTokenRequest,
+    tags = ["auth_service"],
+    responses(
+        (status = 200, description = "Success revoke refresh token", body = [MessageResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+)]
+pub async fn controller_revoke_token(
+    State(state): State<AppState>,
+    Json(req): Json<RefreshTokenRequest>,
+) -> AppResult<Json<MessageResponse>> {
+    tracing::info!("Revoke refresh token.");
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_revoke_token(&state, req).await {
+        Ok(_) => {
+            tracing::info!("Success revoke refresh token");
+            Ok(Json(MessageResponse::new("The refresh token has been revoked.")))
+        },
+        Err(err) => {
+            tracing::error!("Unsuccessfully revoke refresh token error: {err:?}");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/forget_password",
+    params(ForgetPasswordQueryParam),
+    tags = ["auth_service"],
+    responses(
+        (status = 200, description = "Success send forget password code", body = [ForgetPasswordResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 404, description = "Account not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    )
+)]
+pub async fn controller_forget_password(
+    State(state): State<AppState>,
+    Query(param): Query<ForgetPasswordQueryParam>,
+) -> AppResult<Json<ForgetPasswordResponse>> {
+    tracing::info!("Forget password with parameter: {param:?}.");
+    if param.validate().is_err() {
+        return Err(AppError::BadRequestError(param.validate().unwrap_err().to_string()));
+    }
+    match service_forget_password(&state, param).await {
+        Ok(res) => {
+            tracing::info!("Success send forget password code!");
+            Ok(Json(res))
+        },
+        Err(err) => {
+            tracing::error!("Unsuccessfully send forget password code error: {err:?}");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/reset_password",
+    request_body = ResetPasswordRequest,
+    tags = ["auth_service"],
+    responses(
+        (status = 200, description = "Success reset password", body = [MessageResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 404, description = "Account not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    )
+)]
+pub async fn controller_reset_password(
+    State(state): State<AppState>,
+    Json(req): Json<ResetPasswordRequest>,
+) -> AppResult<Json<MessageResponse>> {
+    tracing::info!("Reset password for email: {}.", req.email);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_reset_password(&state, req).await {
+        Ok(_) => {
+            tracing::info!("Success reset password!");
+            Ok(Json(MessageResponse::new("The password has been reset, please login again.")))
+        },
+        Err(err) => {
+            tracing::error!("Unsuccessfully reset password error: {err:?}");
+            Err(err)
+        },
+    }
+}
@@ -0,0 +1,186 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::audit_log::AuditContext;
+use crate::domain::calendar::request::{
+    CreateHolidayRequest, DeleteHolidayRequest, HolidayQueryParam, UpdateHolidayRequest,
+    WorkingDaysQueryParam,
+};
+use crate::domain::calendar::response::{HolidayResponse, WorkingDaysResponse};
+use crate::domain::calendar::services::{
+    service_create_holiday, service_delete_holiday, service_get_list_holiday,
+    service_get_working_days, service_update_holiday,
+};
+use crate::util::claim::UserClaims;
+use crate::util::filter_and_pagination::PageQueryParam;
+use axum::extract::{Path, State};
+use axum::Json;
+use axum_extra::extract::Query;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/v1/calendar/holiday/create",
+    request_body = CreateHolidayRequest,
+    tags = ["calendar_service"],
+    responses(
+        (status = 200, description = "Success create new holiday", body = [HolidayResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 409, description = "Another holiday falls on the same day", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_holiday(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<CreateHolidayRequest>,
+) -> AppResult<Json<HolidayResponse>> {
+    tracing::info!("Create new holiday by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_create_holiday(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully create new holiday: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/calendar/holiday/update",
+    request_body = UpdateHolidayRequest,
+    tags = ["calendar_service"],
+    responses(
+        (status = 200, description = "Success update holiday", body = [HolidayResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Holiday not found", body = [ClientResponseError]),
+        (status = 409, description = "Another holiday falls on the same day", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_holiday(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<UpdateHolidayRequest>,
+) -> AppResult<Json<HolidayResponse>> {
+    tracing::info!("Update holiday by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_update_holiday(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully update holiday: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/calendar/holiday/delete",
+    request_body = DeleteHolidayRequest,
+    tags = ["calendar_service"],
+    responses(
+        (status = 200, description = "Success delete holiday", body = [HolidayResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Holiday not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_delete_holiday(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<DeleteHolidayRequest>,
+) -> AppResult<Json<HolidayResponse>> {
+    tracing::info!("Delete holiday by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_delete_holiday(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully delete holiday: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/calendar/holiday/list",
+    params(HolidayQueryParam, PageQueryParam),
+    tags = ["calendar_service"],
+    responses(
+        (status = 200, description = "Success get list of holidays", body = [EntityResponse<Vec<HolidayResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_holiday(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(query): Query<HolidayQueryParam>,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<HolidayResponse>>>> {
+    tracing::info!("Get list of holidays by user: {}, parameter: {param:?}.", claims.uuid);
+    match service_get_list_holiday(&state, query, param).await {
+        Ok(results) => Ok(Json(EntityResponse::paginated("Get all holidays success!", results))),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of holidays: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/calendar/working_days/{employee_uuid}",
+    params(
+        ("employee_uuid" = Uuid, Path, description = "uuid of the employee"),
+        WorkingDaysQueryParam
+    ),
+    tags = ["calendar_service"],
+    responses(
+        (status = 200, description = "Success get working days of the employee between both days", body = [WorkingDaysResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_working_days(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(employee_uuid): Path<Uuid>,
+    Query(query): Query<WorkingDaysQueryParam>,
+) -> AppResult<Json<WorkingDaysResponse>> {
+    tracing::info!("Get working days of {employee_uuid} by user: {}.", claims.uuid);
+    match service_get_working_days(&state, employee_uuid, query).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get working days of employee: {err:?}.");
+            Err(err)
+        },
+    }
+}
@@ -0,0 +1,311 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::audit_log::AuditContext;
+use crate::domain::department::request::{
+    CreateDepartmentRequest, DeleteDepartmentRequest, DepartmentQueryParam,
+    MoveDepartmentRequest, UpdateDepartmentRequest,
+};
+use crate::domain::department::response::{DepartmentResponse, DepartmentTreeResponse};
+use crate::domain::department::services::{
+    service_create_department, service_delete_department, service_get_department,
+    service_get_department_ancestors, service_get_department_subtree,
+    service_get_department_tree, service_get_list_department, service_move_department,
+    service_update_department,
+};
+use crate::util::claim::UserClaims;
+use crate::util::filter_and_pagination::PageQueryParam;
+use axum::extract::{Path, State};
+use axum::Json;
+use axum_extra::extract::Query;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/v1/department/create",
+    request_body = CreateDepartmentRequest,
+    tags = ["department_service"],
+    responses(
+        (status = 200, description = "Success create new department", body = [DepartmentResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 409, description = "Department already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_department(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<CreateDepartmentRequest>,
+) -> AppResult<Json<DepartmentResponse>> {
+    tracing::info!("Create new department by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_create_department(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully create new department: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/department/list",
+    params(DepartmentQueryParam, PageQueryParam),
+    tags = ["department_service"],
+    responses(
+        (status = 200, description = "Success get list of departments", body = [EntityResponse<Vec<DepartmentResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_department(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(query): Query<DepartmentQueryParam>,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<DepartmentResponse>>>> {
+    tracing::info!("Get list of departments by user: {}, parameter: {param:?}.", claims.uuid);
+    match service_get_list_department(&state, query.organization_uuid, param).await {
+        Ok(results) => Ok(Json(EntityResponse::paginated("Get all departments success!", results))),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of departments: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/department/{department_uuid}",
+    params(("department_uuid" = Uuid, Path, description = "uuid of the department")),
+    tags = ["department_service"],
+    responses(
+        (status = 200, description = "Success get department", body = [DepartmentResponse]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_department(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(department_uuid): Path<Uuid>,
+) -> AppResult<Json<DepartmentResponse>> {
+    tracing::info!("Get department {department_uuid} by user: {}.", claims.uuid);
+    match service_get_department(&state, department_uuid).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get department: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/department/update",
+    request_body = UpdateDepartmentRequest,
+    tags = ["department_service"],
+    responses(
+        (status = 200, description = "Success update department", body = [DepartmentResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 409, description = "Department already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_department(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<UpdateDepartmentRequest>,
+) -> AppResult<Json<DepartmentResponse>> {
+    tracing::info!("Update department by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_update_department(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully update department: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/department/delete",
+    request_body = DeleteDepartmentRequest,
+    tags = ["department_service"],
+    responses(
+        (status = 200, description = "Success delete department", body = [EntityResponse<i64>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_delete_department(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<DeleteDepartmentRequest>,
+) -> AppResult<Json<EntityResponse<i64>>> {
+    tracing::info!("Delete department by user: {}.", context.actor_uuid);
+    match service_delete_department(&state, &context, req).await {
+        Ok(value) => Ok(Json(EntityResponse {
+            message: "Delete department success!".to_string(),
+            data: Some(value),
+            total: Some(1),
+            pagination: None,
+        })),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully delete department: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/department/move",
+    request_body = MoveDepartmentRequest,
+    tags = ["department_service"],
+    responses(
+        (status = 200, description = "Success move department", body = [DepartmentResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_move_department(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<MoveDepartmentRequest>,
+) -> AppResult<Json<DepartmentResponse>> {
+    tracing::info!("Move department by user: {}.", context.actor_uuid);
+    match service_move_department(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully move department: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/department/tree",
+    params(DepartmentQueryParam),
+    tags = ["department_service"],
+    responses(
+        (status = 200, description = "Success get department tree", body = [EntityResponse<Vec<DepartmentTreeResponse>>]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_department_tree(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(query): Query<DepartmentQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<DepartmentTreeResponse>>>> {
+    tracing::info!("Get department tree by user: {}.", claims.uuid);
+    match service_get_department_tree(&state, query.organization_uuid).await {
+        Ok(results) => Ok(Json(EntityResponse {
+            message: "Get department tree success!".to_string(),
+            total: Some(results.len() as u64),
+            data: Some(results),
+            pagination: None,
+        })),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get department tree: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/department/{department_uuid}/subtree",
+    params(("department_uuid" = Uuid, Path, description = "uuid of the department")),
+    tags = ["department_service"],
+    responses(
+        (status = 200, description = "Success get sub departments", body = [DepartmentTreeResponse]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_department_subtree(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(department_uuid): Path<Uuid>,
+) -> AppResult<Json<DepartmentTreeResponse>> {
+    tracing::info!("Get sub departments of {department_uuid} by user: {}.", claims.uuid);
+    match service_get_department_subtree(&state, department_uuid).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get sub departments: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/department/{department_uuid}/ancestors",
+    params(("department_uuid" = Uuid, Path, description = "uuid of the department")),
+    tags = ["department_service"],
+    responses(
+        (status = 200, description = "Success get parent departments, root first", body = [EntityResponse<Vec<DepartmentResponse>>]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_department_ancestors(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(department_uuid): Path<Uuid>,
+) -> AppResult<Json<EntityResponse<Vec<DepartmentResponse>>>> {
+    tracing::info!("Get parent departments of {department_uuid} by user: {}.", claims.uuid);
+    match service_get_department_ancestors(&state, department_uuid).await {
+        Ok(results) => Ok(Json(EntityResponse {
+            message: "Get parent departments success!".to_string(),
+            total: Some(results.len() as u64),
+            data: Some(results),
+            pagination: None,
+        })),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get parent departments: {err:?}.");
+            Err(err)
+        },
+    }
+}
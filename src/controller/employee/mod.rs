@@ -1,18 +1,35 @@
 use crate::core::app_state::AppState;
-use crate::core::error::AppResult;
+use crate::core::error::{AppError, AppResult};
 use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::audit_log::AuditContext;
 use crate::domain::employee::request::{
-    CreateNewEmployeeByUserUuidRequest, CreateNewEmployeeRequest, DeleteEmployeeRequest,
+    AssignManagerRequest, CreateNewEmployeeByUserUuidRequest, CreateNewEmployeeRequest,
+    DeleteEmployeeRequest, EmployeeImportFileRequest, EmployeeImportQueryParam, EmployeeQueryParam,
     UpdateEmployeeRequest,
 };
+use crate::domain::employee::response::{
+    EmployeeAssignmentResponse, EmployeeDetailResponse, EmployeeExportRow, EmployeeImportResponse,
+    EmployeeResponse, SpanOfControlResponse,
+};
 use crate::domain::employee::services::{
-    service_create_new_employee, service_create_new_employee_by_user_exist,
-    service_delete_employee, service_update_employee,
+    service_assign_manager, service_create_new_employee,
+    service_create_new_employee_by_user_exist, service_delete_employee, service_export_employees,
+    service_get_chain_of_command, service_get_direct_reports, service_get_employee,
+    service_get_employee_history, service_get_list_employee, service_get_span_of_control,
+    service_import_employees, service_update_employee,
 };
-use axum::extract::State;
+use crate::util::export::{export_response, EExportFormat, ExportQueryParam};
+use crate::util::filter_and_pagination::PageQueryParam;
+use axum::body::Bytes;
+use axum::extract::multipart::MultipartError;
+use axum::extract::{Multipart, Path, State};
+use axum::http::{header, HeaderMap};
+use axum::response::Response;
 use axum::Json;
+use axum_extra::extract::Query;
 use tower_http::validate_request::ValidateRequest;
 use tracing::{info, warn};
+use uuid::Uuid;
 
 #[utoipa::path(
     post,
@@ -22,19 +39,23 @@ use tracing::{info, warn};
     responses(
         (status = 200, description = "Success create employee from exist user", body = [EntityResponse<i64>]),
         (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
         (status = 500, description = "Internal server error", body = [ClientResponseError])
-    )
+    ),
+    security(("jwt" = []))
 )]
 pub async fn create_new_employee_by_user_exist(
     State(state): State<AppState>,
+    context: AuditContext,
     Json(req): Json<CreateNewEmployeeByUserUuidRequest>,
 ) -> AppResult<Json<EntityResponse<i64>>> {
     info!("Register new employee with request: {req:?}");
-    return match service_create_new_employee_by_user_exist(&state, &req).await {
+    return match service_create_new_employee_by_user_exist(&state, &context, &req).await {
         Ok(value) => Ok(Json(EntityResponse {
             message: "create new employee from existed 123123!".to_string(),
             data: Some(value.clone()),
-            total: 1,
+            total: Some(1),
+            pagination: None,
         })),
         Err(e) => {
             warn!("Unsuccessfully get profile 123123: {e:?}.");
@@ -51,18 +72,22 @@ pub async fn create_new_employee_by_user_exist(
     responses(
         (status = 200, description = "Success create new employee", body = [EntityResponse<i64>]),
         (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
         (status = 500, description = "Internal server error", body = [ClientResponseError])
-    )
+    ),
+    security(("jwt" = []))
 )]
 pub async fn create_new_employee(
     State(state): State<AppState>,
+    context: AuditContext,
     Json(req): Json<CreateNewEmployeeRequest>,
 ) -> AppResult<Json<EntityResponse<i64>>> {
-    return match service_create_new_employee(&state, &req).await {
+    return match service_create_new_employee(&state, &context, &req).await {
         Ok(value) => Ok(Json(EntityResponse {
             message: "create new employee from existed 123123!".to_string(),
             data: Some(value),
-            total: 1,
+            total: Some(1),
+            pagination: None,
         })),
         Err(e) => {
             warn!("Unsuccessfully get profile 123123: {e:?}.");
@@ -71,6 +96,51 @@ pub async fn create_new_employee(
     };
 }
 
+#[utoipa::path(
+    post,
+    tags = ["employee_service"],
+    params(EmployeeImportQueryParam),
+    request_body(content = EmployeeImportFileRequest, content_type = "multipart/form-data"),
+    path = "/v1/employee/import",
+    responses(
+        (status = 200, description = "Success import employees", body = [EmployeeImportResponse]),
+        (status = 400, description = "Invalid import file", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn import_employees(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Query(query): Query<EmployeeImportQueryParam>,
+    mut multipart: Multipart,
+) -> AppResult<Json<EmployeeImportResponse>> {
+    let (file_name, content) = read_import_file(&mut multipart).await?;
+    info!("Import employees from file: {file_name}");
+    match service_import_employees(&state, &context, &file_name, &content, query.dry_run).await {
+        Ok(result) => Ok(Json(result)),
+        Err(e) => {
+            warn!("Unsuccessfully import employees: {e:?}.");
+            Err(e)
+        },
+    }
+}
+
+async fn read_import_file(multipart: &mut Multipart) -> AppResult<(String, Bytes)> {
+    let invalid = |err: MultipartError| AppError::BadRequestError(err.body_text());
+    while let Some(field) = multipart.next_field().await.map_err(invalid)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let file_name = field.file_name().unwrap_or_default().to_string();
+        let content = field.bytes().await.map_err(invalid)?;
+        return Ok((file_name, content));
+    }
+    Err(AppError::BadRequestError("Missing multipart field `file`".to_string()))
+}
+
 #[utoipa::path(
     put,
     tags = ["employee_service"],
@@ -79,19 +149,23 @@ pub async fn create_new_employee(
     responses(
         (status = 200, description = "Success create new employee", body = [EntityResponse<i64>]),
         (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
         (status = 500, description = "Internal server error", body = [ClientResponseError])
-    )
+    ),
+    security(("jwt" = []))
 )]
 pub async fn update_employee(
     State(state): State<AppState>,
+    context: AuditContext,
     Json(req): Json<UpdateEmployeeRequest>,
 ) -> AppResult<Json<EntityResponse<i64>>> {
     info!("Register new employee with request: {req:?}");
-    return match service_update_employee(&state, &req).await {
+    return match service_update_employee(&state, &context, &req).await {
         Ok(value) => Ok(Json(EntityResponse {
             message: "create new employee from existed 123123!".to_string(),
             data: Some(value),
-            total: 1,
+            total: Some(1),
+            pagination: None,
         })),
         Err(e) => {
             warn!("Unsuccessfully get profile 123123: {e:?}.");
@@ -108,19 +182,23 @@ pub async fn update_employee(
     responses(
         (status = 200, description = "Success create new employee", body = [EntityResponse<i64>]),
         (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
         (status = 500, description = "Internal server error", body = [ClientResponseError])
-    )
+    ),
+    security(("jwt" = []))
 )]
 pub async fn delete_employee(
     State(state): State<AppState>,
+    context: AuditContext,
     Json(req): Json<DeleteEmployeeRequest>,
 ) -> AppResult<Json<EntityResponse<i64>>> {
     info!("Register new employee with request: {req:?}");
-    match service_delete_employee(&state, &req).await {
+    match service_delete_employee(&state, &context, &req).await {
         Ok(value) => Ok(Json(EntityResponse {
             message: "create new employee from existed 123123!".to_string(),
             data: Some(value),
-            total: 1,
+            total: Some(1),
+            pagination: None,
         })),
         Err(e) => {
             warn!("Unsuccessfully get profile 123123: {e:?}.");
@@ -128,3 +206,253 @@ pub async fn delete_employee(
         },
     }
 }
+
+#[utoipa::path(
+    get,
+    tags = ["employee_service"],
+    params(EmployeeQueryParam, PageQueryParam),
+    path = "/v1/employee",
+    responses(
+        (status = 200, description = "Success get list of employees", body = [EntityResponse<Vec<EmployeeResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn get_list_employee(
+    State(state): State<AppState>,
+    Query(query): Query<EmployeeQueryParam>,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<EmployeeResponse>>>> {
+    info!("Get list of employees with query: {query:?}, parameter: {param:?}");
+    match service_get_list_employee(&state, query, param).await {
+        Ok(results) => Ok(Json(EntityResponse::paginated("Get all employees success!", results))),
+        Err(e) => {
+            warn!("Unsuccessfully get list of employees: {e:?}.");
+            Err(e)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    tags = ["employee_service"],
+    params(EmployeeQueryParam, ExportQueryParam),
+    path = "/v1/employee/export",
+    responses(
+        (status = 200, description = "Success export employees as json, csv or xlsx", body = [Vec<EmployeeExportRow>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn export_employees(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<EmployeeQueryParam>,
+    Query(param): Query<ExportQueryParam>,
+) -> AppResult<Response> {
+    let accept = headers.get(header::ACCEPT).and_then(|accept| accept.to_str().ok());
+    let format = EExportFormat::negotiate(param.format, accept);
+    match service_export_employees(&state, query, &param).await {
+        Ok((columns, rows)) => export_response(format, "employees", columns, rows).await,
+        Err(e) => {
+            warn!("Unsuccessfully export employees: {e:?}.");
+            Err(e)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    tags = ["employee_service"],
+    params(("employee_uuid" = Uuid, Path, description = "uuid of the employee")),
+    path = "/v1/employee/{employee_uuid}",
+    responses(
+        (status = 200, description = "Success get employee detail", body = [EmployeeDetailResponse]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn get_employee(
+    State(state): State<AppState>,
+    Path(employee_uuid): Path<Uuid>,
+) -> AppResult<Json<EmployeeDetailResponse>> {
+    info!("Get employee detail with uuid: {employee_uuid}");
+    match service_get_employee(&state, employee_uuid).await {
+        Ok(result) => Ok(Json(result)),
+        Err(e) => {
+            warn!("Unsuccessfully get employee detail: {e:?}.");
+            Err(e)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    tags = ["employee_service"],
+    params(("employee_uuid" = Uuid, Path, description = "uuid of the employee")),
+    path = "/v1/employee/{employee_uuid}/history",
+    responses(
+        (status = 200, description = "Success get assignment history of employee", body = [EntityResponse<Vec<EmployeeAssignmentResponse>>]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn get_employee_history(
+    State(state): State<AppState>,
+    Path(employee_uuid): Path<Uuid>,
+) -> AppResult<Json<EntityResponse<Vec<EmployeeAssignmentResponse>>>> {
+    info!("Get assignment history of employee with uuid: {employee_uuid}");
+    match service_get_employee_history(&state, employee_uuid).await {
+        Ok(history) => Ok(Json(EntityResponse {
+            message: "Get employee history success!".to_string(),
+            total: Some(history.len() as u64),
+            data: Some(history),
+            pagination: None,
+        })),
+        Err(e) => {
+            warn!("Unsuccessfully get employee history: {e:?}.");
+            Err(e)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    tags = ["employee_service"],
+    request_body = AssignManagerRequest,
+    path = "/v1/employee/manager",
+    responses(
+        (status = 200, description = "Success assign manager of employee", body = [EntityResponse<i64>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn assign_manager(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<AssignManagerRequest>,
+) -> AppResult<Json<EntityResponse<i64>>> {
+    info!("Assign manager with request: {req:?}");
+    match service_assign_manager(&state, &context, &req).await {
+        Ok(value) => Ok(Json(EntityResponse {
+            message: "Assign manager success!".to_string(),
+            data: Some(value),
+            total: Some(1),
+            pagination: None,
+        })),
+        Err(e) => {
+            warn!("Unsuccessfully assign manager: {e:?}.");
+            Err(e)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    tags = ["employee_service"],
+    params(("employee_uuid" = Uuid, Path, description = "uuid of the employee")),
+    path = "/v1/employee/{employee_uuid}/direct_reports",
+    responses(
+        (status = 200, description = "Success get direct reports of employee", body = [EntityResponse<Vec<EmployeeResponse>>]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn get_direct_reports(
+    State(state): State<AppState>,
+    Path(employee_uuid): Path<Uuid>,
+) -> AppResult<Json<EntityResponse<Vec<EmployeeResponse>>>> {
+    info!("Get direct reports of employee with uuid: {employee_uuid}");
+    match service_get_direct_reports(&state, employee_uuid).await {
+        Ok(reports) => Ok(Json(EntityResponse {
+            message: "Get direct reports success!".to_string(),
+            total: Some(reports.len() as u64),
+            data: Some(reports),
+            pagination: None,
+        })),
+        Err(e) => {
+            warn!("Unsuccessfully get direct reports: {e:?}.");
+            Err(e)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    tags = ["employee_service"],
+    params(("employee_uuid" = Uuid, Path, description = "uuid of the employee")),
+    path = "/v1/employee/{employee_uuid}/chain_of_command",
+    responses(
+        (status = 200, description = "Success get managers of employee, direct manager first", body = [EntityResponse<Vec<EmployeeResponse>>]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn get_chain_of_command(
+    State(state): State<AppState>,
+    Path(employee_uuid): Path<Uuid>,
+) -> AppResult<Json<EntityResponse<Vec<EmployeeResponse>>>> {
+    info!("Get chain of command of employee with uuid: {employee_uuid}");
+    match service_get_chain_of_command(&state, employee_uuid).await {
+        Ok(managers) => Ok(Json(EntityResponse {
+            message: "Get chain of command success!".to_string(),
+            total: Some(managers.len() as u64),
+            data: Some(managers),
+            pagination: None,
+        })),
+        Err(e) => {
+            warn!("Unsuccessfully get chain of command: {e:?}.");
+            Err(e)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    tags = ["employee_service"],
+    params(("employee_uuid" = Uuid, Path, description = "uuid of the employee")),
+    path = "/v1/employee/{employee_uuid}/span_of_control",
+    responses(
+        (status = 200, description = "Success get span of control of employee", body = [SpanOfControlResponse]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn get_span_of_control(
+    State(state): State<AppState>,
+    Path(employee_uuid): Path<Uuid>,
+) -> AppResult<Json<SpanOfControlResponse>> {
+    info!("Get span of control of employee with uuid: {employee_uuid}");
+    match service_get_span_of_control(&state, employee_uuid).await {
+        Ok(result) => Ok(Json(result)),
+        Err(e) => {
+            warn!("Unsuccessfully get span of control: {e:?}.");
+            Err(e)
+        },
+    }
+}
@@ -0,0 +1,360 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::audit_log::AuditContext;
+use crate::domain::leave::request::{
+    CancelLeaveRequest, CreateLeaveTypeRequest, DecideLeaveRequest, LeaveBalanceQueryParam,
+    LeaveRequestQueryParam, LeaveTypeQueryParam, SubmitLeaveRequest, UpdateLeaveTypeRequest,
+};
+use crate::domain::leave::response::{
+    LeaveBalanceResponse, LeaveRequestResponse, LeaveTypeResponse,
+};
+use crate::domain::leave::services::{
+    service_approve_leave_request, service_cancel_leave_request, service_create_leave_type,
+    service_get_leave_balance, service_get_list_leave_request, service_get_list_leave_type,
+    service_get_my_leave_balance, service_reject_leave_request, service_submit_leave_request,
+    service_update_leave_type,
+};
+use crate::util::claim::UserClaims;
+use crate::util::filter_and_pagination::PageQueryParam;
+use axum::extract::{Path, State};
+use axum::Json;
+use axum_extra::extract::Query;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/v1/leave/type/create",
+    request_body = CreateLeaveTypeRequest,
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success create new leave type", body = [LeaveTypeResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 409, description = "Leave type already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_leave_type(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<CreateLeaveTypeRequest>,
+) -> AppResult<Json<LeaveTypeResponse>> {
+    tracing::info!("Create new leave type by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_create_leave_type(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully create new leave type: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/leave/type/update",
+    request_body = UpdateLeaveTypeRequest,
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success update leave type", body = [LeaveTypeResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Leave type not found", body = [ClientResponseError]),
+        (status = 409, description = "Leave type already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_leave_type(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<UpdateLeaveTypeRequest>,
+) -> AppResult<Json<LeaveTypeResponse>> {
+    tracing::info!("Update leave type by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_update_leave_type(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully update leave type: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/leave/type/list",
+    params(LeaveTypeQueryParam, PageQueryParam),
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success get list of leave types", body = [EntityResponse<Vec<LeaveTypeResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_leave_type(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(query): Query<LeaveTypeQueryParam>,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<LeaveTypeResponse>>>> {
+    tracing::info!("Get list of leave types by user: {}, parameter: {param:?}.", claims.uuid);
+    match service_get_list_leave_type(&state, query.organization_uuid, param).await {
+        Ok(results) => {
+            Ok(Json(EntityResponse::paginated("Get all leave types success!", results)))
+        },
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of leave types: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/leave/request/submit",
+    request_body = SubmitLeaveRequest,
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success submit leave request", body = [LeaveRequestResponse]),
+        (status = 400, description = "Invalid data input or not enough balance", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee or leave type not found", body = [ClientResponseError]),
+        (status = 409, description = "Leave overlaps another leave", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_submit_leave_request(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<SubmitLeaveRequest>,
+) -> AppResult<Json<LeaveRequestResponse>> {
+    tracing::info!("Submit leave request by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_submit_leave_request(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully submit leave request: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/leave/request/cancel",
+    request_body = CancelLeaveRequest,
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success cancel leave request", body = [LeaveRequestResponse]),
+        (status = 400, description = "Leave request can not be cancelled", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Leave request not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_cancel_leave_request(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<CancelLeaveRequest>,
+) -> AppResult<Json<LeaveRequestResponse>> {
+    tracing::info!("Cancel leave request by user: {}.", context.actor_uuid);
+    match service_cancel_leave_request(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully cancel leave request: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/leave/request/approve",
+    request_body = DecideLeaveRequest,
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success approve leave request", body = [LeaveRequestResponse]),
+        (status = 400, description = "Leave request is not pending or not enough balance", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Not the manager or a department head of the employee", body = [ClientResponseError]),
+        (status = 404, description = "Leave request not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_approve_leave_request(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<DecideLeaveRequest>,
+) -> AppResult<Json<LeaveRequestResponse>> {
+    tracing::info!("Approve leave request by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_approve_leave_request(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully approve leave request: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/leave/request/reject",
+    request_body = DecideLeaveRequest,
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success reject leave request", body = [LeaveRequestResponse]),
+        (status = 400, description = "Leave request is not pending", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Not the manager or a department head of the employee", body = [ClientResponseError]),
+        (status = 404, description = "Leave request not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_reject_leave_request(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<DecideLeaveRequest>,
+) -> AppResult<Json<LeaveRequestResponse>> {
+    tracing::info!("Reject leave request by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_reject_leave_request(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully reject leave request: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/leave/request/list",
+    params(LeaveRequestQueryParam, PageQueryParam),
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success get list of leave requests", body = [EntityResponse<Vec<LeaveRequestResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_leave_request(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(query): Query<LeaveRequestQueryParam>,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<LeaveRequestResponse>>>> {
+    tracing::info!("Get list of leave requests by user: {}, parameter: {param:?}.", claims.uuid);
+    match service_get_list_leave_request(&state, query, param).await {
+        Ok(results) => {
+            Ok(Json(EntityResponse::paginated("Get all leave requests success!", results)))
+        },
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of leave requests: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/leave/balance",
+    params(LeaveBalanceQueryParam),
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success get own leave balances", body = [EntityResponse<Vec<LeaveBalanceResponse>>]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "User is not an employee", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_my_leave_balance(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Query(query): Query<LeaveBalanceQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<LeaveBalanceResponse>>>> {
+    tracing::info!("Get own leave balances by user: {}.", context.actor_uuid);
+    match service_get_my_leave_balance(&state, &context, query.as_of).await {
+        Ok(results) => Ok(Json(EntityResponse {
+            message: "Get leave balances success!".to_string(),
+            total: Some(results.len() as u64),
+            data: Some(results),
+            pagination: None,
+        })),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get own leave balances: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/leave/balance/{employee_uuid}",
+    params(
+        ("employee_uuid" = Uuid, Path, description = "uuid of the employee"),
+        LeaveBalanceQueryParam
+    ),
+    tags = ["leave_service"],
+    responses(
+        (status = 200, description = "Success get leave balances of the employee", body = [EntityResponse<Vec<LeaveBalanceResponse>>]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_leave_balance(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(employee_uuid): Path<Uuid>,
+    Query(query): Query<LeaveBalanceQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<LeaveBalanceResponse>>>> {
+    tracing::info!("Get leave balances of {employee_uuid} by user: {}.", claims.uuid);
+    match service_get_leave_balance(&state, employee_uuid, query.as_of).await {
+        Ok(results) => Ok(Json(EntityResponse {
+            message: "Get leave balances success!".to_string(),
+            total: Some(results.len() as u64),
+            data: Some(results),
+            pagination: None,
+        })),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get leave balances: {err:?}.");
+            Err(err)
+        },
+    }
+}
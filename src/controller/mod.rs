@@ -1,35 +1,394 @@
 use crate::core::app_state::AppState;
-use axum::routing::{get, post, put};
+use crate::domain::role::permission::EPermission;
+use crate::infrastructure::middleware::authenticate::RequirePermissionLayer;
+use axum::routing::{delete, get, post, put};
 use axum::Router;
 
 pub mod admin;
+pub mod attendance;
 pub mod auth;
+pub mod calendar;
+pub mod department;
 pub mod employee;
+pub mod leave;
 pub mod openapi;
+pub mod organization;
+pub mod position;
+pub mod role;
 pub mod server;
+pub mod shift;
 pub mod user;
 
-pub fn build_routes() -> Router<AppState> {
+pub fn build_routes(state: &AppState) -> Router<AppState> {
     let server_routes = Router::new()
         .route("/v1/server/health_check", get(server::health_check))
         .route("/v1/server/state", get(server::server_state));
 
     let auth_routes = Router::new()
         .route("/v1/login_by_email", post(auth::controller_login_by_email))
-        .route("/v1/refresh_token", post(auth::controller_refresh_token));
+        .route("/v1/refresh_token", post(auth::controller_refresh_token))
+        .route("/v1/revoke_token", post(auth::controller_revoke_token))
+        .route("/v1/forget_password", get(auth::controller_forget_password))
+        .route("/v1/reset_password", post(auth::controller_reset_password));
 
     let user_routes = Router::new()
-        .route("/v1/admin/create", post(admin::user::controller_admin_create_account))
-        .route("/v1/admin/list", get(admin::user::controller_admin_get_list))
+        .route(
+            "/v1/admin/create",
+            post(admin::user::controller_admin_create_account)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::UserCreate])),
+        )
+        .route(
+            "/v1/admin/list",
+            get(admin::user::controller_admin_get_list)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::UserRead])),
+        )
+        .route(
+            "/v1/admin/export",
+            get(admin::user::controller_admin_export)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::UserRead])),
+        )
+        .route(
+            "/v1/admin/audit_log",
+            get(admin::audit_log::controller_get_list_audit_log)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::AuditLogRead])),
+        )
         .route("/v1/me", get(user::controller_get_profile))
         .route("/v1/me", put(user::controller_update_profile))
-        .route("/v1/logout", post(user::controller_logout));
+        .route("/v1/logout", post(user::controller_logout))
+        .route("/v1/me/sessions", get(user::controller_get_sessions))
+        .route("/v1/me/sessions", delete(user::controller_revoke_all_sessions))
+        .route("/v1/me/sessions/:session_id", delete(user::controller_revoke_session));
 
-    let employee_routes =
-        Router::new().route("/v1/employee/create_by_exist_user", post(employee::create_new_employee_by_user_exist))
-            .route("/v1/employee/new", post(employee::create_new_employee))
-            .route("/v1/employee/update", put(employee::update_employee))
-            .route("/v1/employee/delete", put(employee::delete_employee));
+    let employee_routes = Router::new()
+        .route(
+            "/v1/employee",
+            get(employee::get_list_employee)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeRead])),
+        )
+        .route(
+            "/v1/employee/export",
+            get(employee::export_employees)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeRead])),
+        )
+        .route(
+            "/v1/employee/:employee_uuid",
+            get(employee::get_employee)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeRead])),
+        )
+        .route(
+            "/v1/employee/:employee_uuid/history",
+            get(employee::get_employee_history)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeRead])),
+        )
+        .route(
+            "/v1/employee/:employee_uuid/direct_reports",
+            get(employee::get_direct_reports)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeRead])),
+        )
+        .route(
+            "/v1/employee/:employee_uuid/chain_of_command",
+            get(employee::get_chain_of_command)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeRead])),
+        )
+        .route(
+            "/v1/employee/:employee_uuid/span_of_control",
+            get(employee::get_span_of_control)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeRead])),
+        )
+        .route(
+            "/v1/employee/create_by_exist_user",
+            post(employee::create_new_employee_by_user_exist)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeCreate])),
+        )
+        .route(
+            "/v1/employee/new",
+            post(employee::create_new_employee).route_layer(RequirePermissionLayer::new(
+                state,
+                &[EPermission::EmployeeCreate, EPermission::UserCreate],
+            )),
+        )
+        .route(
+            "/v1/employee/import",
+            post(employee::import_employees).route_layer(RequirePermissionLayer::new(
+                state,
+                &[EPermission::EmployeeCreate, EPermission::UserCreate],
+            )),
+        )
+        .route(
+            "/v1/employee/update",
+            put(employee::update_employee)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeUpdate])),
+        )
+        .route(
+            "/v1/employee/manager",
+            put(employee::assign_manager)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeUpdate])),
+        )
+        .route(
+            "/v1/employee/delete",
+            put(employee::delete_employee)
+                .route_layer(RequirePermissionLayer::new(state, &[EPermission::EmployeeDelete])),
+        );
 
-    Router::new().merge(server_routes).merge(auth_routes).merge(user_routes).merge(employee_routes)
+    let role_read = RequirePermissionLayer::new(state, &[EPermission::RoleRead]);
+    let role_write = RequirePermissionLayer::new(state, &[EPermission::RoleWrite]);
+    let role_routes = Router::new()
+        .route("/v1/role/create", post(role::controller_create_role).route_layer(role_write.clone()))
+        .route("/v1/role/list", get(role::controller_get_list_role).route_layer(role_read.clone()))
+        .route("/v1/role/update", put(role::controller_update_role).route_layer(role_write.clone()))
+        .route("/v1/role/delete", put(role::controller_delete_role).route_layer(role_write))
+        .route("/v1/role/:role_uuid", get(role::controller_get_role).route_layer(role_read.clone()))
+        .route(
+            "/v1/permission/list",
+            get(role::controller_get_list_permission).route_layer(role_read),
+        );
+
+    let department_read = RequirePermissionLayer::new(state, &[EPermission::DepartmentRead]);
+    let department_write = RequirePermissionLayer::new(state, &[EPermission::DepartmentWrite]);
+    let department_routes = Router::new()
+        .route(
+            "/v1/department/create",
+            post(department::controller_create_department).route_layer(department_write.clone()),
+        )
+        .route(
+            "/v1/department/list",
+            get(department::controller_get_list_department).route_layer(department_read.clone()),
+        )
+        .route(
+            "/v1/department/update",
+            put(department::controller_update_department).route_layer(department_write.clone()),
+        )
+        .route(
+            "/v1/department/delete",
+            put(department::controller_delete_department).route_layer(department_write.clone()),
+        )
+        .route(
+            "/v1/department/move",
+            put(department::controller_move_department).route_layer(department_write),
+        )
+        .route(
+            "/v1/department/tree",
+            get(department::controller_get_department_tree).route_layer(department_read.clone()),
+        )
+        .route(
+            "/v1/department/:department_uuid/subtree",
+            get(department::controller_get_department_subtree)
+                .route_layer(department_read.clone()),
+        )
+        .route(
+            "/v1/department/:department_uuid/ancestors",
+            get(department::controller_get_department_ancestors)
+                .route_layer(department_read.clone()),
+        )
+        .route(
+            "/v1/department/:department_uuid",
+            get(department::controller_get_department).route_layer(department_read),
+        );
+
+    let position_read = RequirePermissionLayer::new(state, &[EPermission::PositionRead]);
+    let position_write = RequirePermissionLayer::new(state, &[EPermission::PositionWrite]);
+    let position_routes = Router::new()
+        .route(
+            "/v1/position/create",
+            post(position::controller_create_position).route_layer(position_write.clone()),
+        )
+        .route(
+            "/v1/position/list",
+            get(position::controller_get_list_position).route_layer(position_read),
+        )
+        .route(
+            "/v1/position/update",
+            put(position::controller_update_position).route_layer(position_write.clone()),
+        )
+        .route(
+            "/v1/position/deactivate",
+            put(position::controller_deactivate_position).route_layer(position_write),
+        );
+
+    let organization_read = RequirePermissionLayer::new(state, &[EPermission::OrganizationRead]);
+    let organization_write = RequirePermissionLayer::new(state, &[EPermission::OrganizationWrite]);
+    let organization_routes = Router::new()
+        .route(
+            "/v1/organization/create",
+            post(organization::controller_create_organization)
+                .route_layer(organization_write.clone()),
+        )
+        .route(
+            "/v1/organization/list",
+            get(organization::controller_get_list_organization)
+                .route_layer(organization_read.clone()),
+        )
+        .route(
+            "/v1/organization/update",
+            put(organization::controller_update_organization).route_layer(organization_write),
+        )
+        .route(
+            "/v1/organization/:organization_uuid/org_chart",
+            get(organization::controller_get_org_chart).route_layer(RequirePermissionLayer::new(
+                state,
+                &[EPermission::OrganizationRead, EPermission::EmployeeRead],
+            )),
+        )
+        .route(
+            "/v1/organization/:organization_uuid",
+            get(organization::controller_get_organization).route_layer(organization_read),
+        );
+
+    let leave_read = RequirePermissionLayer::new(state, &[EPermission::LeaveRead]);
+    let leave_write = RequirePermissionLayer::new(state, &[EPermission::LeaveWrite]);
+    let leave_request = RequirePermissionLayer::new(state, &[EPermission::LeaveRequest]);
+    let leave_approve = RequirePermissionLayer::new(state, &[EPermission::LeaveApprove]);
+    let leave_routes = Router::new()
+        .route(
+            "/v1/leave/type/create",
+            post(leave::controller_create_leave_type).route_layer(leave_write.clone()),
+        )
+        .route(
+            "/v1/leave/type/update",
+            put(leave::controller_update_leave_type).route_layer(leave_write),
+        )
+        .route(
+            "/v1/leave/type/list",
+            get(leave::controller_get_list_leave_type).route_layer(leave_read.clone()),
+        )
+        .route(
+            "/v1/leave/request/submit",
+            post(leave::controller_submit_leave_request).route_layer(leave_request.clone()),
+        )
+        .route(
+            "/v1/leave/request/cancel",
+            put(leave::controller_cancel_leave_request).route_layer(leave_request.clone()),
+        )
+        .route(
+            "/v1/leave/request/approve",
+            put(leave::controller_approve_leave_request).route_layer(leave_approve.clone()),
+        )
+        .route(
+            "/v1/leave/request/reject",
+            put(leave::controller_reject_leave_request).route_layer(leave_approve),
+        )
+        .route(
+            "/v1/leave/request/list",
+            get(leave::controller_get_list_leave_request).route_layer(leave_read.clone()),
+        )
+        .route(
+            "/v1/leave/balance",
+            get(leave::controller_get_my_leave_balance).route_layer(leave_request),
+        )
+        .route(
+            "/v1/leave/balance/:employee_uuid",
+            get(leave::controller_get_leave_balance).route_layer(leave_read),
+        );
+
+    let attendance_read = RequirePermissionLayer::new(state, &[EPermission::AttendanceRead]);
+    let attendance_record = RequirePermissionLayer::new(state, &[EPermission::AttendanceRecord]);
+    let attendance_manage = RequirePermissionLayer::new(state, &[EPermission::AttendanceManage]);
+    let attendance_routes = Router::new()
+        .route(
+            "/v1/attendance/clock",
+            post(attendance::controller_clock).route_layer(attendance_record.clone()),
+        )
+        .route(
+            "/v1/attendance/timesheet",
+            get(attendance::controller_get_my_timesheet).route_layer(attendance_record),
+        )
+        .route(
+            "/v1/attendance/timesheet/:employee_uuid",
+            get(attendance::controller_get_timesheet).route_layer(attendance_read.clone()),
+        )
+        .route(
+            "/v1/attendance/correction/create",
+            post(attendance::controller_create_attendance_correction)
+                .route_layer(attendance_manage.clone()),
+        )
+        .route(
+            "/v1/attendance/correction/update",
+            put(attendance::controller_update_attendance_correction)
+                .route_layer(attendance_manage.clone()),
+        )
+        .route(
+            "/v1/attendance/correction/delete",
+            put(attendance::controller_delete_attendance_correction)
+                .route_layer(attendance_manage),
+        )
+        .route(
+            "/v1/attendance/summary/employee/:employee_uuid",
+            get(attendance::controller_get_employee_attendance_summary)
+                .route_layer(attendance_read.clone()),
+        )
+        .route(
+            "/v1/attendance/summary/department/:department_uuid",
+            get(attendance::controller_get_department_attendance_summary)
+                .route_layer(attendance_read),
+        );
+
+    let shift_read = RequirePermissionLayer::new(state, &[EPermission::ShiftRead]);
+    let shift_write = RequirePermissionLayer::new(state, &[EPermission::ShiftWrite]);
+    let shift_routes = Router::new()
+        .route(
+            "/v1/shift/template/create",
+            post(shift::controller_create_shift_template).route_layer(shift_write.clone()),
+        )
+        .route(
+            "/v1/shift/template/update",
+            put(shift::controller_update_shift_template).route_layer(shift_write.clone()),
+        )
+        .route(
+            "/v1/shift/template/list",
+            get(shift::controller_get_list_shift_template).route_layer(shift_read.clone()),
+        )
+        .route(
+            "/v1/shift/assignment/check",
+            post(shift::controller_check_shift_assignment).route_layer(shift_write.clone()),
+        )
+        .route(
+            "/v1/shift/assignment/create",
+            post(shift::controller_create_shift_assignment).route_layer(shift_write.clone()),
+        )
+        .route(
+            "/v1/shift/assignment/delete",
+            put(shift::controller_delete_shift_assignment).route_layer(shift_write),
+        )
+        .route(
+            "/v1/shift/roster/:department_uuid",
+            get(shift::controller_get_roster).route_layer(shift_read),
+        );
+
+    let calendar_read = RequirePermissionLayer::new(state, &[EPermission::CalendarRead]);
+    let calendar_write = RequirePermissionLayer::new(state, &[EPermission::CalendarWrite]);
+    let calendar_routes = Router::new()
+        .route(
+            "/v1/calendar/holiday/create",
+            post(calendar::controller_create_holiday).route_layer(calendar_write.clone()),
+        )
+        .route(
+            "/v1/calendar/holiday/update",
+            put(calendar::controller_update_holiday).route_layer(calendar_write.clone()),
+        )
+        .route(
+            "/v1/calendar/holiday/delete",
+            put(calendar::controller_delete_holiday).route_layer(calendar_write),
+        )
+        .route(
+            "/v1/calendar/holiday/list",
+            get(calendar::controller_get_list_holiday).route_layer(calendar_read.clone()),
+        )
+        .route(
+            "/v1/calendar/working_days/:employee_uuid",
+            get(calendar::controller_get_working_days).route_layer(calendar_read),
+        );
+
+    Router::new()
+        .merge(server_routes)
+        .merge(auth_routes)
+        .merge(user_routes)
+        .merge(employee_routes)
+        .merge(role_routes)
+        .merge(department_routes)
+        .merge(position_routes)
+        .merge(organization_routes)
+        .merge(leave_routes)
+        .merge(attendance_routes)
+        .merge(shift_routes)
+        .merge(calendar_routes)
 }
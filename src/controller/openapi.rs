@@ -1,14 +1,81 @@
 use crate::core::response::{
-    ClientResponseError, EntityResponse, MessageResponse, ServiceStatusResponse,
+    ClientResponseError, EntityResponse, MessageResponse, PageResponse, ServiceStatusResponse,
 };
-use crate::domain::authenticate::request::{LoginByEmailRequest, RefreshTokenRequest};
-use crate::domain::authenticate::response::{LoginResponse, TokenResponse};
+use crate::domain::attendance::request::{
+    AttendanceSummaryQueryParam, ClockRequest, CreateAttendanceCorrectionRequest,
+    DeleteAttendanceCorrectionRequest, TimesheetQueryParam, UpdateAttendanceCorrectionRequest,
+};
+use crate::domain::attendance::response::{
+    AttendanceEventResponse, AttendanceSummary, DailyTimesheetResponse,
+    DepartmentAttendanceSummaryResponse, EmployeeAttendanceSummaryResponse,
+    EmployeeTimesheetResponse,
+};
+use crate::domain::attendance::EAttendanceEventType;
+use crate::domain::audit_log::request::AuditLogQueryParam;
+use crate::domain::audit_log::response::AuditLogResponse;
+use crate::domain::audit_log::{EAuditAction, EAuditEntity};
+use crate::domain::calendar::request::{
+    CreateHolidayRequest, DeleteHolidayRequest, HolidayQueryParam, UpdateHolidayRequest,
+    WorkingDaysQueryParam,
+};
+use crate::domain::calendar::response::{
+    HolidayDateResponse, HolidayResponse, WorkingDaysResponse,
+};
+use crate::domain::authenticate::request::{
+    ForgetPasswordQueryParam, LoginByEmailRequest, RefreshTokenRequest, ResetPasswordRequest,
+};
+use crate::domain::authenticate::response::{
+    ForgetPasswordResponse, LoginResponse, SessionResponse, TokenResponse,
+};
+use crate::domain::department::request::{
+    CreateDepartmentRequest, DeleteDepartmentRequest, DepartmentQueryParam,
+    MoveDepartmentRequest, UpdateDepartmentRequest,
+};
+use crate::domain::department::response::{DepartmentResponse, DepartmentTreeResponse};
 use crate::domain::employee::request::{
-    CreateNewEmployeeByUserUuidRequest, CreateNewEmployeeRequest, DeleteEmployeeRequest,
+    AssignManagerRequest, CreateNewEmployeeByUserUuidRequest, CreateNewEmployeeRequest,
+    DeleteEmployeeRequest, EmployeeImportFileRequest, EmployeeImportQueryParam, EmployeeQueryParam,
     UpdateEmployeeRequest,
 };
+use crate::domain::employee::response::{
+    EAssignmentType, EmployeeAssignmentResponse, EmployeeDepartmentResponse,
+    EmployeeDetailResponse, EmployeeExportRow, EmployeeImportResponse, EmployeeImportRowError,
+    EmployeePositionResponse, EmployeeResponse, EmployeeUserResponse, SpanOfControlResponse,
+};
+use crate::domain::leave::request::{
+    CancelLeaveRequest, CreateLeaveTypeRequest, DecideLeaveRequest, LeaveBalanceQueryParam,
+    LeaveRequestQueryParam, LeaveTypeQueryParam, SubmitLeaveRequest, UpdateLeaveTypeRequest,
+};
+use crate::domain::leave::response::{
+    LeaveBalanceResponse, LeaveRequestResponse, LeaveTypeResponse,
+};
+use crate::domain::leave::{EAccrualPeriod, ELeaveRequestStatus};
+use crate::domain::organization::request::{
+    CreateOrganizationRequest, EOrgChartFormat, OrgChartQueryParam, UpdateOrganizationRequest,
+};
+use crate::domain::organization::response::{
+    DepartmentNodeResponse, EOrgChartNodeType, OrgChartNodeResponse, OrganizationDetailResponse,
+    OrganizationResponse, OrganizationSettingResponse, PositionNodeResponse,
+};
+use crate::domain::organization::EWeekday;
+use crate::domain::position::request::{
+    CreatePositionRequest, DeactivatePositionRequest, PositionQueryParam, UpdatePositionRequest,
+};
+use crate::domain::position::response::PositionResponse;
+use crate::domain::role::permission::EPermission;
+use crate::domain::role::request::{CreateRoleRequest, DeleteRoleRequest, UpdateRoleRequest};
+use crate::domain::role::response::{PermissionResponse, RoleResponse};
+use crate::domain::shift::request::{
+    CreateShiftAssignmentRequest, CreateShiftTemplateRequest, DeleteShiftAssignmentRequest,
+    RosterQueryParam, ShiftTemplateQueryParam, UpdateShiftTemplateRequest,
+};
+use crate::domain::shift::response::{
+    EShiftConflictKind, RosterDayResponse, RosterEmployeeResponse, RosterResponse,
+    RosterShiftResponse, ShiftAssignmentResponse, ShiftConflictResponse, ShiftTemplateResponse,
+};
 use crate::domain::user::request::{AdminCreateAccountRequest, UpdateProfileRequest};
 use crate::domain::user::response::PublicProfileResponse;
+use crate::util::export::{EExportFormat, ExportQueryParam};
 use crate::util::filter_and_pagination::{Direction, PageQueryParam};
 use utoipa::{
     openapi::security::{Http, HttpAuthScheme, SecurityScheme},
@@ -28,39 +95,247 @@ use utoipa::{
         // auth api
         crate::controller::auth::controller_login_by_email,
         crate::controller::auth::controller_refresh_token,
+        crate::controller::auth::controller_revoke_token,
+        crate::controller::auth::controller_forget_password,
+        crate::controller::auth::controller_reset_password,
         // user api
         crate::controller::admin::user::controller_admin_create_account,
         crate::controller::admin::user::controller_admin_get_list,
+        crate::controller::admin::user::controller_admin_export,
         crate::controller::user::controller_get_profile,
         crate::controller::user::controller_update_profile,
         crate::controller::user::controller_logout,
+        crate::controller::user::controller_get_sessions,
+        crate::controller::user::controller_revoke_session,
+        crate::controller::user::controller_revoke_all_sessions,
 
         // employee api
         crate::controller::employee::create_new_employee_by_user_exist,
         crate::controller::employee::create_new_employee,
+        crate::controller::employee::import_employees,
         crate::controller::employee::update_employee,
         crate::controller::employee::delete_employee,
+        crate::controller::employee::get_list_employee,
+        crate::controller::employee::export_employees,
+        crate::controller::employee::get_employee,
+        crate::controller::employee::get_employee_history,
+        crate::controller::employee::assign_manager,
+        crate::controller::employee::get_direct_reports,
+        crate::controller::employee::get_chain_of_command,
+        crate::controller::employee::get_span_of_control,
+
+        // role api
+        crate::controller::role::controller_create_role,
+        crate::controller::role::controller_get_list_role,
+        crate::controller::role::controller_get_role,
+        crate::controller::role::controller_update_role,
+        crate::controller::role::controller_delete_role,
+        crate::controller::role::controller_get_list_permission,
+
+        // department api
+        crate::controller::department::controller_create_department,
+        crate::controller::department::controller_get_list_department,
+        crate::controller::department::controller_get_department,
+        crate::controller::department::controller_update_department,
+        crate::controller::department::controller_delete_department,
+        crate::controller::department::controller_move_department,
+        crate::controller::department::controller_get_department_tree,
+        crate::controller::department::controller_get_department_subtree,
+        crate::controller::department::controller_get_department_ancestors,
+
+        // position api
+        crate::controller::position::controller_create_position,
+        crate::controller::position::controller_get_list_position,
+        crate::controller::position::controller_update_position,
+        crate::controller::position::controller_deactivate_position,
+
+        // organization api
+        crate::controller::organization::controller_create_organization,
+        crate::controller::organization::controller_get_list_organization,
+        crate::controller::organization::controller_get_organization,
+        crate::controller::organization::controller_update_organization,
+        crate::controller::organization::controller_get_org_chart,
+
+        // leave api
+        crate::controller::leave::controller_create_leave_type,
+        crate::controller::leave::controller_update_leave_type,
+        crate::controller::leave::controller_get_list_leave_type,
+        crate::controller::leave::controller_submit_leave_request,
+        crate::controller::leave::controller_cancel_leave_request,
+        crate::controller::leave::controller_approve_leave_request,
+        crate::controller::leave::controller_reject_leave_request,
+        crate::controller::leave::controller_get_list_leave_request,
+        crate::controller::leave::controller_get_my_leave_balance,
+        crate::controller::leave::controller_get_leave_balance,
+
+        // attendance api
+        crate::controller::attendance::controller_clock,
+        crate::controller::attendance::controller_get_my_timesheet,
+        crate::controller::attendance::controller_get_timesheet,
+        crate::controller::attendance::controller_create_attendance_correction,
+        crate::controller::attendance::controller_update_attendance_correction,
+        crate::controller::attendance::controller_delete_attendance_correction,
+        crate::controller::attendance::controller_get_employee_attendance_summary,
+        crate::controller::attendance::controller_get_department_attendance_summary,
+
+        // shift api
+        crate::controller::shift::controller_create_shift_template,
+        crate::controller::shift::controller_update_shift_template,
+        crate::controller::shift::controller_get_list_shift_template,
+        crate::controller::shift::controller_check_shift_assignment,
+        crate::controller::shift::controller_create_shift_assignment,
+        crate::controller::shift::controller_delete_shift_assignment,
+        crate::controller::shift::controller_get_roster,
+
+        // calendar api
+        crate::controller::calendar::controller_create_holiday,
+        crate::controller::calendar::controller_update_holiday,
+        crate::controller::calendar::controller_delete_holiday,
+        crate::controller::calendar::controller_get_list_holiday,
+        crate::controller::calendar::controller_get_working_days,
+
+        // audit log api
+        crate::controller::admin::audit_log::controller_get_list_audit_log,
     ),
     components(
         schemas(
             // request
             LoginByEmailRequest,
             RefreshTokenRequest,
+            ForgetPasswordQueryParam,
+            ResetPasswordRequest,
             AdminCreateAccountRequest,
             UpdateProfileRequest,
             CreateNewEmployeeRequest,
             CreateNewEmployeeByUserUuidRequest,
             UpdateEmployeeRequest,
             DeleteEmployeeRequest,
+            AssignManagerRequest,
+            EmployeeQueryParam,
+            EmployeeImportQueryParam,
+            EmployeeImportFileRequest,
+            CreateRoleRequest,
+            UpdateRoleRequest,
+            DeleteRoleRequest,
+            CreateDepartmentRequest,
+            UpdateDepartmentRequest,
+            DeleteDepartmentRequest,
+            MoveDepartmentRequest,
+            DepartmentQueryParam,
+            CreatePositionRequest,
+            UpdatePositionRequest,
+            DeactivatePositionRequest,
+            PositionQueryParam,
+            CreateOrganizationRequest,
+            UpdateOrganizationRequest,
+            OrgChartQueryParam,
+            EOrgChartFormat,
+            EWeekday,
+            CreateLeaveTypeRequest,
+            UpdateLeaveTypeRequest,
+            LeaveTypeQueryParam,
+            SubmitLeaveRequest,
+            CancelLeaveRequest,
+            DecideLeaveRequest,
+            LeaveRequestQueryParam,
+            LeaveBalanceQueryParam,
+            EAccrualPeriod,
+            ELeaveRequestStatus,
+            ClockRequest,
+            TimesheetQueryParam,
+            AttendanceSummaryQueryParam,
+            CreateAttendanceCorrectionRequest,
+            UpdateAttendanceCorrectionRequest,
+            DeleteAttendanceCorrectionRequest,
+            EAttendanceEventType,
+            CreateShiftTemplateRequest,
+            UpdateShiftTemplateRequest,
+            ShiftTemplateQueryParam,
+            CreateShiftAssignmentRequest,
+            DeleteShiftAssignmentRequest,
+            RosterQueryParam,
+            CreateHolidayRequest,
+            UpdateHolidayRequest,
+            DeleteHolidayRequest,
+            HolidayQueryParam,
+            WorkingDaysQueryParam,
+            EPermission,
+            AuditLogQueryParam,
+            EAuditAction,
+            EAuditEntity,
             Direction,
             PageQueryParam,
+            ExportQueryParam,
+            EExportFormat,
 
             // response
             LoginResponse,
             TokenResponse,
+            ForgetPasswordResponse,
+            SessionResponse,
             MessageResponse,
+            PageResponse,
             EntityResponse<Vec<PublicProfileResponse>>,
             PublicProfileResponse,
+            EmployeeResponse,
+            EmployeeDetailResponse,
+            EmployeeUserResponse,
+            EmployeeExportRow,
+            EmployeeDepartmentResponse,
+            EmployeePositionResponse,
+            EAssignmentType,
+            EmployeeAssignmentResponse,
+            SpanOfControlResponse,
+            EmployeeImportRowError,
+            EmployeeImportResponse,
+            EntityResponse<Vec<EmployeeResponse>>,
+            EntityResponse<Vec<EmployeeAssignmentResponse>>,
+            RoleResponse,
+            PermissionResponse,
+            EntityResponse<Vec<RoleResponse>>,
+            EntityResponse<Vec<PermissionResponse>>,
+            DepartmentResponse,
+            EntityResponse<Vec<DepartmentResponse>>,
+            DepartmentTreeResponse,
+            EntityResponse<Vec<DepartmentTreeResponse>>,
+            PositionResponse,
+            EntityResponse<Vec<PositionResponse>>,
+            OrganizationSettingResponse,
+            OrganizationResponse,
+            OrganizationDetailResponse,
+            DepartmentNodeResponse,
+            PositionNodeResponse,
+            EOrgChartNodeType,
+            OrgChartNodeResponse,
+            EntityResponse<Vec<OrganizationResponse>>,
+            LeaveTypeResponse,
+            EntityResponse<Vec<LeaveTypeResponse>>,
+            LeaveRequestResponse,
+            EntityResponse<Vec<LeaveRequestResponse>>,
+            LeaveBalanceResponse,
+            EntityResponse<Vec<LeaveBalanceResponse>>,
+            AttendanceEventResponse,
+            DailyTimesheetResponse,
+            AttendanceSummary,
+            EmployeeTimesheetResponse,
+            EmployeeAttendanceSummaryResponse,
+            DepartmentAttendanceSummaryResponse,
+            ShiftTemplateResponse,
+            EntityResponse<Vec<ShiftTemplateResponse>>,
+            ShiftAssignmentResponse,
+            EShiftConflictKind,
+            ShiftConflictResponse,
+            EntityResponse<Vec<ShiftConflictResponse>>,
+            RosterEmployeeResponse,
+            RosterShiftResponse,
+            RosterDayResponse,
+            RosterResponse,
+            HolidayResponse,
+            EntityResponse<Vec<HolidayResponse>>,
+            HolidayDateResponse,
+            WorkingDaysResponse,
+            AuditLogResponse,
+            EntityResponse<Vec<AuditLogResponse>>,
             ServiceStatusResponse,
             ClientResponseError,
         )
@@ -70,6 +345,15 @@ use utoipa::{
         (name = "auth_service", description = "authenticate endpoints."),
         (name = "user_service", description = "user endpoints."),
         (name = "employee_service", description = "employee endpoints."),
+        (name = "role_service", description = "role and permission endpoints."),
+        (name = "department_service", description = "department endpoints."),
+        (name = "position_service", description = "position endpoints."),
+        (name = "organization_service", description = "organization endpoints."),
+        (name = "leave_service", description = "leave endpoints."),
+        (name = "attendance_service", description = "attendance endpoints."),
+        (name = "shift_service", description = "shift and roster endpoints."),
+        (name = "calendar_service", description = "holiday and working day endpoints."),
+        (name = "audit_log_service", description = "audit log endpoints."),
     ),
     modifiers(&SecurityAddon)
 )]
@@ -0,0 +1,197 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::organization::org_chart::{render_dot, render_svg};
+use crate::domain::organization::request::{
+    CreateOrganizationRequest, EOrgChartFormat, OrgChartQueryParam, UpdateOrganizationRequest,
+};
+use crate::domain::organization::response::{
+    OrgChartNodeResponse, OrganizationDetailResponse, OrganizationResponse,
+};
+use crate::domain::organization::services::{
+    service_create_organization, service_get_list_organization, service_get_org_chart,
+    service_get_organization, service_update_organization,
+};
+use crate::util::claim::UserClaims;
+use crate::util::filter_and_pagination::PageQueryParam;
+use axum::extract::{Path, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use axum_extra::extract::Query;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/v1/organization/create",
+    request_body = CreateOrganizationRequest,
+    tags = ["organization_service"],
+    responses(
+        (status = 200, description = "Success create new organization", body = [OrganizationResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 409, description = "Organization already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_organization(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Json(req): Json<CreateOrganizationRequest>,
+) -> AppResult<Json<OrganizationResponse>> {
+    tracing::info!("Create new organization by user: {}.", claims.uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_create_organization(&state, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully create new organization: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/organization/list",
+    params(PageQueryParam),
+    tags = ["organization_service"],
+    responses(
+        (status = 200, description = "Success get list of organizations", body = [EntityResponse<Vec<OrganizationResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_organization(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<OrganizationResponse>>>> {
+    tracing::info!("Get list of organizations by user: {}, parameter: {param:?}.", claims.uuid);
+    match service_get_list_organization(&state, param).await {
+        Ok(results) => {
+            Ok(Json(EntityResponse::paginated("Get all organizations success!", results)))
+        },
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of organizations: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/organization/{organization_uuid}",
+    params(("organization_uuid" = Uuid, Path, description = "uuid of the organization")),
+    tags = ["organization_service"],
+    responses(
+        (status = 200, description = "Success get organization with its departments and positions", body = [OrganizationDetailResponse]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_organization(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(organization_uuid): Path<Uuid>,
+) -> AppResult<Json<OrganizationDetailResponse>> {
+    tracing::info!("Get organization {organization_uuid} by user: {}.", claims.uuid);
+    match service_get_organization(&state, organization_uuid).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get organization: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/organization/update",
+    request_body = UpdateOrganizationRequest,
+    tags = ["organization_service"],
+    responses(
+        (status = 200, description = "Success update organization", body = [OrganizationResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 409, description = "Organization already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_organization(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Json(req): Json<UpdateOrganizationRequest>,
+) -> AppResult<Json<OrganizationResponse>> {
+    tracing::info!("Update organization by user: {}.", claims.uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_update_organization(&state, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully update organization: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/organization/{organization_uuid}/org_chart",
+    params(
+        ("organization_uuid" = Uuid, Path, description = "uuid of the organization"),
+        OrgChartQueryParam
+    ),
+    tags = ["organization_service"],
+    responses(
+        (status = 200, description = "Success get org chart as json, graphviz dot or svg", body = [OrgChartNodeResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Organization not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_org_chart(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(organization_uuid): Path<Uuid>,
+    Query(query): Query<OrgChartQueryParam>,
+) -> AppResult<Response> {
+    tracing::info!("Get org chart of {organization_uuid} by user: {}.", claims.uuid);
+    if query.validate().is_err() {
+        return Err(AppError::BadRequestError(query.validate().unwrap_err().to_string()));
+    }
+    match service_get_org_chart(&state, organization_uuid, query.depth).await {
+        Ok(chart) => Ok(match query.format {
+            EOrgChartFormat::Json => Json(chart).into_response(),
+            EOrgChartFormat::Dot => {
+                ([(header::CONTENT_TYPE, "text/vnd.graphviz; charset=utf-8")], render_dot(&chart))
+                    .into_response()
+            },
+            EOrgChartFormat::Svg => {
+                ([(header::CONTENT_TYPE, "image/svg+xml; charset=utf-8")], render_svg(&chart))
+                    .into_response()
+            },
+        }),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get org chart: {err:?}.");
+            Err(err)
+        },
+    }
+}
@@ -0,0 +1,152 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::audit_log::AuditContext;
+use crate::domain::position::request::{
+    CreatePositionRequest, DeactivatePositionRequest, PositionQueryParam, UpdatePositionRequest,
+};
+use crate::domain::position::response::PositionResponse;
+use crate::domain::position::services::{
+    service_create_position, service_deactivate_position, service_get_list_position,
+    service_update_position,
+};
+use crate::util::claim::UserClaims;
+use crate::util::filter_and_pagination::PageQueryParam;
+use axum::extract::State;
+use axum::Json;
+use axum_extra::extract::Query;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/v1/position/create",
+    request_body = CreatePositionRequest,
+    tags = ["position_service"],
+    responses(
+        (status = 200, description = "Success create new position", body = [PositionResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 409, description = "Position already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_position(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<CreatePositionRequest>,
+) -> AppResult<Json<PositionResponse>> {
+    tracing::info!("Create new position by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_create_position(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully create new position: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/position/list",
+    params(PositionQueryParam, PageQueryParam),
+    tags = ["position_service"],
+    responses(
+        (status = 200, description = "Success get list of positions", body = [EntityResponse<Vec<PositionResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_position(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(query): Query<PositionQueryParam>,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<PositionResponse>>>> {
+    tracing::info!("Get list of positions by user: {}, parameter: {param:?}.", claims.uuid);
+    match service_get_list_position(&state, query.department_uuid, param).await {
+        Ok(results) => Ok(Json(EntityResponse::paginated("Get all positions success!", results))),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of positions: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/position/update",
+    request_body = UpdatePositionRequest,
+    tags = ["position_service"],
+    responses(
+        (status = 200, description = "Success update position", body = [PositionResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Position not found", body = [ClientResponseError]),
+        (status = 409, description = "Position already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_position(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<UpdatePositionRequest>,
+) -> AppResult<Json<PositionResponse>> {
+    tracing::info!("Update position by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_update_position(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully update position: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/position/deactivate",
+    request_body = DeactivatePositionRequest,
+    tags = ["position_service"],
+    responses(
+        (status = 200, description = "Success deactivate position", body = [EntityResponse<i64>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Position not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_deactivate_position(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<DeactivatePositionRequest>,
+) -> AppResult<Json<EntityResponse<i64>>> {
+    tracing::info!("Deactivate position by user: {}.", context.actor_uuid);
+    match service_deactivate_position(&state, &context, req).await {
+        Ok(value) => Ok(Json(EntityResponse {
+            message: "Deactivate position success!".to_string(),
+            data: Some(value),
+            total: Some(1),
+            pagination: None,
+        })),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully deactivate position: {err:?}.");
+            Err(err)
+        },
+    }
+}
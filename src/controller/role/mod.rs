@@ -0,0 +1,203 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::role::request::{CreateRoleRequest, DeleteRoleRequest, UpdateRoleRequest};
+use crate::domain::role::response::{PermissionResponse, RoleResponse};
+use crate::domain::role::services::{
+    service_create_role, service_delete_role, service_get_list_permission, service_get_list_role,
+    service_get_role, service_update_role,
+};
+use crate::util::claim::UserClaims;
+use crate::util::filter_and_pagination::PageQueryParam;
+use axum::extract::{Path, State};
+use axum::Json;
+use axum_extra::extract::Query;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/v1/role/create",
+    request_body = CreateRoleRequest,
+    tags = ["role_service"],
+    responses(
+        (status = 200, description = "Success create new role", body = [RoleResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 409, description = "Role already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_role(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Json(req): Json<CreateRoleRequest>,
+) -> AppResult<Json<RoleResponse>> {
+    tracing::info!("Create new role by user: {}.", claims.uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_create_role(&state, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully create new role: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/role/list",
+    params(PageQueryParam),
+    tags = ["role_service"],
+    responses(
+        (status = 200, description = "Success get list of roles", body = [EntityResponse<Vec<RoleResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_role(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<RoleResponse>>>> {
+    tracing::info!("Get list of roles by user: {}, parameter: {param:?}.", claims.uuid);
+    match service_get_list_role(&state, param).await {
+        Ok(results) => Ok(Json(EntityResponse::paginated("Get all roles success!", results))),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of roles: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/role/{role_uuid}",
+    params(("role_uuid" = Uuid, Path, description = "uuid of the role")),
+    tags = ["role_service"],
+    responses(
+        (status = 200, description = "Success get role", body = [RoleResponse]),
+        (status = 400, description = "Role not found", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_role(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(role_uuid): Path<Uuid>,
+) -> AppResult<Json<RoleResponse>> {
+    tracing::info!("Get role {role_uuid} by user: {}.", claims.uuid);
+    match service_get_role(&state, role_uuid).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get role: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/role/update",
+    request_body = UpdateRoleRequest,
+    tags = ["role_service"],
+    responses(
+        (status = 200, description = "Success update role", body = [RoleResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 409, description = "Role already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_role(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Json(req): Json<UpdateRoleRequest>,
+) -> AppResult<Json<RoleResponse>> {
+    tracing::info!("Update role by user: {}.", claims.uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_update_role(&state, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully update role: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/role/delete",
+    request_body = DeleteRoleRequest,
+    tags = ["role_service"],
+    responses(
+        (status = 200, description = "Success delete role", body = [EntityResponse<i64>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_delete_role(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Json(req): Json<DeleteRoleRequest>,
+) -> AppResult<Json<EntityResponse<i64>>> {
+    tracing::info!("Delete role by user: {}.", claims.uuid);
+    match service_delete_role(&state, req).await {
+        Ok(value) => Ok(Json(EntityResponse {
+            message: "Delete role success!".to_string(),
+            data: Some(value),
+            total: Some(1),
+            pagination: None,
+        })),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully delete role: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/permission/list",
+    tags = ["role_service"],
+    responses(
+        (status = 200, description = "Success get list of permissions", body = [EntityResponse<Vec<PermissionResponse>>]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_permission(
+    State(state): State<AppState>,
+    claims: UserClaims,
+) -> AppResult<Json<EntityResponse<Vec<PermissionResponse>>>> {
+    tracing::info!("Get list of permissions by user: {}.", claims.uuid);
+    match service_get_list_permission(&state).await {
+        Ok(results) => Ok(Json(EntityResponse {
+            message: "Get all permissions success!".to_string(),
+            total: Some(results.len() as u64),
+            pagination: None,
+            data: Some(results),
+        })),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of permissions: {err:?}.");
+            Err(err)
+        },
+    }
+}
@@ -0,0 +1,263 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::core::response::{ClientResponseError, EntityResponse};
+use crate::domain::audit_log::AuditContext;
+use crate::domain::shift::request::{
+    CreateShiftAssignmentRequest, CreateShiftTemplateRequest, DeleteShiftAssignmentRequest,
+    RosterQueryParam, ShiftTemplateQueryParam, UpdateShiftTemplateRequest,
+};
+use crate::domain::shift::response::{
+    RosterResponse, ShiftAssignmentResponse, ShiftConflictResponse, ShiftTemplateResponse,
+};
+use crate::domain::shift::services::{
+    service_check_shift_assignment, service_create_shift_assignment,
+    service_create_shift_template, service_delete_shift_assignment,
+    service_get_list_shift_template, service_get_roster, service_update_shift_template,
+};
+use crate::util::claim::UserClaims;
+use crate::util::filter_and_pagination::PageQueryParam;
+use axum::extract::{Path, State};
+use axum::Json;
+use axum_extra::extract::Query;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/v1/shift/template/create",
+    request_body = CreateShiftTemplateRequest,
+    tags = ["shift_service"],
+    responses(
+        (status = 200, description = "Success create new shift template", body = [ShiftTemplateResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 409, description = "Shift template already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_shift_template(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<CreateShiftTemplateRequest>,
+) -> AppResult<Json<ShiftTemplateResponse>> {
+    tracing::info!("Create new shift template by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_create_shift_template(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully create new shift template: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/shift/template/update",
+    request_body = UpdateShiftTemplateRequest,
+    tags = ["shift_service"],
+    responses(
+        (status = 200, description = "Success update shift template", body = [ShiftTemplateResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Shift template not found", body = [ClientResponseError]),
+        (status = 409, description = "Shift template already exists", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_shift_template(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<UpdateShiftTemplateRequest>,
+) -> AppResult<Json<ShiftTemplateResponse>> {
+    tracing::info!("Update shift template by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_update_shift_template(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully update shift template: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/shift/template/list",
+    params(ShiftTemplateQueryParam, PageQueryParam),
+    tags = ["shift_service"],
+    responses(
+        (status = 200, description = "Success get list of shift templates", body = [EntityResponse<Vec<ShiftTemplateResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_list_shift_template(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Query(query): Query<ShiftTemplateQueryParam>,
+    Query(param): Query<PageQueryParam>,
+) -> AppResult<Json<EntityResponse<Vec<ShiftTemplateResponse>>>> {
+    tracing::info!("Get list of shift templates by user: {}, parameter: {param:?}.", claims.uuid);
+    match service_get_list_shift_template(&state, query, param).await {
+        Ok(results) => {
+            Ok(Json(EntityResponse::paginated("Get all shift templates success!", results)))
+        },
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get list of shift templates: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/shift/assignment/check",
+    request_body = CreateShiftAssignmentRequest,
+    tags = ["shift_service"],
+    responses(
+        (status = 200, description = "Conflicts the assignment would have, empty when none", body = [EntityResponse<Vec<ShiftConflictResponse>>]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Shift template or employee not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_check_shift_assignment(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Json(req): Json<CreateShiftAssignmentRequest>,
+) -> AppResult<Json<EntityResponse<Vec<ShiftConflictResponse>>>> {
+    tracing::info!("Check shift assignment by user: {}.", claims.uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_check_shift_assignment(&state, req).await {
+        Ok(conflicts) => Ok(Json(EntityResponse {
+            message: "Check shift assignment success!".to_string(),
+            total: Some(conflicts.len() as u64),
+            pagination: None,
+            data: Some(conflicts),
+        })),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully check shift assignment: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/shift/assignment/create",
+    request_body = CreateShiftAssignmentRequest,
+    tags = ["shift_service"],
+    responses(
+        (status = 200, description = "Success assign employee to shift", body = [ShiftAssignmentResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Shift template or employee not found", body = [ClientResponseError]),
+        (status = 409, description = "Assignment overlaps another shift or a leave, or goes over the weekly hours", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_shift_assignment(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<CreateShiftAssignmentRequest>,
+) -> AppResult<Json<ShiftAssignmentResponse>> {
+    tracing::info!("Create shift assignment by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_create_shift_assignment(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully create shift assignment: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/v1/shift/assignment/delete",
+    request_body = DeleteShiftAssignmentRequest,
+    tags = ["shift_service"],
+    responses(
+        (status = 200, description = "Success delete shift assignment", body = [ShiftAssignmentResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Shift assignment not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_delete_shift_assignment(
+    State(state): State<AppState>,
+    context: AuditContext,
+    Json(req): Json<DeleteShiftAssignmentRequest>,
+) -> AppResult<Json<ShiftAssignmentResponse>> {
+    tracing::info!("Delete shift assignment by user: {}.", context.actor_uuid);
+    if req.validate().is_err() {
+        return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
+    }
+    match service_delete_shift_assignment(&state, &context, req).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully delete shift assignment: {err:?}.");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/shift/roster/{department_uuid}",
+    params(
+        ("department_uuid" = Uuid, Path, description = "uuid of the department"),
+        RosterQueryParam
+    ),
+    tags = ["shift_service"],
+    responses(
+        (status = 200, description = "Success get weekly roster of the department", body = [RosterResponse]),
+        (status = 400, description = "Invalid data input", body = [ClientResponseError]),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 403, description = "Permission denied", body = [ClientResponseError]),
+        (status = 404, description = "Department not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_roster(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(department_uuid): Path<Uuid>,
+    Query(query): Query<RosterQueryParam>,
+) -> AppResult<Json<RosterResponse>> {
+    tracing::info!("Get roster of {department_uuid} by user: {}.", claims.uuid);
+    match service_get_roster(&state, department_uuid, query).await {
+        Ok(result) => Ok(Json(result)),
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get roster of department: {err:?}.");
+            Err(err)
+        },
+    }
+}
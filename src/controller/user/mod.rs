@@ -1,7 +1,11 @@
 use crate::core::app_state::AppState;
 use crate::core::error::{AppError, AppResult};
 use crate::core::response::{ClientResponseError, EntityResponse, MessageResponse};
-use crate::domain::authenticate::services::service_logout;
+use crate::domain::audit_log::AuditContext;
+use crate::domain::authenticate::response::SessionResponse;
+use crate::domain::authenticate::services::{
+    service_get_sessions, service_logout, service_revoke_all_sessions, service_revoke_session,
+};
 use crate::domain::user::request::UpdateProfileRequest;
 use crate::domain::user::response::PublicProfileResponse;
 use crate::domain::user::services::{
@@ -12,6 +16,7 @@ use crate::util::filter_and_pagination::PageQueryParam;
 use axum::extract::{Path, State};
 use axum::{Extension, Json};
 use axum_extra::extract::Query;
+use uuid::Uuid;
 use validator::Validate;
 
 #[utoipa::path(
@@ -57,16 +62,16 @@ pub async fn controller_get_profile(
 )]
 pub async fn controller_update_profile(
     State(state): State<AppState>,
-    claims: UserClaims,
+    context: AuditContext,
     Json(req): Json<UpdateProfileRequest>,
 ) -> AppResult<Json<MessageResponse>> {
-    tracing::info!("Update profile user_id: {}.", claims.uuid);
+    tracing::info!("Update profile user_id: {}.", context.actor_uuid);
     if req.validate().is_err() {
         return Err(AppError::BadRequestError(req.validate().unwrap_err().to_string()));
     }
-    match service_update_profile(&state, claims.uuid, req).await {
+    match service_update_profile(&state, &context, context.actor_uuid, req).await {
         Ok(_) => {
-            tracing::info!("Success update profile user id: {}.", claims.uuid);
+            tracing::info!("Success update profile user id: {}.", context.actor_uuid);
             Ok(Json(MessageResponse::new("User profile updated.")))
         },
         Err(err) => {
@@ -92,7 +97,7 @@ pub async fn controller_logout(
     claims: UserClaims,
 ) -> AppResult<Json<MessageResponse>> {
     tracing::info!("Logout user id: {}", claims.uuid);
-    match service_logout(&state, claims.uuid).await {
+    match service_logout(&state, &claims).await {
         Ok(_) => {
             tracing::info!("Success logout user id: {}", claims.uuid);
             Ok(Json(MessageResponse::new("This user has successfully logged out!")))
@@ -103,3 +108,95 @@ pub async fn controller_logout(
         },
     }
 }
+
+#[utoipa::path(
+    get,
+    path = "/v1/me/sessions",
+    tags = ["user_service"],
+    responses(
+        (status = 200, description = "Success get active sessions", body = EntityResponse<Vec<SessionResponse>>),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_sessions(
+    State(state): State<AppState>,
+    claims: UserClaims,
+) -> AppResult<Json<EntityResponse<Vec<SessionResponse>>>> {
+    tracing::info!("Get sessions of user id: {}", claims.uuid);
+    match service_get_sessions(&state, &claims).await {
+        Ok(sessions) => {
+            tracing::info!("Success get sessions of user id: {}", claims.uuid);
+            Ok(Json(EntityResponse {
+                message: "Successfully get active sessions.".to_string(),
+                total: Some(sessions.len() as u64),
+                pagination: None,
+                data: Some(sessions),
+            }))
+        },
+        Err(err) => {
+            tracing::warn!("Unsuccessfully get sessions: {err:?}");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/me/sessions/{session_id}",
+    params(("session_id" = Uuid, Path, description = "Id of the session to revoke")),
+    tags = ["user_service"],
+    responses(
+        (status = 200, description = "Success revoke session", body = MessageResponse),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 404, description = "Session not found", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_revoke_session(
+    State(state): State<AppState>,
+    claims: UserClaims,
+    Path(session_id): Path<Uuid>,
+) -> AppResult<Json<MessageResponse>> {
+    tracing::info!("Revoke session {session_id} of user id: {}", claims.uuid);
+    match service_revoke_session(&state, &claims, session_id).await {
+        Ok(_) => {
+            tracing::info!("Success revoke session {session_id}");
+            Ok(Json(MessageResponse::new("The session has been revoked.")))
+        },
+        Err(err) => {
+            tracing::warn!("Unsuccessfully revoke session: {err:?}");
+            Err(err)
+        },
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/me/sessions",
+    tags = ["user_service"],
+    responses(
+        (status = 200, description = "Success revoke all sessions", body = MessageResponse),
+        (status = 401, description = "Unauthorized", body = [ClientResponseError]),
+        (status = 500, description = "Internal server error", body = [ClientResponseError])
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_revoke_all_sessions(
+    State(state): State<AppState>,
+    claims: UserClaims,
+) -> AppResult<Json<MessageResponse>> {
+    tracing::info!("Revoke all sessions of user id: {}", claims.uuid);
+    match service_revoke_all_sessions(&state, &claims).await {
+        Ok(_) => {
+            tracing::info!("Success revoke all sessions of user id: {}", claims.uuid);
+            Ok(Json(MessageResponse::new("All sessions have been revoked.")))
+        },
+        Err(err) => {
+            tracing::error!("Unsuccessfully revoke all sessions: {err:?}");
+            Err(err)
+        },
+    }
+}
@@ -2,6 +2,7 @@ use crate::core::configure::AppConfig;
 use crate::core::error::AppResult;
 use crate::infrastructure::persistence::postgres::{DatabaseClient, DatabaseClientExt};
 use crate::infrastructure::persistence::redis_client::instance::{RedisClient, RedisClientBuilder};
+use crate::infrastructure::third_party::email_client::{EmailClient, EmailClientBuilder};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -9,6 +10,7 @@ pub struct AppState {
     pub config: Arc<AppConfig>,
     pub db: Arc<DatabaseClient>,
     pub redis: Arc<RedisClient>,
+    pub email: Arc<EmailClient>,
 }
 
 impl AppState {
@@ -16,7 +18,8 @@ impl AppState {
         let config = Arc::new(config);
         let db = Arc::new(DatabaseClient::build_from_config(&config).await?);
         let redis = Arc::new(RedisClient::build_from_config(&config)?);
+        let email = Arc::new(EmailClient::build_from_config(&config)?);
 
-        Ok(Self { config, db, redis })
+        Ok(Self { config, db, redis, email })
     }
 }
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct EmailConfig {
+    pub transport: EmailTransport,
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub from: String,
+    pub file_dir: PathBuf,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum EmailTransport {
+    #[serde(rename = "smtp")]
+    #[strum(serialize = "smtp")]
+    Smtp,
+    #[serde(rename = "file")]
+    #[strum(serialize = "file")]
+    File,
+    #[serde(rename = "stdout")]
+    #[strum(serialize = "stdout")]
+    Stdout,
+}
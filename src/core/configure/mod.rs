@@ -1,4 +1,4 @@
-use self::{db::DatabaseConfig, secret::SecretConfig, server::ServerConfig};
+use self::{db::DatabaseConfig, email::EmailConfig, secret::SecretConfig, server::ServerConfig};
 use crate::core::configure::redis::RedisConfig;
 use crate::util::dir::get_project_root;
 use config::{ConfigError, Environment};
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 pub mod db;
+pub mod email;
 pub mod env;
 pub mod redis;
 pub mod secret;
@@ -21,6 +22,7 @@ pub struct AppConfig {
     pub sentry: Sentry,
     pub redis: RedisConfig,
     pub secret: SecretConfig,
+    pub email: EmailConfig,
 }
 
 impl AppConfig {
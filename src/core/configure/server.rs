@@ -1,10 +1,13 @@
 use serde::Deserialize;
-use std::net::{AddrParseError, SocketAddr};
+use std::net::{AddrParseError, IpAddr, SocketAddr};
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct ServerConfig {
     pub addr: String,
     pub port: u16,
+    // Addresses of the reverse proxies whose forwarded headers are honoured.
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 impl ServerConfig {
@@ -18,4 +21,8 @@ impl ServerConfig {
     pub fn get_socket_addr(&self) -> Result<SocketAddr, AddrParseError> {
         self.get_addr().parse()
     }
+
+    pub fn is_trusted_proxy(&self, ip: &IpAddr) -> bool {
+        self.trusted_proxies.contains(ip)
+    }
 }
@@ -50,6 +50,10 @@ pub enum AppError {
     #[error(transparent)]
     LetterError(#[from] lettre::error::Error),
     #[error(transparent)]
+    EmailFileError(#[from] lettre::transport::file::Error),
+    #[error(transparent)]
+    EmailAddressError(#[from] lettre::address::AddressError),
+    #[error(transparent)]
     ParseJsonError(#[from] serde_json::Error),
     #[error(transparent)]
     ParseFloatError(#[from] std::num::ParseFloatError),
@@ -165,6 +169,12 @@ impl AppError {
             LetterError(_err) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, ClientResponseError::InternalServerError)
             },
+            EmailFileError(_err) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ClientResponseError::InternalServerError)
+            },
+            EmailAddressError(_err) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ClientResponseError::InternalServerError)
+            },
             HashError(_err) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, ClientResponseError::InternalServerError)
             },
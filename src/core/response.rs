@@ -1,3 +1,4 @@
+use crate::util::filter_and_pagination::Paginated;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -22,7 +23,39 @@ pub struct ServiceStatusResponse {
 pub struct EntityResponse<T> {
     pub message: String,
     pub data: Option<T>,
-    pub total: u16,
+    // Number of matching rows, unknown for cursor pages since they are not counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<PageResponse>,
+}
+
+impl<T> EntityResponse<Vec<T>> {
+    pub fn paginated<S: Into<String>>(message: S, page: Paginated<T>) -> Self {
+        Self {
+            message: message.into(),
+            total: page.pagination.total_items,
+            data: Some(page.items),
+            pagination: Some(page.pagination),
+        }
+    }
+}
+
+// Counters are only computed for offset pagination, cursor pagination returns the cursors of
+// the neighbour pages instead.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, Eq, ToSchema)]
+pub struct PageResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_items: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_pages: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_num: Option<u64>,
+    pub page_size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_cursor: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema)]
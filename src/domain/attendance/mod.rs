@@ -0,0 +1,89 @@
+use crate::domain::entity::{AttendanceEventEntity, EmployeeEntity};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
+use serde::{Deserialize, Serialize};
+
+pub mod request;
+pub mod response;
+pub mod services;
+pub mod timesheet;
+
+// Clock-in, clock-out or break event of an employee, timesheets are derived from them.
+#[derive(Debug, PartialEq, Clone, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "attendance_events", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique, indexed)]
+    pub attendance_event_uuid: Uuid,
+    #[sea_orm()]
+    pub employee_id: i64,
+    // One of `EAttendanceEventType`.
+    #[sea_orm()]
+    pub event_type: String,
+    #[sea_orm()]
+    pub occurred_at: DateTime<Utc>,
+    // Day of the timesheet the event belongs to, in the timezone of the organization. A clock-out
+    // after midnight belongs to the day of its clock-in.
+    #[sea_orm()]
+    pub work_date: NaiveDate,
+    #[sea_orm(nullable)]
+    pub note: Option<String>,
+    // Manager who added or corrected the event, none when recorded by the employee.
+    #[sea_orm(nullable)]
+    pub corrected_by_employee_id: Option<i64>,
+    // 0 when removed by a correction.
+    #[sea_orm(nullable, default_value = 1)]
+    pub status: Option<i16>,
+    #[sea_orm()]
+    pub create_at: NaiveDateTime,
+    #[sea_orm()]
+    pub update_at: NaiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::employee::Entity",
+        from = "Column::EmployeeId",
+        to = "super::employee::Column::Id"
+    )]
+    Employee,
+}
+
+impl Related<EmployeeEntity> for AttendanceEventEntity {
+    fn to() -> RelationDef {
+        Relation::Employee.def()
+    }
+}
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+    utoipa::ToSchema,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EAttendanceEventType {
+    ClockIn,
+    ClockOut,
+    BreakStart,
+    BreakEnd,
+}
+
+impl Model {
+    pub fn event_type(&self) -> Option<EAttendanceEventType> {
+        self.event_type.parse().ok()
+    }
+}
@@ -0,0 +1,61 @@
+use crate::domain::attendance::EAttendanceEventType;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+// Event of the authenticated employee, recorded at the time of the request.
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct ClockRequest {
+    pub event_type: EAttendanceEventType,
+    #[validate(length(max = 255))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, ToSchema, IntoParams)]
+pub struct TimesheetQueryParam {
+    // First and last day of the timesheets, the current month up to today by default.
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema, IntoParams)]
+pub struct AttendanceSummaryQueryParam {
+    #[validate(range(min = 2000, max = 9999))]
+    pub year: i32,
+    #[validate(range(min = 1, max = 12))]
+    pub month: u32,
+}
+
+// Event added by a manager, e.g. a forgotten clock-out.
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateAttendanceCorrectionRequest {
+    pub employee_uuid: Uuid,
+    pub event_type: EAttendanceEventType,
+    pub occurred_at: DateTime<Utc>,
+    // Day of the timesheet, the day of `occurred_at` in the timezone of the organization by
+    // default.
+    pub work_date: Option<NaiveDate>,
+    // Reason of the correction.
+    #[validate(length(min = 1, max = 500))]
+    pub note: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateAttendanceCorrectionRequest {
+    pub attendance_event_uuid: Uuid,
+    pub event_type: Option<EAttendanceEventType>,
+    pub occurred_at: Option<DateTime<Utc>>,
+    // Day of the timesheet, kept when only the time changes.
+    pub work_date: Option<NaiveDate>,
+    #[validate(length(min = 1, max = 500))]
+    pub note: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct DeleteAttendanceCorrectionRequest {
+    pub attendance_event_uuid: Uuid,
+    #[validate(length(min = 1, max = 500))]
+    pub note: String,
+}
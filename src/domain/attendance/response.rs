@@ -0,0 +1,179 @@
+use crate::domain::attendance::timesheet::DailyTimesheet;
+use crate::domain::model::AttendanceEventModel;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AttendanceEventResponse {
+    pub attendance_event_uuid: Uuid,
+    pub employee_uuid: Uuid,
+    pub event_type: String,
+    pub occurred_at: DateTime<Utc>,
+    pub work_date: NaiveDate,
+    pub note: Option<String>,
+    pub corrected_by_employee_uuid: Option<Uuid>,
+    pub status: Option<i16>,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl AttendanceEventResponse {
+    pub fn new(
+        event: AttendanceEventModel,
+        employee_uuid: Uuid,
+        corrected_by_employee_uuid: Option<Uuid>,
+    ) -> Self {
+        AttendanceEventResponse {
+            attendance_event_uuid: event.attendance_event_uuid,
+            employee_uuid,
+            event_type: event.event_type,
+            occurred_at: event.occurred_at,
+            work_date: event.work_date,
+            note: event.note,
+            corrected_by_employee_uuid,
+            status: event.status,
+            create_at: event.create_at,
+            update_at: event.update_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DailyTimesheetResponse {
+    pub work_date: NaiveDate,
+    pub is_working_day: bool,
+    pub on_leave: bool,
+    pub is_absent: bool,
+    pub first_clock_in: Option<DateTime<Utc>>,
+    pub last_clock_out: Option<DateTime<Utc>>,
+    pub worked_minutes: i64,
+    pub break_minutes: i64,
+    pub late_minutes: i64,
+    pub early_leave_minutes: i64,
+    pub is_complete: bool,
+    pub events: Vec<AttendanceEventResponse>,
+}
+
+impl DailyTimesheetResponse {
+    // `employee_uuids` holds the uuids of the managers who corrected the events.
+    pub fn new(
+        timesheet: DailyTimesheet,
+        employee_uuid: Uuid,
+        employee_uuids: &HashMap<i64, Uuid>,
+    ) -> Self {
+        DailyTimesheetResponse {
+            is_absent: timesheet.is_absent(),
+            work_date: timesheet.work_date,
+            is_working_day: timesheet.is_working_day,
+            on_leave: timesheet.on_leave,
+            first_clock_in: timesheet.first_clock_in,
+            last_clock_out: timesheet.last_clock_out,
+            worked_minutes: timesheet.worked_minutes,
+            break_minutes: timesheet.break_minutes,
+            late_minutes: timesheet.late_minutes,
+            early_leave_minutes: timesheet.early_leave_minutes,
+            is_complete: timesheet.is_complete,
+            events: timesheet
+                .events
+                .into_iter()
+                .map(|event| {
+                    let corrected_by = event
+                        .corrected_by_employee_id
+                        .and_then(|id| employee_uuids.get(&id).copied());
+                    AttendanceEventResponse::new(event, employee_uuid, corrected_by)
+                })
+                .collect(),
+        }
+    }
+}
+
+// Totals of a range of days, only the days the employee was hired up to today count.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, ToSchema)]
+pub struct AttendanceSummary {
+    pub working_days: u32,
+    pub present_days: u32,
+    pub absent_days: u32,
+    pub leave_days: u32,
+    pub late_days: u32,
+    pub late_minutes: i64,
+    pub early_leave_days: u32,
+    pub early_leave_minutes: i64,
+    pub incomplete_days: u32,
+    pub worked_minutes: i64,
+    pub break_minutes: i64,
+}
+
+impl AttendanceSummary {
+    pub fn add_day(&mut self, timesheet: &DailyTimesheet) {
+        if timesheet.is_working_day {
+            self.working_days += 1;
+            if timesheet.on_leave {
+                self.leave_days += 1;
+            }
+        }
+        if timesheet.first_clock_in.is_some() {
+            self.present_days += 1;
+        }
+        if timesheet.is_absent() {
+            self.absent_days += 1;
+        }
+        if timesheet.late_minutes > 0 {
+            self.late_days += 1;
+            self.late_minutes += timesheet.late_minutes;
+        }
+        if timesheet.early_leave_minutes > 0 {
+            self.early_leave_days += 1;
+            self.early_leave_minutes += timesheet.early_leave_minutes;
+        }
+        if !timesheet.is_complete {
+            self.incomplete_days += 1;
+        }
+        self.worked_minutes += timesheet.worked_minutes;
+        self.break_minutes += timesheet.break_minutes;
+    }
+
+    pub fn merge(&mut self, other: &AttendanceSummary) {
+        self.working_days += other.working_days;
+        self.present_days += other.present_days;
+        self.absent_days += other.absent_days;
+        self.leave_days += other.leave_days;
+        self.late_days += other.late_days;
+        self.late_minutes += other.late_minutes;
+        self.early_leave_days += other.early_leave_days;
+        self.early_leave_minutes += other.early_leave_minutes;
+        self.incomplete_days += other.incomplete_days;
+        self.worked_minutes += other.worked_minutes;
+        self.break_minutes += other.break_minutes;
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeTimesheetResponse {
+    pub employee_uuid: Uuid,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub summary: AttendanceSummary,
+    pub days: Vec<DailyTimesheetResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeAttendanceSummaryResponse {
+    pub employee_uuid: Uuid,
+    pub year: i32,
+    pub month: u32,
+    pub summary: AttendanceSummary,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DepartmentAttendanceSummaryResponse {
+    pub department_uuid: Uuid,
+    pub name: String,
+    pub year: i32,
+    pub month: u32,
+    // Totals of every employee of the department.
+    pub summary: AttendanceSummary,
+    pub employees: Vec<EmployeeAttendanceSummaryResponse>,
+}
@@ -0,0 +1,510 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::domain::attendance::request::{
+    AttendanceSummaryQueryParam, ClockRequest, CreateAttendanceCorrectionRequest,
+    DeleteAttendanceCorrectionRequest, TimesheetQueryParam, UpdateAttendanceCorrectionRequest,
+};
+use crate::domain::attendance::response::{
+    AttendanceEventResponse, AttendanceSummary, DailyTimesheetResponse,
+    DepartmentAttendanceSummaryResponse, EmployeeAttendanceSummaryResponse,
+    EmployeeTimesheetResponse,
+};
+use crate::domain::attendance::timesheet::{
+    build_timesheets, current_state, next_state, DailyTimesheet, EAttendanceState, WorkSchedule,
+};
+use crate::domain::attendance::EAttendanceEventType;
+use crate::domain::audit_log::services::record_audit_log;
+use crate::domain::audit_log::{AuditContext, EAuditAction, EAuditEntity};
+use crate::domain::calendar::services::find_holidays;
+use crate::domain::calendar::work_calendar::WorkCalendar;
+use crate::domain::department::services::find_department_by_uuid;
+use crate::domain::employee::services::{
+    find_employee_by_user_uuid, find_employee_by_uuid, find_employee_organization_id,
+    is_manager_or_department_head,
+};
+use crate::domain::entity::{
+    AttendanceEventEntity, EmployeeEntity, LeaveRequestEntity, OrganizationEntity,
+};
+use crate::domain::model::{AttendanceEventModel, EmployeeModel, LeaveRequestModel};
+use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
+use chrono::{DateTime, Datelike, Days, Duration, Months, NaiveDate, Utc};
+use sea_orm::{ConnectionTrait, DatabaseTransaction, TransactionTrait};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use uuid::Uuid;
+
+// Longest range of days of a timesheet request.
+const MAX_TIMESHEET_DAYS: i64 = 93;
+// Longest time after its clock-in a day left open over midnight still takes the next events.
+const MAX_SHIFT_HOURS: i64 = 16;
+
+pub async fn service_clock(
+    state: &AppState,
+    context: &AuditContext,
+    req: ClockRequest,
+) -> AppResult<AttendanceEventResponse> {
+    tracing::info!("Clock request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let employee = find_employee_by_user_uuid(&tx, &context.actor_uuid).await?;
+    // The state comes from the events already recorded, two clocks at once must not both see it.
+    let employee = EmployeeEntity::lock_by_ids(&tx, &[employee.id])
+        .await
+        .and_then(|employees| employees.into_iter().next())
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    if employee.status != Some(1) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!("Employee is not active {}", employee.employee_uuid),
+        });
+    }
+    let now = Utc::now();
+    let organization_id =
+        find_employee_organization_id(&tx, &employee, now.date_naive()).await?;
+    let schedule = find_schedule(&tx, organization_id).await?;
+    let work_date =
+        open_work_date(&tx, &employee, req.event_type, schedule.local_date(now), now).await?;
+    let events = find_events(&tx, &[employee.id], work_date, work_date).await?;
+    let state = current_state(&events);
+    if next_state(state, req.event_type).is_none() {
+        return Err(AppError::BadRequestError(format!(
+            "Can not record {} while {state:?}",
+            req.event_type
+        )));
+    }
+
+    let event = AttendanceEventModel {
+        id: Default::default(),
+        attendance_event_uuid: Uuid::new_v4(),
+        employee_id: employee.id,
+        event_type: req.event_type.to_string(),
+        occurred_at: now,
+        work_date,
+        note: req.note,
+        corrected_by_employee_id: None,
+        status: Some(1),
+        create_at: now.naive_utc(),
+        update_at: now.naive_utc(),
+    };
+    let event = create_event(&tx, context, event).await?;
+    tx.commit().await?;
+    Ok(AttendanceEventResponse::new(event, employee.employee_uuid, None))
+}
+
+pub async fn service_get_my_timesheet(
+    state: &AppState,
+    context: &AuditContext,
+    query: TimesheetQueryParam,
+) -> AppResult<EmployeeTimesheetResponse> {
+    tracing::info!("Get timesheet of user {}: {query:?}", context.actor_uuid);
+    let employee = find_employee_by_user_uuid(&*state.db, &context.actor_uuid).await?;
+    employee_timesheet(&*state.db, &employee, query).await
+}
+
+pub async fn service_get_timesheet(
+    state: &AppState,
+    employee_uuid: Uuid,
+    query: TimesheetQueryParam,
+) -> AppResult<EmployeeTimesheetResponse> {
+    tracing::info!("Get timesheet of employee {employee_uuid}: {query:?}");
+    let employee = find_employee_by_uuid(&*state.db, &employee_uuid).await?;
+    employee_timesheet(&*state.db, &employee, query).await
+}
+
+pub async fn service_create_attendance_correction(
+    state: &AppState,
+    context: &AuditContext,
+    req: CreateAttendanceCorrectionRequest,
+) -> AppResult<AttendanceEventResponse> {
+    tracing::info!("Create attendance correction request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let employee = find_employee_by_uuid(&tx, &req.employee_uuid).await?;
+    let work_date = match req.work_date {
+        Some(work_date) => work_date,
+        None => {
+            let organization_id =
+                find_employee_organization_id(&tx, &employee, req.occurred_at.date_naive())
+                    .await?;
+            find_schedule(&tx, organization_id).await?.local_date(req.occurred_at)
+        },
+    };
+    let manager = find_correcting_manager(&tx, context, &employee, work_date).await?;
+
+    let event = AttendanceEventModel {
+        id: Default::default(),
+        attendance_event_uuid: Uuid::new_v4(),
+        employee_id: employee.id,
+        event_type: req.event_type.to_string(),
+        occurred_at: req.occurred_at,
+        work_date,
+        note: Some(req.note),
+        corrected_by_employee_id: Some(manager.id),
+        status: Some(1),
+        create_at: Utc::now().naive_utc(),
+        update_at: Utc::now().naive_utc(),
+    };
+    let event = create_event(&tx, context, event).await?;
+    tx.commit().await?;
+    Ok(AttendanceEventResponse::new(event, employee.employee_uuid, Some(manager.employee_uuid)))
+}
+
+pub async fn service_update_attendance_correction(
+    state: &AppState,
+    context: &AuditContext,
+    req: UpdateAttendanceCorrectionRequest,
+) -> AppResult<AttendanceEventResponse> {
+    tracing::info!("Update attendance correction request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut event = find_active_event_by_uuid(&tx, &req.attendance_event_uuid).await?;
+    let employee = find_event_employee(&tx, &event).await?;
+    let before = event.clone();
+    if let Some(event_type) = req.event_type {
+        event.event_type = event_type.to_string();
+    }
+    if let Some(occurred_at) = req.occurred_at {
+        event.occurred_at = occurred_at;
+    }
+    if let Some(work_date) = req.work_date {
+        event.work_date = work_date;
+    }
+    // The manager must be allowed on the day before and after the correction.
+    find_correcting_manager(&tx, context, &employee, before.work_date).await?;
+    let manager = find_correcting_manager(&tx, context, &employee, event.work_date).await?;
+    event.note = Some(req.note);
+    event.corrected_by_employee_id = Some(manager.id);
+    event.update_at = Utc::now().naive_utc();
+
+    let event = update_event(&tx, context, EAuditAction::Update, before, event).await?;
+    tx.commit().await?;
+    Ok(AttendanceEventResponse::new(event, employee.employee_uuid, Some(manager.employee_uuid)))
+}
+
+pub async fn service_delete_attendance_correction(
+    state: &AppState,
+    context: &AuditContext,
+    req: DeleteAttendanceCorrectionRequest,
+) -> AppResult<AttendanceEventResponse> {
+    tracing::info!("Delete attendance event request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut event = find_active_event_by_uuid(&tx, &req.attendance_event_uuid).await?;
+    let employee = find_event_employee(&tx, &event).await?;
+    let manager = find_correcting_manager(&tx, context, &employee, event.work_date).await?;
+    let before = event.clone();
+    event.status = Some(0);
+    event.note = Some(req.note);
+    event.corrected_by_employee_id = Some(manager.id);
+    event.update_at = Utc::now().naive_utc();
+
+    let event = update_event(&tx, context, EAuditAction::Delete, before, event).await?;
+    tx.commit().await?;
+    Ok(AttendanceEventResponse::new(event, employee.employee_uuid, Some(manager.employee_uuid)))
+}
+
+pub async fn service_get_employee_attendance_summary(
+    state: &AppState,
+    employee_uuid: Uuid,
+    query: AttendanceSummaryQueryParam,
+) -> AppResult<EmployeeAttendanceSummaryResponse> {
+    tracing::info!("Get attendance summary of employee {employee_uuid}: {query:?}");
+    let employee = find_employee_by_uuid(&*state.db, &employee_uuid).await?;
+    let (from, to) = month_range(query.year, query.month)?;
+    let organization_id = find_employee_organization_id(&*state.db, &employee, to).await?;
+    let schedule = find_schedule(&*state.db, organization_id).await?;
+    let events = find_events(&*state.db, &[employee.id], from, to).await?;
+    let leaves = find_leaves(&*state.db, &[employee.id], from, to).await?;
+    let timesheets = employee_timesheets(&schedule, &employee, from, to, &events, &leaves);
+    Ok(EmployeeAttendanceSummaryResponse {
+        employee_uuid,
+        year: query.year,
+        month: query.month,
+        summary: summarize(&employee, &timesheets),
+    })
+}
+
+// Summary of every employee of the department on the last day of the month.
+pub async fn service_get_department_attendance_summary(
+    state: &AppState,
+    department_uuid: Uuid,
+    query: AttendanceSummaryQueryParam,
+) -> AppResult<DepartmentAttendanceSummaryResponse> {
+    tracing::info!("Get attendance summary of department {department_uuid}: {query:?}");
+    let department = find_department_by_uuid(&*state.db, &department_uuid).await?;
+    let (from, to) = month_range(query.year, query.month)?;
+    let schedule = find_schedule(&*state.db, department.organization_id).await?;
+    let employees = EmployeeEntity::find_by_department(&*state.db, department.id, to)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let employee_ids = employees.iter().map(|employee| employee.id).collect::<Vec<_>>();
+    let events = find_events(&*state.db, &employee_ids, from, to).await?;
+    let leaves = find_leaves(&*state.db, &employee_ids, from, to).await?;
+
+    let mut summary = AttendanceSummary::default();
+    let mut items = Vec::new();
+    for employee in employees {
+        let timesheets = employee_timesheets(&schedule, &employee, from, to, &events, &leaves);
+        let employee_summary = summarize(&employee, &timesheets);
+        summary.merge(&employee_summary);
+        items.push(EmployeeAttendanceSummaryResponse {
+            employee_uuid: employee.employee_uuid,
+            year: query.year,
+            month: query.month,
+            summary: employee_summary,
+        });
+    }
+    Ok(DepartmentAttendanceSummaryResponse {
+        department_uuid,
+        name: department.name,
+        year: query.year,
+        month: query.month,
+        summary,
+        employees: items,
+    })
+}
+
+async fn employee_timesheet<DB>(
+    conn: &DB,
+    employee: &EmployeeModel,
+    query: TimesheetQueryParam,
+) -> AppResult<EmployeeTimesheetResponse>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let to = query.to.unwrap_or_else(|| Utc::now().date_naive());
+    let from = query.from.unwrap_or_else(|| to.with_day(1).unwrap_or(to));
+    if to < from {
+        return Err(AppError::BadRequestError("The last day is before the first day".to_string()));
+    }
+    if (to - from).num_days() >= MAX_TIMESHEET_DAYS {
+        return Err(AppError::BadRequestError(format!(
+            "A timesheet covers at most {MAX_TIMESHEET_DAYS} days"
+        )));
+    }
+    let organization_id = find_employee_organization_id(conn, employee, to).await?;
+    let schedule = find_schedule(conn, organization_id).await?;
+    let events = find_events(conn, &[employee.id], from, to).await?;
+    let leaves = find_leaves(conn, &[employee.id], from, to).await?;
+    let timesheets = employee_timesheets(&schedule, employee, from, to, &events, &leaves);
+
+    let corrector_ids = events
+        .iter()
+        .filter_map(|event| event.corrected_by_employee_id)
+        .collect::<Vec<_>>();
+    let correctors = EmployeeEntity::find_by_ids(conn, &corrector_ids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let employee_uuids = correctors
+        .into_iter()
+        .map(|corrector| (corrector.id, corrector.employee_uuid))
+        .collect::<HashMap<_, _>>();
+    Ok(EmployeeTimesheetResponse {
+        employee_uuid: employee.employee_uuid,
+        from,
+        to,
+        summary: summarize(employee, &timesheets),
+        days: timesheets
+            .into_iter()
+            .map(|day| DailyTimesheetResponse::new(day, employee.employee_uuid, &employee_uuids))
+            .collect(),
+    })
+}
+
+// Timesheets of one employee, `events` and `leaves` may hold the ones of other employees.
+fn employee_timesheets(
+    schedule: &WorkSchedule,
+    employee: &EmployeeModel,
+    from: NaiveDate,
+    to: NaiveDate,
+    events: &[AttendanceEventModel],
+    leaves: &[LeaveRequestModel],
+) -> Vec<DailyTimesheet> {
+    let events = events
+        .iter()
+        .filter(|event| event.employee_id == employee.id)
+        .cloned()
+        .collect::<Vec<_>>();
+    let leaves = leaves
+        .iter()
+        .filter(|leave| leave.employee_id == employee.id)
+        .map(|leave| (leave.start_date, leave.end_date))
+        .collect::<Vec<_>>();
+    build_timesheets(schedule, from, to, &events, &leaves)
+}
+
+// Only the days from the hire date up to today count.
+fn summarize(employee: &EmployeeModel, timesheets: &[DailyTimesheet]) -> AttendanceSummary {
+    let hire_date = employee.hire_date;
+    let today = Utc::now().date_naive();
+    let mut summary = AttendanceSummary::default();
+    timesheets
+        .iter()
+        .filter(|day| hire_date <= day.work_date && day.work_date <= today)
+        .for_each(|day| summary.add_day(day));
+    summary
+}
+
+// A clock-in always opens today. A day still open after midnight, at most from yesterday, keeps
+// the other events until its clock-out, as long as its clock-in is less than `MAX_SHIFT_HOURS`
+// ago, a day forgotten open is left incomplete instead.
+async fn open_work_date(
+    tx: &DatabaseTransaction,
+    employee: &EmployeeModel,
+    event_type: EAttendanceEventType,
+    today: NaiveDate,
+    now: DateTime<Utc>,
+) -> AppResult<NaiveDate> {
+    if event_type == EAttendanceEventType::ClockIn {
+        return Ok(today);
+    }
+    let Some(latest) = AttendanceEventEntity::find_latest_by_employee(tx, employee.id).await else {
+        return Ok(today);
+    };
+    let yesterday = today.checked_sub_days(Days::new(1)).unwrap_or(today);
+    if latest.work_date != yesterday {
+        return Ok(today);
+    }
+    let events = find_events(tx, &[employee.id], yesterday, yesterday).await?;
+    let clock_in = events
+        .iter()
+        .rev()
+        .find(|event| event.event_type() == Some(EAttendanceEventType::ClockIn));
+    match (current_state(&events), clock_in) {
+        (EAttendanceState::Off, _) | (_, None) => Ok(today),
+        (_, Some(clock_in)) if now - clock_in.occurred_at > Duration::hours(MAX_SHIFT_HOURS) => {
+            Ok(today)
+        },
+        _ => Ok(yesterday),
+    }
+}
+
+// The authenticated employee must manage the employee, directly or as a department head.
+async fn find_correcting_manager(
+    tx: &DatabaseTransaction,
+    context: &AuditContext,
+    employee: &EmployeeModel,
+    work_date: NaiveDate,
+) -> AppResult<EmployeeModel> {
+    let manager = find_employee_by_user_uuid(tx, &context.actor_uuid).await?;
+    if !is_manager_or_department_head(tx, &manager, employee, work_date).await? {
+        return Err(AppError::PermissionDeniedError(
+            "Only the manager or a department head of the employee can correct the attendance"
+                .to_string(),
+        ));
+    }
+    Ok(manager)
+}
+
+async fn create_event(
+    tx: &DatabaseTransaction,
+    context: &AuditContext,
+    event: AttendanceEventModel,
+) -> AppResult<AttendanceEventModel> {
+    let event_id = AttendanceEventEntity::create(tx, &event).await.ok_or_else(|| {
+        AppError::EntityNotAvailableError { detail: "Attendance event".to_string() }
+    })?;
+    let event = AttendanceEventModel { id: event_id, ..event };
+    record_audit_log(
+        tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::AttendanceEvent,
+        event.attendance_event_uuid,
+        None,
+        Some(&event),
+    )
+    .await?;
+    Ok(event)
+}
+
+async fn update_event(
+    tx: &DatabaseTransaction,
+    context: &AuditContext,
+    action: EAuditAction,
+    before: AttendanceEventModel,
+    event: AttendanceEventModel,
+) -> AppResult<AttendanceEventModel> {
+    AttendanceEventEntity::update(tx, event.clone()).await.ok_or_else(|| {
+        AppError::EntityNotAvailableError { detail: "Attendance event".to_string() }
+    })?;
+    record_audit_log(
+        tx,
+        context,
+        action,
+        EAuditEntity::AttendanceEvent,
+        event.attendance_event_uuid,
+        Some(&before),
+        Some(&event),
+    )
+    .await?;
+    Ok(event)
+}
+
+async fn find_schedule<DB>(conn: &DB, organization_id: i64) -> AppResult<WorkSchedule>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let organization = OrganizationEntity::find_data_by_id(conn, organization_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Organization not found".to_string(),
+        })?;
+    let holidays = find_holidays(conn, organization_id).await?;
+    Ok(WorkSchedule::new(&organization, WorkCalendar::new(&organization, holidays)))
+}
+
+async fn find_events<DB>(
+    conn: &DB,
+    employee_ids: &[i64],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> AppResult<Vec<AttendanceEventModel>>
+where
+    DB: ConnectionTrait,
+{
+    AttendanceEventEntity::find_by_employees_between(conn, employee_ids, from, to)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))
+}
+
+async fn find_leaves<DB>(
+    conn: &DB,
+    employee_ids: &[i64],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> AppResult<Vec<LeaveRequestModel>>
+where
+    DB: ConnectionTrait,
+{
+    LeaveRequestEntity::find_approved_between(conn, employee_ids, from, to)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))
+}
+
+async fn find_active_event_by_uuid(
+    tx: &DatabaseTransaction,
+    attendance_event_uuid: &Uuid,
+) -> AppResult<AttendanceEventModel> {
+    match AttendanceEventEntity::find_by_uuid(tx, attendance_event_uuid).await {
+        Some(event) if event.status == Some(1) => Ok(event),
+        _ => Err(AppError::EntityNotFoundError {
+            detail: format!("Attendance event not found by uuid {attendance_event_uuid}"),
+        }),
+    }
+}
+
+async fn find_event_employee(
+    tx: &DatabaseTransaction,
+    event: &AttendanceEventModel,
+) -> AppResult<EmployeeModel> {
+    EmployeeEntity::find_data_by_id(tx, event.employee_id).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: "Employee not found".to_string() }
+    })
+}
+
+// First and last day of the month.
+fn month_range(year: i32, month: u32) -> AppResult<(NaiveDate, NaiveDate)> {
+    let from = NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or_else(|| AppError::BadRequestError(format!("Invalid month {year}-{month}")))?;
+    let to = from
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(from);
+    Ok((from, to))
+}
@@ -0,0 +1,323 @@
+use crate::domain::attendance::EAttendanceEventType;
+use crate::domain::calendar::work_calendar::WorkCalendar;
+use crate::domain::model::{AttendanceEventModel, OrganizationModel};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+
+// Working hours of an organization, used to find late arrivals and early leaves.
+#[derive(Debug, Clone)]
+pub struct WorkSchedule {
+    pub time_zone: Tz,
+    pub calendar: WorkCalendar,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub late_grace_minutes: i64,
+}
+
+impl WorkSchedule {
+    pub fn new(organization: &OrganizationModel, calendar: WorkCalendar) -> Self {
+        WorkSchedule {
+            time_zone: organization.time_zone(),
+            calendar,
+            start: organization.work_start_time,
+            end: organization.work_end_time,
+            late_grace_minutes: i64::from(organization.late_grace_minutes),
+        }
+    }
+
+    pub fn local_date(&self, at: DateTime<Utc>) -> NaiveDate {
+        self.local_time(at).date()
+    }
+
+    // Holidays of the organization are not working days.
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        self.calendar.is_working_day(date)
+    }
+
+    fn local_time(&self, at: DateTime<Utc>) -> NaiveDateTime {
+        at.with_timezone(&self.time_zone).naive_local()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EAttendanceState {
+    Off,
+    Working,
+    OnBreak,
+}
+
+// State after the event, none when the event is not expected in this state, e.g. a break
+// before the clock-in.
+pub fn next_state(
+    state: EAttendanceState,
+    event_type: EAttendanceEventType,
+) -> Option<EAttendanceState> {
+    match (state, event_type) {
+        (EAttendanceState::Off, EAttendanceEventType::ClockIn) => Some(EAttendanceState::Working),
+        (EAttendanceState::Working, EAttendanceEventType::BreakStart) => {
+            Some(EAttendanceState::OnBreak)
+        },
+        (EAttendanceState::OnBreak, EAttendanceEventType::BreakEnd) => {
+            Some(EAttendanceState::Working)
+        },
+        (EAttendanceState::Working | EAttendanceState::OnBreak, EAttendanceEventType::ClockOut) => {
+            Some(EAttendanceState::Off)
+        },
+        _ => None,
+    }
+}
+
+// State after the events of a day sorted by time, unexpected events are skipped.
+pub fn current_state(events: &[AttendanceEventModel]) -> EAttendanceState {
+    events.iter().fold(EAttendanceState::Off, |state, event| {
+        event.event_type().and_then(|event_type| next_state(state, event_type)).unwrap_or(state)
+    })
+}
+
+// Day of an employee derived from its events.
+#[derive(Debug, Clone)]
+pub struct DailyTimesheet {
+    pub work_date: NaiveDate,
+    pub is_working_day: bool,
+    // An approved leave covers the day.
+    pub on_leave: bool,
+    pub first_clock_in: Option<DateTime<Utc>>,
+    pub last_clock_out: Option<DateTime<Utc>>,
+    pub worked_minutes: i64,
+    pub break_minutes: i64,
+    // Minutes after the start of the working hours, only when beyond the grace period.
+    pub late_minutes: i64,
+    // Minutes before the end of the working hours.
+    pub early_leave_minutes: i64,
+    // False when an event is missing or unexpected, e.g. a clock-in without clock-out.
+    pub is_complete: bool,
+    pub events: Vec<AttendanceEventModel>,
+}
+
+impl DailyTimesheet {
+    pub fn is_absent(&self) -> bool {
+        self.is_working_day && !self.on_leave && self.first_clock_in.is_none()
+    }
+}
+
+// Timesheet of one day, `events` are the active events of the day sorted by time.
+pub fn build_timesheet(
+    schedule: &WorkSchedule,
+    work_date: NaiveDate,
+    events: &[AttendanceEventModel],
+    on_leave: bool,
+) -> DailyTimesheet {
+    let mut timesheet = DailyTimesheet {
+        work_date,
+        is_working_day: schedule.is_working_day(work_date),
+        on_leave,
+        first_clock_in: None,
+        last_clock_out: None,
+        worked_minutes: 0,
+        break_minutes: 0,
+        late_minutes: 0,
+        early_leave_minutes: 0,
+        is_complete: true,
+        events: events.to_vec(),
+    };
+    let mut state = EAttendanceState::Off;
+    let mut since: Option<DateTime<Utc>> = None;
+    for event in events {
+        let next = event.event_type().and_then(|event_type| next_state(state, event_type));
+        let Some(next) = next else {
+            timesheet.is_complete = false;
+            continue;
+        };
+        if let Some(since) = since {
+            let minutes = (event.occurred_at - since).num_minutes();
+            match state {
+                EAttendanceState::Working => timesheet.worked_minutes += minutes,
+                EAttendanceState::OnBreak => timesheet.break_minutes += minutes,
+                EAttendanceState::Off => {},
+            }
+        }
+        match (state, next) {
+            (EAttendanceState::Off, _) => {
+                timesheet.first_clock_in.get_or_insert(event.occurred_at);
+            },
+            (_, EAttendanceState::Off) => timesheet.last_clock_out = Some(event.occurred_at),
+            _ => {},
+        }
+        state = next;
+        since = Some(event.occurred_at);
+    }
+    if state != EAttendanceState::Off {
+        timesheet.is_complete = false;
+    }
+
+    if !timesheet.is_working_day || on_leave {
+        return timesheet;
+    }
+    if let Some(first_clock_in) = timesheet.first_clock_in {
+        let late = (schedule.local_time(first_clock_in) - work_date.and_time(schedule.start))
+            .num_minutes();
+        if late > schedule.late_grace_minutes {
+            timesheet.late_minutes = late;
+        }
+    }
+    if let (Some(last_clock_out), true) = (timesheet.last_clock_out, timesheet.is_complete) {
+        let early =
+            (work_date.and_time(schedule.end) - schedule.local_time(last_clock_out)).num_minutes();
+        timesheet.early_leave_minutes = early.max(0);
+    }
+    timesheet
+}
+
+// Timesheets of every day between both days, both included. `events` are the active events of
+// one employee sorted by time and `leaves` the first and last days of its approved leaves.
+pub fn build_timesheets(
+    schedule: &WorkSchedule,
+    from: NaiveDate,
+    to: NaiveDate,
+    events: &[AttendanceEventModel],
+    leaves: &[(NaiveDate, NaiveDate)],
+) -> Vec<DailyTimesheet> {
+    from.iter_days()
+        .take_while(|day| *day <= to)
+        .map(|day| {
+            let day_events = events
+                .iter()
+                .filter(|event| event.work_date == day)
+                .cloned()
+                .collect::<Vec<_>>();
+            let on_leave = leaves.iter().any(|(start, end)| *start <= day && day <= *end);
+            build_timesheet(schedule, day, &day_events, on_leave)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Weekday;
+    use uuid::Uuid;
+
+    fn schedule() -> WorkSchedule {
+        WorkSchedule {
+            time_zone: chrono_tz::UTC,
+            calendar: WorkCalendar {
+                working_days: vec![
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                ],
+                holidays: Vec::new(),
+            },
+            start: NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            late_grace_minutes: 5,
+        }
+    }
+
+    // Monday 3 March 2025.
+    fn monday() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 3).unwrap()
+    }
+
+    fn event(event_type: EAttendanceEventType, occurred_at: &str) -> AttendanceEventModel {
+        let occurred_at = occurred_at.parse::<DateTime<Utc>>().unwrap();
+        AttendanceEventModel {
+            id: 1,
+            attendance_event_uuid: Uuid::nil(),
+            employee_id: 1,
+            event_type: event_type.to_string(),
+            occurred_at,
+            work_date: monday(),
+            note: None,
+            corrected_by_employee_id: None,
+            status: Some(1),
+            create_at: occurred_at.naive_utc(),
+            update_at: occurred_at.naive_utc(),
+        }
+    }
+
+    #[test]
+    fn test_complete_day_counts_work_break_and_late_minutes() {
+        let events = [
+            event(EAttendanceEventType::ClockIn, "2025-03-03T08:10:00Z"),
+            event(EAttendanceEventType::BreakStart, "2025-03-03T12:00:00Z"),
+            event(EAttendanceEventType::BreakEnd, "2025-03-03T13:00:00Z"),
+            event(EAttendanceEventType::ClockOut, "2025-03-03T17:00:00Z"),
+        ];
+        let timesheet = build_timesheet(&schedule(), monday(), &events, false);
+        assert!(timesheet.is_complete);
+        assert_eq!(timesheet.worked_minutes, 470);
+        assert_eq!(timesheet.break_minutes, 60);
+        assert_eq!(timesheet.late_minutes, 10);
+        assert_eq!(timesheet.early_leave_minutes, 0);
+        assert_eq!(timesheet.first_clock_in, Some(events[0].occurred_at));
+        assert_eq!(timesheet.last_clock_out, Some(events[3].occurred_at));
+    }
+
+    #[test]
+    fn test_late_arrival_within_grace_is_not_late_but_early_leave_counts() {
+        let events = [
+            event(EAttendanceEventType::ClockIn, "2025-03-03T08:04:00Z"),
+            event(EAttendanceEventType::ClockOut, "2025-03-03T16:30:00Z"),
+        ];
+        let timesheet = build_timesheet(&schedule(), monday(), &events, false);
+        assert_eq!(timesheet.worked_minutes, 506);
+        assert_eq!(timesheet.late_minutes, 0);
+        assert_eq!(timesheet.early_leave_minutes, 30);
+    }
+
+    #[test]
+    fn test_missing_or_unexpected_events_make_the_day_incomplete() {
+        let open = [event(EAttendanceEventType::ClockIn, "2025-03-03T08:00:00Z")];
+        let timesheet = build_timesheet(&schedule(), monday(), &open, false);
+        assert!(!timesheet.is_complete);
+        assert_eq!(timesheet.last_clock_out, None);
+
+        let unexpected = [
+            event(EAttendanceEventType::BreakEnd, "2025-03-03T07:00:00Z"),
+            event(EAttendanceEventType::ClockIn, "2025-03-03T08:00:00Z"),
+            event(EAttendanceEventType::ClockOut, "2025-03-03T16:00:00Z"),
+        ];
+        let timesheet = build_timesheet(&schedule(), monday(), &unexpected, false);
+        assert!(!timesheet.is_complete);
+        assert_eq!(timesheet.worked_minutes, 480);
+        // An incomplete day is not counted as an early leave.
+        assert_eq!(timesheet.early_leave_minutes, 0);
+    }
+
+    #[test]
+    fn test_clock_out_after_midnight_belongs_to_the_day_of_the_clock_in() {
+        let events = [
+            event(EAttendanceEventType::ClockIn, "2025-03-03T22:00:00Z"),
+            event(EAttendanceEventType::ClockOut, "2025-03-04T06:00:00Z"),
+        ];
+        let timesheet = build_timesheet(&schedule(), monday(), &events, false);
+        assert!(timesheet.is_complete);
+        assert_eq!(timesheet.worked_minutes, 480);
+        assert_eq!(timesheet.early_leave_minutes, 0);
+    }
+
+    #[test]
+    fn test_day_without_events_is_absent_only_on_an_uncovered_working_day() {
+        let saturday = NaiveDate::from_ymd_opt(2025, 3, 8).unwrap();
+        assert!(build_timesheet(&schedule(), monday(), &[], false).is_absent());
+        assert!(!build_timesheet(&schedule(), monday(), &[], true).is_absent());
+        assert!(!build_timesheet(&schedule(), saturday, &[], false).is_absent());
+    }
+
+    #[test]
+    fn test_timesheets_cover_every_day_and_their_leave() {
+        let events = [event(EAttendanceEventType::ClockIn, "2025-03-03T08:00:00Z")];
+        let tuesday = NaiveDate::from_ymd_opt(2025, 3, 4).unwrap();
+        let wednesday = NaiveDate::from_ymd_opt(2025, 3, 5).unwrap();
+        let timesheets =
+            build_timesheets(&schedule(), monday(), wednesday, &events, &[(tuesday, tuesday)]);
+        let days = timesheets.iter().map(|day| day.work_date).collect::<Vec<_>>();
+        assert_eq!(days, vec![monday(), tuesday, wednesday]);
+        assert_eq!(timesheets[0].events.len(), 1);
+        assert!(timesheets[1].on_leave && !timesheets[1].is_absent());
+        assert!(timesheets[2].is_absent());
+    }
+}
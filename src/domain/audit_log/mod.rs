@@ -0,0 +1,94 @@
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
+use serde::{Deserialize, Serialize};
+
+pub mod request;
+pub mod response;
+pub mod services;
+
+#[derive(Debug, PartialEq, Clone, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "audit_logs", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique, indexed)]
+    pub audit_log_uuid: Uuid,
+    #[sea_orm()]
+    pub actor_uuid: Uuid,
+    #[sea_orm()]
+    pub action: String,
+    #[sea_orm()]
+    pub entity_type: String,
+    #[sea_orm()]
+    pub entity_uuid: Uuid,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub diff: Json,
+    #[sea_orm(nullable)]
+    pub ip: Option<String>,
+    #[sea_orm(nullable)]
+    pub request_id: Option<String>,
+    #[sea_orm()]
+    pub create_at: NaiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+    utoipa::ToSchema,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EAuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+    utoipa::ToSchema,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EAuditEntity {
+    User,
+    Employee,
+    Department,
+    Position,
+    LeaveType,
+    LeaveRequest,
+    AttendanceEvent,
+    ShiftTemplate,
+    ShiftAssignment,
+    Holiday,
+}
+
+// Who sends the request being audited, extracted from the access token and the request headers.
+#[derive(Debug, Clone)]
+pub struct AuditContext {
+    pub actor_uuid: Uuid,
+    pub ip: Option<String>,
+    pub request_id: Option<String>,
+}
@@ -0,0 +1,18 @@
+use crate::domain::audit_log::{EAuditAction, EAuditEntity};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams)]
+pub struct AuditLogQueryParam {
+    pub actor_uuid: Option<Uuid>,
+    pub action: Option<EAuditAction>,
+    pub entity_type: Option<EAuditEntity>,
+    pub entity_uuid: Option<Uuid>,
+    pub request_id: Option<String>,
+    // Only entries written at or after this time.
+    pub from: Option<NaiveDateTime>,
+    // Only entries written before this time.
+    pub to: Option<NaiveDateTime>,
+}
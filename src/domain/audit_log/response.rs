@@ -0,0 +1,36 @@
+use crate::domain::model::AuditLogModel;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogResponse {
+    pub audit_log_uuid: Uuid,
+    pub actor_uuid: Uuid,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_uuid: Uuid,
+    // Changed fields as `{"field": {"before": .., "after": ..}}`.
+    #[schema(value_type = Object)]
+    pub diff: serde_json::Value,
+    pub ip: Option<String>,
+    pub request_id: Option<String>,
+    pub create_at: NaiveDateTime,
+}
+
+impl From<AuditLogModel> for AuditLogResponse {
+    fn from(model: AuditLogModel) -> Self {
+        Self {
+            audit_log_uuid: model.audit_log_uuid,
+            actor_uuid: model.actor_uuid,
+            action: model.action,
+            entity_type: model.entity_type,
+            entity_uuid: model.entity_uuid,
+            diff: model.diff,
+            ip: model.ip,
+            request_id: model.request_id,
+            create_at: model.create_at,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::domain::audit_log::request::AuditLogQueryParam;
+use crate::domain::audit_log::response::AuditLogResponse;
+use crate::domain::audit_log::{AuditContext, EAuditAction, EAuditEntity};
+use crate::domain::entity::AuditLogEntity;
+use crate::domain::model::AuditLogModel;
+use crate::util::filter_and_pagination::{PageQueryParam, Paginated};
+use chrono::Utc;
+use sea_orm::DatabaseTransaction;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+// Fields never written to the audit log.
+const HIDDEN_FIELDS: [&str; 1] = ["password"];
+
+// Writes an audit entry inside the transaction of the audited write, `before` is `None` for a
+// creation and `after` is `None` for a hard deletion.
+pub async fn record_audit_log<T: Serialize>(
+    tx: &DatabaseTransaction,
+    context: &AuditContext,
+    action: EAuditAction,
+    entity_type: EAuditEntity,
+    entity_uuid: Uuid,
+    before: Option<&T>,
+    after: Option<&T>,
+) -> AppResult {
+    let audit_log = AuditLogModel {
+        id: Default::default(),
+        audit_log_uuid: Uuid::new_v4(),
+        actor_uuid: context.actor_uuid,
+        action: action.to_string(),
+        entity_type: entity_type.to_string(),
+        entity_uuid,
+        diff: diff(serde_json::to_value(before)?, serde_json::to_value(after)?),
+        ip: context.ip.clone(),
+        request_id: context.request_id.clone(),
+        create_at: Utc::now().naive_utc(),
+    };
+    AuditLogEntity::append(tx, &audit_log)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Audit log".to_string() })?;
+    Ok(())
+}
+
+pub async fn service_get_list_audit_log(
+    state: &AppState,
+    query: AuditLogQueryParam,
+    param: PageQueryParam,
+) -> AppResult<Paginated<AuditLogResponse>> {
+    tracing::info!("Get audit log list with query: {query:?}, parameter: {param:?}");
+    let audit_logs = AuditLogEntity::search(&*state.db, &query, param).await?;
+    Ok(audit_logs.map(AuditLogResponse::from))
+}
+
+// Top level fields whose value differs between the two snapshots.
+fn diff(before: Value, after: Value) -> Value {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+    let fields = before.keys().chain(after.keys()).collect::<BTreeSet<_>>();
+
+    let mut changes = Map::new();
+    for field in fields {
+        if HIDDEN_FIELDS.contains(&field.as_str()) {
+            continue;
+        }
+        let old = before.get(field).unwrap_or(&Value::Null);
+        let new = after.get(field).unwrap_or(&Value::Null);
+        if old != new {
+            changes.insert(field.clone(), json!({ "before": old, "after": new }));
+        }
+    }
+    Value::Object(changes)
+}
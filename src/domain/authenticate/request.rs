@@ -8,6 +8,8 @@ pub struct LoginByEmailRequest {
     #[validate(email)]
     pub email: String,
     pub password: String,
+    #[validate(length(max = 100))]
+    pub device: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, Validate, IntoParams)]
@@ -21,3 +23,13 @@ pub struct ForgetPasswordQueryParam {
     #[validate(email)]
     pub email: String,
 }
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct ResetPasswordRequest {
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 1))]
+    pub code: String,
+    #[validate(length(min = 8))]
+    pub new_password: String,
+}
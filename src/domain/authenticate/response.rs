@@ -1,6 +1,9 @@
+use crate::infrastructure::persistence::redis_client::services::SessionValue;
 use crate::util::constant::BEARER;
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[serde(tag = "type")]
@@ -34,3 +37,26 @@ pub struct ForgetPasswordResponse {
     pub expire_in: u64,
     pub message: String,
 }
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SessionResponse {
+    pub session_id: Uuid,
+    pub device: Option<String>,
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub is_current: bool,
+}
+
+impl SessionResponse {
+    pub fn new(session: SessionValue, current_session_id: Uuid) -> Self {
+        Self {
+            is_current: session.session_id == current_session_id,
+            session_id: session.session_id,
+            device: session.device,
+            user_agent: session.user_agent,
+            ip: session.ip,
+            created_at: session.created_at,
+        }
+    }
+}
@@ -1,18 +1,38 @@
 use crate::core::app_state::AppState;
 use crate::core::error::{AppError, AppResult};
-use crate::domain::authenticate::request::LoginByEmailRequest;
-use crate::domain::authenticate::response::TokenResponse;
-use crate::domain::entity::UserEntity;
+use crate::domain::authenticate::request::{
+    ForgetPasswordQueryParam, LoginByEmailRequest, ResetPasswordRequest,
+};
+use crate::domain::authenticate::response::{
+    ForgetPasswordResponse, SessionResponse, TokenResponse,
+};
+use crate::domain::entity::{RoleEntity, UserEntity};
+use crate::domain::model::UserModel;
 use crate::infrastructure::persistence::redis_client;
-use crate::infrastructure::persistence::redis_client::services::SessionKey;
+use crate::infrastructure::middleware::client_info::ClientInfo;
+use crate::infrastructure::persistence::redis_client::services::{
+    ForgetPasswordAttemptKey, ForgetPasswordKey, ForgetPasswordRequestKey, InvitationKey,
+};
+use crate::infrastructure::persistence::repo_interface::WriteRepository;
 use crate::infrastructure::third_party;
-use crate::util::password;
+use crate::infrastructure::third_party::email_client::EmailClientExt;
+use crate::infrastructure::third_party::template::{ForgetPasswordTemplate, InvitationTemplate};
+use crate::util::claim::UserClaims;
+use crate::util::constant::{
+    CHECK_EMAIL_MESSAGE, CODE_LEN, EXPIRE_FORGET_PASS_CODE_SECS, EXPIRE_INVITATION_CODE_SECS,
+    MAX_FORGET_PASS_CODE_ATTEMPTS, MAX_FORGET_PASS_REQUESTS,
+};
+use crate::util::{hash, password};
+use crate::util::random::generate_random_string;
+use chrono::Utc;
+use sea_orm::TransactionTrait;
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub async fn service_login_by_email(
     state: &AppState,
     req: LoginByEmailRequest,
+    client: ClientInfo,
 ) -> AppResult<TokenResponse> {
     tracing::info!("User login request :{req:?}.");
     let user = UserEntity::repo_find_by_email_and_status(&*state.db, &req.email, &1).await;
@@ -21,18 +41,180 @@ pub async fn service_login_by_email(
     }
     let user_unwrap = user.unwrap();
     password::verify(req.password, user_unwrap.password).await?;
-    let session_id = redis_client::session::set(&state.redis, user_unwrap.user_uuid).await?;
+    let permissions = RoleEntity::find_permission_codes(&*state.db, user_unwrap.role_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Role".to_string() })?;
+    let session_id = redis_client::session::set(
+        &state.redis,
+        user_unwrap.user_uuid,
+        req.device,
+        client.user_agent,
+        client.ip,
+    )
+    .await?;
     let res = third_party::token::service_generate_tokens(
+        &state.redis,
         user_unwrap.user_uuid,
         user_unwrap.role_id,
+        permissions,
         session_id,
-    )?;
+    )
+    .await?;
     Ok(res)
 }
 
-pub async fn service_logout(state: &AppState, user_id: Uuid) -> AppResult<()> {
-    tracing::info!("Logout user id: {user_id}");
-    let key = SessionKey { user_id };
+pub async fn service_logout(state: &AppState, claims: &UserClaims) -> AppResult<()> {
+    tracing::info!("Logout user id: {}, session id: {}", claims.uuid, claims.sid);
+    redis_client::session::revoke(&state.redis, claims.uuid, claims.sid).await?;
+    Ok(())
+}
+
+pub async fn service_get_sessions(
+    state: &AppState,
+    claims: &UserClaims,
+) -> AppResult<Vec<SessionResponse>> {
+    tracing::info!("Get sessions of user: {}", claims.uuid);
+    let sessions = redis_client::session::list(&state.redis, claims.uuid).await?;
+    Ok(sessions
+        .into_iter()
+        .map(|session| SessionResponse::new(session, claims.sid))
+        .collect())
+}
+
+pub async fn service_revoke_session(
+    state: &AppState,
+    claims: &UserClaims,
+    session_id: Uuid,
+) -> AppResult {
+    if !redis_client::session::revoke(&state.redis, claims.uuid, session_id).await? {
+        return Err(AppError::EntityNotFoundError { detail: "Session not found".to_string() });
+    }
+    Ok(())
+}
+
+pub async fn service_revoke_all_sessions(state: &AppState, claims: &UserClaims) -> AppResult {
+    redis_client::session::revoke_all(&state.redis, claims.uuid).await
+}
+
+// The response is the same whether the email belongs to a user or not, so the endpoint can not
+// be used to enumerate accounts.
+pub async fn service_forget_password(
+    state: &AppState,
+    param: ForgetPasswordQueryParam,
+) -> AppResult<ForgetPasswordResponse> {
+    tracing::info!("Forget password request: {param:?}.");
+    let response = ForgetPasswordResponse {
+        expire_in: EXPIRE_FORGET_PASS_CODE_SECS.as_secs(),
+        message: CHECK_EMAIL_MESSAGE.to_string(),
+    };
+    let Some(user) =
+        UserEntity::repo_find_by_email_and_status(&*state.db, &param.email, &1).await
+    else {
+        tracing::info!("Forget password requested for an unknown email.");
+        return Ok(response);
+    };
+    let key = ForgetPasswordKey { user_id: user.user_uuid };
+    let ttl = redis_client::services::get_tll(&state.redis, &key).await?;
+    if ttl > 0 {
+        tracing::info!("Forget password code is still available for user: {}", user.user_uuid);
+        return Ok(response);
+    }
+
+    // A user out of attempts waits for the attempt window to end, whatever codes are asked.
+    let attempt_key = ForgetPasswordAttemptKey { user_id: user.user_uuid };
+    let attempts = redis_client::services::get(&state.redis, &attempt_key).await?;
+    if attempts.is_some_and(|attempts| attempts >= MAX_FORGET_PASS_CODE_ATTEMPTS) {
+        tracing::warn!("Security event: forget password asked by locked user {}.", user.user_uuid);
+        return Ok(response);
+    }
+    let request_key = ForgetPasswordRequestKey { user_id: user.user_uuid };
+    let requests = redis_client::services::incr(&state.redis, &request_key).await?;
+    if requests > MAX_FORGET_PASS_REQUESTS {
+        tracing::warn!("Security event: too many forget password codes for {}.", user.user_uuid);
+        return Ok(response);
+    }
+
+    let code = generate_random_string(CODE_LEN);
+    redis_client::services::set(&state.redis, (&key, &code)).await?;
+    send_forget_password_code(state, &user, code).await?;
+    Ok(response)
+}
+
+pub async fn service_reset_password(state: &AppState, req: ResetPasswordRequest) -> AppResult {
+    tracing::info!("Reset password for email: {}.", req.email);
+    let code_expired =
+        || AppError::EntityNotAvailableError { detail: "The code is expired!".to_string() };
+    let tx = state.db.begin().await?;
+    let mut user = UserEntity::repo_find_by_email_and_status(&tx, &req.email, &1)
+        .await
+        .ok_or_else(code_expired)?;
+    let key = ForgetPasswordKey { user_id: user.user_uuid };
+    let invitation_key = InvitationKey { user_id: user.user_uuid };
+    let attempt_key = ForgetPasswordAttemptKey { user_id: user.user_uuid };
+    // An invitation code sets the first password the same way a forget password code does.
+    let codes = [
+        redis_client::services::get(&state.redis, &key).await?,
+        redis_client::services::get(&state.redis, &invitation_key).await?,
+    ];
+    if codes.iter().all(Option::is_none) {
+        return Err(code_expired());
+    }
+    // Counted before the compare, so parallel guesses can not all pass the limit.
+    let attempts = redis_client::services::incr(&state.redis, &attempt_key).await?;
+    if attempts > MAX_FORGET_PASS_CODE_ATTEMPTS {
+        redis_client::services::del(&state.redis, &key).await?;
+        redis_client::services::del(&state.redis, &invitation_key).await?;
+        return Err(code_expired());
+    }
+    if !codes.iter().flatten().any(|code| hash::constant_time_eq(code, &req.code)) {
+        if attempts == MAX_FORGET_PASS_CODE_ATTEMPTS {
+            tracing::warn!(
+                "Security event: too many wrong forget password codes for user {}, drop the code.",
+                user.user_uuid
+            );
+            redis_client::services::del(&state.redis, &key).await?;
+            redis_client::services::del(&state.redis, &invitation_key).await?;
+        }
+        return Err(AppError::BadRequestError("The code is not correct!".to_string()));
+    }
+
+    let user_id = user.user_uuid;
+    user.password = password::hash(req.new_password).await?;
+    user.updated_at = Utc::now().naive_utc();
+    if UserEntity::update(&tx, user).await.is_none() {
+        return Err(AppError::EntityNotAvailableError { detail: "User".to_string() });
+    }
+    tx.commit().await?;
+
     redis_client::services::del(&state.redis, &key).await?;
+    redis_client::services::del(&state.redis, &invitation_key).await?;
+    redis_client::services::del(&state.redis, &attempt_key).await?;
+    redis_client::session::revoke_all(&state.redis, user_id).await?;
+    tracing::info!("Reset password success and invalidate sessions of user: {user_id}");
     Ok(())
 }
+
+// Emails a user created by an administrator the code that sets its first password.
+pub async fn service_send_invitation(state: &AppState, user: &UserModel) -> AppResult {
+    tracing::info!("Send invitation to user: {}", user.user_uuid);
+    let code = generate_random_string(CODE_LEN);
+    let key = InvitationKey { user_id: user.user_uuid };
+    redis_client::services::set(&state.redis, (&key, &code)).await?;
+    let template = InvitationTemplate {
+        full_name: user.full_name.clone(),
+        email: user.email.clone(),
+        code,
+        expire_in_hours: EXPIRE_INVITATION_CODE_SECS.as_secs() / 3600,
+    };
+    state.email.send_template(&user.email, &template).await
+}
+
+async fn send_forget_password_code(state: &AppState, user: &UserModel, code: String) -> AppResult {
+    tracing::info!("Send forget password code to user: {}", user.user_uuid);
+    let template = ForgetPasswordTemplate {
+        full_name: user.full_name.clone(),
+        code,
+        expire_in_minutes: EXPIRE_FORGET_PASS_CODE_SECS.as_secs() / 60,
+    };
+    state.email.send_template(&user.email, &template).await
+}
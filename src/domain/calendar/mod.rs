@@ -0,0 +1,65 @@
+use crate::domain::entity::{HolidayEntity, OrganizationEntity};
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
+use serde::{Deserialize, Serialize};
+
+pub mod request;
+pub mod response;
+pub mod services;
+pub mod work_calendar;
+
+// Day off of an organization, either once on `date` or every year on its month and day.
+#[derive(Debug, PartialEq, Clone, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "holidays", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique, indexed)]
+    pub holiday_uuid: Uuid,
+    #[sea_orm(indexed)]
+    pub organization_id: i64,
+    #[sea_orm()]
+    pub name: String,
+    // Day of a one-off holiday, the first year of a recurring one.
+    #[sea_orm()]
+    pub date: NaiveDate,
+    #[sea_orm(default_value = false)]
+    pub recurring: bool,
+    #[sea_orm(nullable, default_value = 1)]
+    pub status: Option<i16>,
+    #[sea_orm()]
+    pub create_at: NaiveDateTime,
+    #[sea_orm()]
+    pub update_at: NaiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::organization::Entity",
+        from = "Column::OrganizationId",
+        to = "super::organization::Column::Id"
+    )]
+    Organization,
+}
+
+impl Related<OrganizationEntity> for HolidayEntity {
+    fn to() -> RelationDef {
+        Relation::Organization.def()
+    }
+}
+
+impl Model {
+    // A recurring holiday starts on its first year, one on february 29th only falls on leap
+    // years.
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        if !self.recurring {
+            return self.date == date;
+        }
+        self.date <= date && self.date.month() == date.month() && self.date.day() == date.day()
+    }
+}
@@ -0,0 +1,42 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateHolidayRequest {
+    pub organization_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+    // Day of a one-off holiday, the first year of a recurring one.
+    pub date: NaiveDate,
+    // Every year on the same month and day, a one-off holiday by default.
+    pub recurring: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateHolidayRequest {
+    pub holiday_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+    pub date: Option<NaiveDate>,
+    pub recurring: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct DeleteHolidayRequest {
+    pub holiday_uuid: Uuid,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams)]
+pub struct HolidayQueryParam {
+    pub organization_uuid: Uuid,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams)]
+pub struct WorkingDaysQueryParam {
+    // First and last day, both included.
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
@@ -0,0 +1,55 @@
+use crate::domain::model::HolidayModel;
+use crate::domain::organization::EWeekday;
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HolidayResponse {
+    pub holiday_uuid: Uuid,
+    pub organization_uuid: Uuid,
+    pub name: String,
+    pub date: NaiveDate,
+    pub recurring: bool,
+    pub status: Option<i16>,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl HolidayResponse {
+    pub fn new(holiday: HolidayModel, organization_uuid: Uuid) -> Self {
+        HolidayResponse {
+            holiday_uuid: holiday.holiday_uuid,
+            organization_uuid,
+            name: holiday.name,
+            date: holiday.date,
+            recurring: holiday.recurring,
+            status: holiday.status,
+            create_at: holiday.create_at,
+            update_at: holiday.update_at,
+        }
+    }
+}
+
+// Holiday falling on a day of the requested range.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct HolidayDateResponse {
+    pub holiday_uuid: Uuid,
+    pub name: String,
+    pub date: NaiveDate,
+    // Whether the day would otherwise be a working day.
+    pub on_working_week: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WorkingDaysResponse {
+    pub employee_uuid: Uuid,
+    pub organization_uuid: Uuid,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub working_week: Vec<EWeekday>,
+    pub working_days: u32,
+    pub dates: Vec<NaiveDate>,
+    pub holidays: Vec<HolidayDateResponse>,
+}
@@ -0,0 +1,254 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::domain::audit_log::services::record_audit_log;
+use crate::domain::audit_log::{AuditContext, EAuditAction, EAuditEntity};
+use crate::domain::calendar::request::{
+    CreateHolidayRequest, DeleteHolidayRequest, HolidayQueryParam, UpdateHolidayRequest,
+    WorkingDaysQueryParam,
+};
+use crate::domain::calendar::response::{
+    HolidayDateResponse, HolidayResponse, WorkingDaysResponse,
+};
+use crate::domain::calendar::work_calendar::WorkCalendar;
+use crate::domain::employee::services::{find_employee_by_uuid, find_employee_organization_id};
+use crate::domain::entity::{HolidayEntity, OrganizationEntity};
+use crate::domain::model::{HolidayModel, OrganizationModel};
+use crate::domain::organization::services::find_organization_by_uuid;
+use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
+use crate::util::filter_and_pagination::{PageQueryParam, Paginated};
+use chrono::{Datelike, Utc};
+use sea_orm::{ConnectionTrait, DatabaseTransaction, TransactionTrait};
+use std::fmt::Debug;
+use uuid::Uuid;
+
+// Longest range of days of a working days request.
+const MAX_CALENDAR_DAYS: i64 = 366;
+
+pub async fn service_create_holiday(
+    state: &AppState,
+    context: &AuditContext,
+    req: CreateHolidayRequest,
+) -> AppResult<HolidayResponse> {
+    tracing::info!("Create new holiday request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let organization = find_organization_by_uuid(&tx, &req.organization_uuid).await?;
+
+    let holiday = HolidayModel {
+        id: Default::default(),
+        holiday_uuid: Uuid::new_v4(),
+        organization_id: organization.id,
+        name: req.name,
+        date: req.date,
+        recurring: req.recurring.unwrap_or_default(),
+        status: Some(1),
+        create_at: Utc::now().naive_utc(),
+        update_at: Utc::now().naive_utc(),
+    };
+    check_free_date(&tx, &holiday).await?;
+    let holiday_id = HolidayEntity::create(&tx, &holiday)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Holiday".to_string() })?;
+    let holiday = HolidayModel { id: holiday_id, ..holiday };
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::Holiday,
+        holiday.holiday_uuid,
+        None,
+        Some(&holiday),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(HolidayResponse::new(holiday, organization.organization_uuid))
+}
+
+pub async fn service_update_holiday(
+    state: &AppState,
+    context: &AuditContext,
+    req: UpdateHolidayRequest,
+) -> AppResult<HolidayResponse> {
+    tracing::info!("Update holiday request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut holiday = find_active_holiday_by_uuid(&tx, &req.holiday_uuid).await?;
+    let before = holiday.clone();
+    let organization = find_organization_by_id(&tx, holiday.organization_id).await?;
+
+    if let Some(name) = req.name {
+        holiday.name = name;
+    }
+    if let Some(date) = req.date {
+        holiday.date = date;
+    }
+    if let Some(recurring) = req.recurring {
+        holiday.recurring = recurring;
+    }
+    check_free_date(&tx, &holiday).await?;
+    holiday.update_at = Utc::now().naive_utc();
+
+    HolidayEntity::update(&tx, holiday.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Holiday".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::Holiday,
+        holiday.holiday_uuid,
+        Some(&before),
+        Some(&holiday),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(HolidayResponse::new(holiday, organization.organization_uuid))
+}
+
+pub async fn service_delete_holiday(
+    state: &AppState,
+    context: &AuditContext,
+    req: DeleteHolidayRequest,
+) -> AppResult<HolidayResponse> {
+    tracing::info!("Delete holiday request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut holiday = find_active_holiday_by_uuid(&tx, &req.holiday_uuid).await?;
+    let before = holiday.clone();
+    let organization = find_organization_by_id(&tx, holiday.organization_id).await?;
+    holiday.status = Some(0);
+    holiday.update_at = Utc::now().naive_utc();
+
+    HolidayEntity::update(&tx, holiday.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Holiday".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Delete,
+        EAuditEntity::Holiday,
+        holiday.holiday_uuid,
+        Some(&before),
+        Some(&holiday),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(HolidayResponse::new(holiday, organization.organization_uuid))
+}
+
+pub async fn service_get_list_holiday(
+    state: &AppState,
+    query: HolidayQueryParam,
+    param: PageQueryParam,
+) -> AppResult<Paginated<HolidayResponse>> {
+    tracing::info!("Get holiday list of organization {}: {param:?}", query.organization_uuid);
+    let organization = find_organization_by_uuid(&*state.db, &query.organization_uuid).await?;
+    let holidays = HolidayEntity::find_by_organization(&*state.db, organization.id, param).await?;
+    Ok(holidays.map(|holiday| HolidayResponse::new(holiday, organization.organization_uuid)))
+}
+
+// Working days of the employee between both days, in the calendar of the organization of its
+// primary department on the last day.
+pub async fn service_get_working_days(
+    state: &AppState,
+    employee_uuid: Uuid,
+    query: WorkingDaysQueryParam,
+) -> AppResult<WorkingDaysResponse> {
+    tracing::info!("Get working days of employee {employee_uuid}: {query:?}");
+    if query.to < query.from {
+        return Err(AppError::BadRequestError("The last day is before the first day".to_string()));
+    }
+    if (query.to - query.from).num_days() >= MAX_CALENDAR_DAYS {
+        return Err(AppError::BadRequestError(format!(
+            "A working days request covers at most {MAX_CALENDAR_DAYS} days"
+        )));
+    }
+    let employee = find_employee_by_uuid(&*state.db, &employee_uuid).await?;
+    let organization_id = find_employee_organization_id(&*state.db, &employee, query.to).await?;
+    let organization = find_organization_by_id(&*state.db, organization_id).await?;
+    let holidays = find_holidays(&*state.db, organization_id).await?;
+    let calendar = WorkCalendar::new(&organization, holidays);
+
+    let holidays = query
+        .from
+        .iter_days()
+        .take_while(|day| *day <= query.to)
+        .filter_map(|day| {
+            calendar.holiday_on(day).map(|holiday| HolidayDateResponse {
+                holiday_uuid: holiday.holiday_uuid,
+                name: holiday.name.clone(),
+                date: day,
+                on_working_week: calendar.working_days.contains(&day.weekday()),
+            })
+        })
+        .collect();
+    let dates = calendar.working_days_between(query.from, query.to);
+    Ok(WorkingDaysResponse {
+        employee_uuid,
+        organization_uuid: organization.organization_uuid,
+        from: query.from,
+        to: query.to,
+        working_week: organization.working_days(),
+        working_days: u32::try_from(dates.len()).unwrap_or(u32::MAX),
+        dates,
+        holidays,
+    })
+}
+
+// Calendar of the organization with its active holidays.
+pub async fn find_work_calendar<DB>(conn: &DB, organization_id: i64) -> AppResult<WorkCalendar>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let organization = find_organization_by_id(conn, organization_id).await?;
+    Ok(WorkCalendar::new(&organization, find_holidays(conn, organization_id).await?))
+}
+
+pub async fn find_holidays<DB>(conn: &DB, organization_id: i64) -> AppResult<Vec<HolidayModel>>
+where
+    DB: ConnectionTrait + Debug,
+{
+    HolidayEntity::find_active_by_organization(conn, organization_id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))
+}
+
+// Two active holidays of an organization never fall on the same day.
+async fn check_free_date(tx: &DatabaseTransaction, holiday: &HolidayModel) -> AppResult {
+    let holidays = find_holidays(tx, holiday.organization_id).await?;
+    let clash = holidays.iter().find(|other| {
+        other.id != holiday.id && (other.occurs_on(holiday.date) || holiday.occurs_on(other.date))
+    });
+    match clash {
+        Some(other) => Err(AppError::EntityExistsError {
+            detail: format!("Holiday {} already falls on this day!", other.name),
+        }),
+        None => Ok(()),
+    }
+}
+
+async fn find_active_holiday_by_uuid(
+    tx: &DatabaseTransaction,
+    holiday_uuid: &Uuid,
+) -> AppResult<HolidayModel> {
+    let holiday = HolidayEntity::find_data_by_uuid(tx, holiday_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError {
+            detail: format!("Holiday not found by uuid {holiday_uuid}"),
+        }
+    })?;
+    if holiday.status != Some(1) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!("Holiday is deleted {holiday_uuid}"),
+        });
+    }
+    Ok(holiday)
+}
+
+async fn find_organization_by_id<DB>(
+    conn: &DB,
+    organization_id: i64,
+) -> AppResult<OrganizationModel>
+where
+    DB: ConnectionTrait + Debug,
+{
+    OrganizationEntity::find_data_by_id(conn, organization_id).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: "Organization not found".to_string() }
+    })
+}
@@ -0,0 +1,115 @@
+use crate::domain::model::{HolidayModel, OrganizationModel};
+use chrono::{Datelike, NaiveDate, Weekday};
+
+// Working week and active holidays of an organization, used by leave, attendance and shifts to
+// tell working days apart.
+#[derive(Debug, Clone)]
+pub struct WorkCalendar {
+    pub working_days: Vec<Weekday>,
+    pub holidays: Vec<HolidayModel>,
+}
+
+impl WorkCalendar {
+    pub fn new(organization: &OrganizationModel, holidays: Vec<HolidayModel>) -> Self {
+        WorkCalendar {
+            working_days: organization.working_days().into_iter().map(Weekday::from).collect(),
+            holidays,
+        }
+    }
+
+    pub fn holiday_on(&self, date: NaiveDate) -> Option<&HolidayModel> {
+        self.holidays.iter().find(|holiday| holiday.occurs_on(date))
+    }
+
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        self.working_days.contains(&date.weekday()) && self.holiday_on(date).is_none()
+    }
+
+    // Working days between both days, both included.
+    pub fn working_days_between(&self, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        let days = from.iter_days().take_while(|day| *day <= to);
+        days.filter(|day| self.is_working_day(*day)).collect()
+    }
+
+    pub fn count_working_days(&self, from: NaiveDate, to: NaiveDate) -> u32 {
+        u32::try_from(self.working_days_between(from, to).len()).unwrap_or(u32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn holiday(date: NaiveDate, recurring: bool) -> HolidayModel {
+        HolidayModel {
+            id: 1,
+            holiday_uuid: Uuid::nil(),
+            organization_id: 1,
+            name: "Holiday".to_string(),
+            date,
+            recurring,
+            status: Some(1),
+            create_at: date.and_hms_opt(0, 0, 0).unwrap(),
+            update_at: date.and_hms_opt(0, 0, 0).unwrap(),
+        }
+    }
+
+    fn calendar(holidays: Vec<HolidayModel>) -> WorkCalendar {
+        WorkCalendar {
+            working_days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            holidays,
+        }
+    }
+
+    #[test]
+    fn test_weekend_and_holiday_are_not_working_days() {
+        // Wednesday 5 March 2025.
+        let calendar = calendar(vec![holiday(date(2025, 3, 5), false)]);
+        assert!(calendar.is_working_day(date(2025, 3, 4)));
+        assert!(!calendar.is_working_day(date(2025, 3, 5)));
+        assert!(!calendar.is_working_day(date(2025, 3, 8)));
+        assert!(!calendar.is_working_day(date(2025, 3, 9)));
+        // A one-off holiday does not come back the next year.
+        assert!(calendar.holiday_on(date(2026, 3, 5)).is_none());
+    }
+
+    #[test]
+    fn test_recurring_holiday_comes_back_from_its_first_year() {
+        let calendar = calendar(vec![holiday(date(2024, 12, 25), true)]);
+        assert!(calendar.holiday_on(date(2023, 12, 25)).is_none());
+        assert!(calendar.holiday_on(date(2024, 12, 25)).is_some());
+        assert!(calendar.holiday_on(date(2030, 12, 25)).is_some());
+        assert!(calendar.holiday_on(date(2030, 12, 26)).is_none());
+    }
+
+    #[test]
+    fn test_recurring_holiday_on_february_29th_only_falls_on_leap_years() {
+        let calendar = calendar(vec![holiday(date(2024, 2, 29), true)]);
+        assert!(calendar.holiday_on(date(2028, 2, 29)).is_some());
+        assert!(calendar.holiday_on(date(2025, 2, 28)).is_none());
+        assert!(calendar.holiday_on(date(2025, 3, 1)).is_none());
+    }
+
+    #[test]
+    fn test_working_days_between_both_days_included() {
+        // Monday 3 to sunday 16 March 2025, with a holiday on friday 7.
+        let calendar = calendar(vec![holiday(date(2025, 3, 7), false)]);
+        let days = calendar.working_days_between(date(2025, 3, 3), date(2025, 3, 10));
+        let expected = [(3, 3), (3, 4), (3, 5), (3, 6), (3, 10)];
+        assert_eq!(days, expected.map(|(month, day)| date(2025, month, day)).to_vec());
+        assert_eq!(calendar.count_working_days(date(2025, 3, 3), date(2025, 3, 16)), 9);
+        assert_eq!(calendar.count_working_days(date(2025, 3, 8), date(2025, 3, 9)), 0);
+        assert_eq!(calendar.count_working_days(date(2025, 3, 10), date(2025, 3, 3)), 0);
+    }
+}
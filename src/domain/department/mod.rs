@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 pub mod request;
 pub mod response;
-mod services;
+pub mod services;
 
 #[derive(
     Debug, PartialEq, Eq, PartialOrd, Ord, Clone, DeriveEntityModel, Deserialize, Serialize,
@@ -20,6 +20,12 @@ pub struct Model {
     pub department_uuid: Uuid,
     #[sea_orm()]
     pub organization_id: i64,
+    // Division or department this one belongs to, a root department has none.
+    #[sea_orm(nullable)]
+    pub parent_department_id: Option<i64>,
+    // Employee heading the department, who may approve the leave of its employees.
+    #[sea_orm(nullable)]
+    pub head_employee_id: Option<i64>,
     #[sea_orm()]
     pub name: String,
     #[sea_orm()]
@@ -43,6 +49,12 @@ pub enum Relation {
     to = "super::organization::Column::Id"
     )]
     Organization,
+    #[sea_orm(
+    belongs_to = "Entity"
+    from = "Column::ParentDepartmentId",
+    to = "Column::Id"
+    )]
+    Parent,
     #[sea_orm(has_many = "super::position::Entity")]
     Position,
 }
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateDepartmentRequest {
+    pub organization_uuid: Uuid,
+    pub parent_department_uuid: Option<Uuid>,
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+    #[validate(url)]
+    pub image_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateDepartmentRequest {
+    pub department_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+    #[validate(url)]
+    pub image_url: Option<String>,
+    #[validate(range(min = 0, max = 1))]
+    pub status: Option<i16>,
+    // Active employee heading the department.
+    pub head_employee_uuid: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct MoveDepartmentRequest {
+    pub department_uuid: Uuid,
+    // New parent of the department, the department becomes a root when it is missing.
+    pub parent_department_uuid: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct DeleteDepartmentRequest {
+    pub department_uuid: Uuid,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams)]
+pub struct DepartmentQueryParam {
+    pub organization_uuid: Uuid,
+}
@@ -0,0 +1,79 @@
+use crate::domain::model::DepartmentModel;
+use chrono::NaiveDateTime;
+use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DepartmentResponse {
+    pub department_uuid: Uuid,
+    pub organization_uuid: Uuid,
+    pub parent_department_uuid: Option<Uuid>,
+    pub name: String,
+    pub image_url: String,
+    pub status: Option<i16>,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl DepartmentResponse {
+    pub fn new(
+        department: DepartmentModel,
+        organization_uuid: Uuid,
+        parent_department_uuid: Option<Uuid>,
+    ) -> Self {
+        DepartmentResponse {
+            department_uuid: department.department_uuid,
+            organization_uuid,
+            parent_department_uuid,
+            name: department.name,
+            image_url: department.image_url,
+            status: department.status,
+            create_at: department.create_at,
+            update_at: department.update_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DepartmentTreeResponse {
+    pub department_uuid: Uuid,
+    pub name: String,
+    pub image_url: String,
+    pub status: Option<i16>,
+    #[schema(no_recursion)]
+    pub children: Vec<DepartmentTreeResponse>,
+}
+
+impl DepartmentTreeResponse {
+    // Nests the departments under their parents, a department whose parent is not part of
+    // `departments` becomes a root. Siblings keep the order of `departments`.
+    pub fn build(departments: Vec<DepartmentModel>) -> Vec<Self> {
+        let ids = departments.iter().map(|department| department.id).collect::<HashSet<_>>();
+        let mut children: HashMap<Option<i64>, Vec<DepartmentModel>> = HashMap::new();
+        for department in departments {
+            let parent = department.parent_department_id.filter(|parent| ids.contains(parent));
+            children.entry(parent).or_default().push(department);
+        }
+        Self::nest(None, &mut children)
+    }
+
+    fn nest(
+        parent: Option<i64>,
+        children: &mut HashMap<Option<i64>, Vec<DepartmentModel>>,
+    ) -> Vec<Self> {
+        children
+            .remove(&parent)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|department| DepartmentTreeResponse {
+                children: Self::nest(Some(department.id), children),
+                department_uuid: department.department_uuid,
+                name: department.name,
+                image_url: department.image_url,
+                status: department.status,
+            })
+            .collect()
+    }
+}
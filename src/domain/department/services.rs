@@ -0,0 +1,414 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::domain::audit_log::services::record_audit_log;
+use crate::domain::audit_log::{AuditContext, EAuditAction, EAuditEntity};
+use crate::domain::department::request::{
+    CreateDepartmentRequest, DeleteDepartmentRequest, MoveDepartmentRequest,
+    UpdateDepartmentRequest,
+};
+use crate::domain::department::response::{DepartmentResponse, DepartmentTreeResponse};
+use crate::domain::entity::{DepartmentEntity, EmployeeEntity, OrganizationEntity};
+use crate::domain::model::{DepartmentModel, OrganizationModel};
+use crate::domain::organization::services::find_organization_by_uuid;
+use crate::infrastructure::persistence::repo_interface::{
+    DeleteRepository, ReadRepository, WriteRepository,
+};
+use crate::util::filter_and_pagination::{PageQueryParam, Paginated};
+use chrono::Utc;
+use sea_orm::{ConnectionTrait, DatabaseTransaction, TransactionTrait};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use uuid::Uuid;
+
+pub async fn service_create_department(
+    state: &AppState,
+    context: &AuditContext,
+    req: CreateDepartmentRequest,
+) -> AppResult<DepartmentResponse> {
+    tracing::info!("Create new department request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let organization = find_organization_by_uuid(&tx, &req.organization_uuid).await?;
+    let organization = lock_organization(&tx, organization.id).await?;
+    if DepartmentEntity::find_by_organization_and_name(&tx, organization.id, &req.name)
+        .await
+        .is_some()
+    {
+        return Err(AppError::EntityExistsError {
+            detail: "Department name is already exists!".to_string(),
+        });
+    }
+    let parent = match req.parent_department_uuid {
+        Some(parent_uuid) => Some(find_parent_department(&tx, &parent_uuid, organization.id).await?),
+        None => None,
+    };
+
+    let department_model = DepartmentModel {
+        id: Default::default(),
+        department_uuid: Uuid::new_v4(),
+        organization_id: organization.id,
+        parent_department_id: parent.as_ref().map(|parent| parent.id),
+        head_employee_id: None,
+        name: req.name,
+        image_url: req.image_url.unwrap_or_default(),
+        status: Some(1),
+        create_at: Utc::now().naive_utc(),
+        update_at: Utc::now().naive_utc(),
+    };
+    let department_id = DepartmentEntity::create(&tx, &department_model)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Department".to_string() })?;
+    let department_model = DepartmentModel { id: department_id, ..department_model };
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::Department,
+        department_model.department_uuid,
+        None,
+        Some(&department_model),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(DepartmentResponse::new(
+        department_model,
+        organization.organization_uuid,
+        parent.map(|parent| parent.department_uuid),
+    ))
+}
+
+pub async fn service_get_department(
+    state: &AppState,
+    department_uuid: Uuid,
+) -> AppResult<DepartmentResponse> {
+    tracing::info!("Get department with uuid: {department_uuid}");
+    let department = find_department_by_uuid(&*state.db, &department_uuid).await?;
+    let organization = OrganizationEntity::find_data_by_id(&*state.db, department.organization_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Organization not found".to_string(),
+        })?;
+    let parent_uuid = find_parent_uuid(&*state.db, &department).await?;
+    Ok(DepartmentResponse::new(department, organization.organization_uuid, parent_uuid))
+}
+
+pub async fn service_get_list_department(
+    state: &AppState,
+    organization_uuid: Uuid,
+    param: PageQueryParam,
+) -> AppResult<Paginated<DepartmentResponse>> {
+    tracing::info!("Get department list of organization {organization_uuid}: {param:?}");
+    let organization = find_organization_by_uuid(&*state.db, &organization_uuid).await?;
+    let departments =
+        DepartmentEntity::find_by_organization(&*state.db, organization.id, param).await?;
+    let parent_ids = departments
+        .items
+        .iter()
+        .filter_map(|department| department.parent_department_id)
+        .collect::<Vec<_>>();
+    let parent_uuids = DepartmentEntity::find_by_ids(&*state.db, &parent_ids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?
+        .into_iter()
+        .map(|parent| (parent.id, parent.department_uuid))
+        .collect::<HashMap<_, _>>();
+    Ok(departments.map(|department| {
+        let parent_uuid =
+            department.parent_department_id.and_then(|id| parent_uuids.get(&id).copied());
+        DepartmentResponse::new(department, organization_uuid, parent_uuid)
+    }))
+}
+
+pub async fn service_update_department(
+    state: &AppState,
+    context: &AuditContext,
+    req: UpdateDepartmentRequest,
+) -> AppResult<DepartmentResponse> {
+    tracing::info!("Update department request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let department = find_department_by_uuid(&tx, &req.department_uuid).await?;
+    let organization = lock_organization(&tx, department.organization_id).await?;
+    let mut department = find_department_by_uuid(&tx, &req.department_uuid).await?;
+    let before = department.clone();
+
+    if let Some(name) = req.name {
+        department.name = name;
+    }
+    if let Some(image_url) = req.image_url {
+        department.image_url = image_url;
+    }
+    if let Some(status) = req.status {
+        department.status = Some(status);
+    }
+    // Renaming or reactivating must not clash with another active department.
+    if department.status == Some(1) {
+        if let Some(exists) =
+            DepartmentEntity::find_by_organization_and_name(&tx, organization.id, &department.name)
+                .await
+        {
+            if exists.id != department.id {
+                return Err(AppError::EntityExistsError {
+                    detail: "Department name is already exists!".to_string(),
+                });
+            }
+        }
+    }
+    if let Some(head_employee_uuid) = req.head_employee_uuid {
+        let head = EmployeeEntity::find_data_by_uuid(&tx, &head_employee_uuid).await.ok_or_else(
+            || AppError::EntityNotFoundError {
+                detail: format!("Employee not found by uuid {head_employee_uuid}"),
+            },
+        )?;
+        if head.status != Some(1) {
+            return Err(AppError::EntityNotAvailableError {
+                detail: format!("Employee is not active {head_employee_uuid}"),
+            });
+        }
+        department.head_employee_id = Some(head.id);
+    }
+    department.update_at = Utc::now().naive_utc();
+    if before.status == Some(1) && department.status != Some(1) {
+        reparent_children(&tx, context, &department).await?;
+    }
+
+    DepartmentEntity::update(&tx, department.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Department".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::Department,
+        department.department_uuid,
+        Some(&before),
+        Some(&department),
+    )
+    .await?;
+    let parent_uuid = find_parent_uuid(&tx, &department).await?;
+    tx.commit().await?;
+    Ok(DepartmentResponse::new(department, organization.organization_uuid, parent_uuid))
+}
+
+pub async fn service_delete_department(
+    state: &AppState,
+    context: &AuditContext,
+    req: DeleteDepartmentRequest,
+) -> AppResult<i64> {
+    tracing::info!("Delete department request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let department = find_department_by_uuid(&tx, &req.department_uuid).await?;
+    lock_organization(&tx, department.organization_id).await?;
+    let before = find_department_by_uuid(&tx, &req.department_uuid).await?;
+    if before.status == Some(1) {
+        reparent_children(&tx, context, &before).await?;
+    }
+    let department_id = DepartmentEntity::delete_data(&tx, req.department_uuid)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Can not delete department".to_string()))?;
+    let after = find_department_by_uuid(&tx, &req.department_uuid).await?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Delete,
+        EAuditEntity::Department,
+        req.department_uuid,
+        Some(&before),
+        Some(&after),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(department_id)
+}
+
+pub async fn service_move_department(
+    state: &AppState,
+    context: &AuditContext,
+    req: MoveDepartmentRequest,
+) -> AppResult<DepartmentResponse> {
+    tracing::info!("Move department request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let department = find_department_by_uuid(&tx, &req.department_uuid).await?;
+    let organization = lock_organization(&tx, department.organization_id).await?;
+    // Read again under the lock, a concurrent move may have changed the parent.
+    let mut department = find_department_by_uuid(&tx, &req.department_uuid).await?;
+    let before = department.clone();
+
+    let parent = match req.parent_department_uuid {
+        Some(parent_uuid) => {
+            let parent = find_parent_department(&tx, &parent_uuid, organization.id).await?;
+            // The subtree contains the department itself, so this also rejects self parenting.
+            let subtree = DepartmentEntity::find_subtree(&tx, department.id)
+                .await
+                .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+            if subtree.iter().any(|child| child.id == parent.id) {
+                return Err(AppError::BadRequestError(
+                    "Can not move a department under itself or one of its sub departments"
+                        .to_string(),
+                ));
+            }
+            Some(parent)
+        },
+        None => None,
+    };
+    department.parent_department_id = parent.as_ref().map(|parent| parent.id);
+    department.update_at = Utc::now().naive_utc();
+
+    DepartmentEntity::update(&tx, department.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Department".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::Department,
+        department.department_uuid,
+        Some(&before),
+        Some(&department),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(DepartmentResponse::new(
+        department,
+        organization.organization_uuid,
+        parent.map(|parent| parent.department_uuid),
+    ))
+}
+
+pub async fn service_get_department_tree(
+    state: &AppState,
+    organization_uuid: Uuid,
+) -> AppResult<Vec<DepartmentTreeResponse>> {
+    tracing::info!("Get department tree of organization {organization_uuid}");
+    let organization = find_organization_by_uuid(&*state.db, &organization_uuid).await?;
+    let departments = DepartmentEntity::find_all_by_organization(&*state.db, organization.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    Ok(DepartmentTreeResponse::build(departments))
+}
+
+pub async fn service_get_department_subtree(
+    state: &AppState,
+    department_uuid: Uuid,
+) -> AppResult<DepartmentTreeResponse> {
+    tracing::info!("Get sub departments of department {department_uuid}");
+    let department = find_department_by_uuid(&*state.db, &department_uuid).await?;
+    let subtree = DepartmentEntity::find_subtree(&*state.db, department.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    DepartmentTreeResponse::build(subtree)
+        .into_iter()
+        .find(|root| root.department_uuid == department_uuid)
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: format!("Department not found by uuid {department_uuid}"),
+        })
+}
+
+pub async fn service_get_department_ancestors(
+    state: &AppState,
+    department_uuid: Uuid,
+) -> AppResult<Vec<DepartmentResponse>> {
+    tracing::info!("Get parent departments of department {department_uuid}");
+    let department = find_department_by_uuid(&*state.db, &department_uuid).await?;
+    let organization = OrganizationEntity::find_data_by_id(&*state.db, department.organization_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Organization not found".to_string(),
+        })?;
+    let ancestors = DepartmentEntity::find_ancestors(&*state.db, department.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    // Ancestors go from the root down, so the parent of each one is the previous entry.
+    let mut parent_uuid = None;
+    Ok(ancestors
+        .into_iter()
+        .map(|ancestor| {
+            let current_uuid = ancestor.department_uuid;
+            let response =
+                DepartmentResponse::new(ancestor, organization.organization_uuid, parent_uuid);
+            parent_uuid = Some(current_uuid);
+            response
+        })
+        .collect())
+}
+
+// Locks the organization row, so department structure changes of one organization run one
+// after another and the parent and subtree checks see the committed tree.
+async fn lock_organization(
+    tx: &DatabaseTransaction,
+    organization_id: i64,
+) -> AppResult<OrganizationModel> {
+    OrganizationEntity::lock_by_id(tx, organization_id).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: "Organization not found".to_string() }
+    })
+}
+
+async fn find_parent_department(
+    tx: &DatabaseTransaction,
+    parent_uuid: &Uuid,
+    organization_id: i64,
+) -> AppResult<DepartmentModel> {
+    let parent = find_department_by_uuid(tx, parent_uuid).await?;
+    if parent.organization_id != organization_id {
+        return Err(AppError::BadRequestError(
+            "Parent department belongs to another organization".to_string(),
+        ));
+    }
+    if parent.status != Some(1) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!("Parent department is not active {parent_uuid}"),
+        });
+    }
+    Ok(parent)
+}
+
+// Moves the active children of a department that is being deactivated up to its parent.
+async fn reparent_children(
+    tx: &DatabaseTransaction,
+    context: &AuditContext,
+    department: &DepartmentModel,
+) -> AppResult {
+    let children = DepartmentEntity::find_children(tx, department.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    for mut child in children {
+        let before = child.clone();
+        child.parent_department_id = department.parent_department_id;
+        child.update_at = Utc::now().naive_utc();
+        DepartmentEntity::update(tx, child.clone())
+            .await
+            .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Department".to_string() })?;
+        record_audit_log(
+            tx,
+            context,
+            EAuditAction::Update,
+            EAuditEntity::Department,
+            child.department_uuid,
+            Some(&before),
+            Some(&child),
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn find_parent_uuid<DB>(conn: &DB, department: &DepartmentModel) -> AppResult<Option<Uuid>>
+where
+    DB: ConnectionTrait + Debug,
+{
+    match department.parent_department_id {
+        Some(parent_id) => Ok(DepartmentEntity::find_data_by_id(conn, parent_id)
+            .await
+            .map(|parent| parent.department_uuid)),
+        None => Ok(None),
+    }
+}
+
+pub async fn find_department_by_uuid<DB>(conn: &DB, department_uuid: &Uuid) -> AppResult<DepartmentModel>
+where
+    DB: ConnectionTrait + Debug,
+{
+    DepartmentEntity::find_data_by_uuid(conn, department_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError {
+            detail: format!("Department not found by uuid {department_uuid}"),
+        }
+    })
+}
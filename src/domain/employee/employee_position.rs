@@ -1,8 +1,11 @@
 use sea_orm::EntityTrait;
 use sea_orm::PrimaryKeyTrait;
 use sea_orm::DerivePrimaryKey;
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use sea_orm::{ActiveModelBehavior, DeriveEntityModel, DeriveRelation, EnumIter};
+use sea_orm::{ColumnTrait, Condition, Related, RelationDef, RelationTrait};
+use crate::domain::employee::{Assignment, AssignmentEntity};
+use sea_orm::{NotSet, Set};
 use serde::{Deserialize, Serialize};
 
 #[derive(
@@ -11,9 +14,18 @@ use serde::{Deserialize, Serialize};
 #[sea_orm(table_name = "employee_position", rename_all = "snake_case")]
 pub struct Model {
     #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(indexed)]
     pub employee_id: i64,
-    #[sea_orm(primary_key)]
     pub position_id: i64,
+    // First and last day of the assignment, an open assignment has no `effective_to`.
+    pub effective_from: NaiveDate,
+    #[sea_orm(nullable)]
+    pub effective_to: Option<NaiveDate>,
+    #[sea_orm(default_value = false)]
+    pub is_primary: bool,
+    #[sea_orm(nullable)]
+    pub reason: Option<String>,
     pub create_at: NaiveDateTime,
     pub update_at: NaiveDateTime,
 }
@@ -35,3 +47,68 @@ pub enum Relation {
     )]
     Position,
 }
+
+impl Related<crate::domain::position::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Position.def()
+    }
+}
+
+impl Entity {
+    // Assignments in effect on the given day.
+    pub fn active_on(date: NaiveDate) -> Condition {
+        Condition::all().add(Column::EffectiveFrom.lte(date)).add(
+            Condition::any().add(Column::EffectiveTo.is_null()).add(Column::EffectiveTo.gte(date)),
+        )
+    }
+}
+
+impl AssignmentEntity for Entity {
+    const TARGET: &'static str = "position";
+
+    fn id_column() -> Column {
+        Column::Id
+    }
+
+    fn employee_id_column() -> Column {
+        Column::EmployeeId
+    }
+
+    fn effective_to_column() -> Column {
+        Column::EffectiveTo
+    }
+
+    fn update_at_column() -> Column {
+        Column::UpdateAt
+    }
+
+    fn to_assignment(model: &Model) -> Assignment {
+        Assignment {
+            id: model.id,
+            target_id: model.position_id,
+            is_primary: model.is_primary,
+            effective_from: model.effective_from,
+            effective_to: model.effective_to,
+        }
+    }
+
+    fn new_link(
+        employee_id: i64,
+        target_id: i64,
+        is_primary: bool,
+        effective_from: NaiveDate,
+        reason: Option<String>,
+    ) -> ActiveModel {
+        ActiveModel {
+            id: NotSet,
+            employee_id: Set(employee_id),
+            position_id: Set(target_id),
+            effective_from: Set(effective_from),
+            effective_to: Set(None),
+            is_primary: Set(is_primary),
+            reason: Set(reason),
+            create_at: Set(Utc::now().naive_utc()),
+            update_at: Set(Utc::now().naive_utc()),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::core::error::{AppError, AppResult};
+use calamine::{Data, DataType, Reader, Xlsx};
+use std::io::Cursor;
+
+// One data row of an employee import file. `row` is the line number shown by spreadsheet
+// tools, the header being row 1.
+#[derive(Debug, Default, Clone)]
+pub struct EmployeeImportRow {
+    pub row: usize,
+    pub fullname: String,
+    pub email: String,
+    pub gender: Option<String>,
+    pub address: Option<String>,
+    pub role: Option<String>,
+    pub status: Option<String>,
+    pub language: Option<String>,
+    pub hire_date: Option<String>,
+    // Uuid or name of the organization, department and position.
+    pub organization: String,
+    pub department: String,
+    pub position: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EImportColumn {
+    Fullname,
+    Email,
+    Gender,
+    Address,
+    Role,
+    Status,
+    Language,
+    HireDate,
+    Organization,
+    Department,
+    Position,
+}
+
+const REQUIRED_COLUMNS: [(EImportColumn, &str); 5] = [
+    (EImportColumn::Fullname, "fullname"),
+    (EImportColumn::Email, "email"),
+    (EImportColumn::Organization, "organization"),
+    (EImportColumn::Department, "department"),
+    (EImportColumn::Position, "position"),
+];
+
+impl EImportColumn {
+    // Header names are compared lower cased, with spaces and dashes read as underscores.
+    fn from_header(header: &str) -> Option<Self> {
+        let header =
+            header.trim_start_matches('\u{feff}').trim().to_lowercase().replace([' ', '-'], "_");
+        match header.as_str() {
+            "fullname" | "full_name" | "name" => Some(Self::Fullname),
+            "email" => Some(Self::Email),
+            "gender" => Some(Self::Gender),
+            "address" => Some(Self::Address),
+            "role" => Some(Self::Role),
+            "status" => Some(Self::Status),
+            "language" => Some(Self::Language),
+            "hire_date" => Some(Self::HireDate),
+            "organization" | "organization_uuid" | "organization_name" => Some(Self::Organization),
+            "department" | "department_uuid" | "department_name" => Some(Self::Department),
+            "position" | "position_uuid" | "position_name" => Some(Self::Position),
+            _ => None,
+        }
+    }
+}
+
+// Reads the first sheet of a `.xlsx` file or a `.csv` file, picked by the file extension.
+pub fn parse_import_file(file_name: &str, content: &[u8]) -> AppResult<Vec<EmployeeImportRow>> {
+    let extension = file_name.rsplit_once('.').map(|(_, extension)| extension.to_lowercase());
+    let table = match extension.as_deref() {
+        Some("csv") => read_csv(content)?,
+        Some("xlsx") => read_xlsx(content)?,
+        _ => {
+            return Err(AppError::BadRequestError(format!(
+                "Unsupported file `{file_name}`, expected a .csv or .xlsx file"
+            )))
+        },
+    };
+    map_rows(table)
+}
+
+fn read_csv(content: &[u8]) -> AppResult<Vec<Vec<String>>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(content);
+    reader
+        .records()
+        .map(|record| {
+            record
+                .map(|record| record.iter().map(str::to_string).collect())
+                .map_err(|err| AppError::BadRequestError(format!("Can not read csv file: {err}")))
+        })
+        .collect()
+}
+
+fn read_xlsx(content: &[u8]) -> AppResult<Vec<Vec<String>>> {
+    let mut workbook: Xlsx<_> = Xlsx::new(Cursor::new(content))
+        .map_err(|err| AppError::BadRequestError(format!("Can not read xlsx file: {err}")))?;
+    let sheet = workbook
+        .worksheet_range_at(0)
+        .ok_or_else(|| AppError::BadRequestError("The xlsx file has no sheet".to_string()))?
+        .map_err(|err| AppError::BadRequestError(format!("Can not read xlsx file: {err}")))?;
+    // Date cells are written as `YYYY-MM-DD`, like a date typed in a csv file.
+    let text = |cell: &Data| match cell {
+        Data::DateTime(_) | Data::DateTimeIso(_) => {
+            cell.as_date().map(|date| date.to_string()).unwrap_or_default()
+        },
+        cell => cell.to_string().trim().to_string(),
+    };
+    Ok(sheet.rows().map(|row| row.iter().map(text).collect()).collect())
+}
+
+fn map_rows(table: Vec<Vec<String>>) -> AppResult<Vec<EmployeeImportRow>> {
+    let mut lines = table.into_iter();
+    let columns = lines
+        .next()
+        .ok_or_else(|| AppError::BadRequestError("The file has no header row".to_string()))?
+        .iter()
+        .map(|header| EImportColumn::from_header(header))
+        .collect::<Vec<_>>();
+    if let Some((_, name)) =
+        REQUIRED_COLUMNS.iter().find(|(column, _)| !columns.contains(&Some(*column)))
+    {
+        return Err(AppError::BadRequestError(format!("Missing column `{name}`")));
+    }
+
+    let mut rows = Vec::new();
+    for (index, line) in lines.enumerate() {
+        if line.iter().all(|cell| cell.is_empty()) {
+            continue;
+        }
+        let mut row = EmployeeImportRow { row: index + 2, ..Default::default() };
+        for (column, cell) in columns.iter().zip(line) {
+            let optional = Some(cell.clone()).filter(|cell| !cell.is_empty());
+            match column {
+                Some(EImportColumn::Fullname) => row.fullname = cell,
+                Some(EImportColumn::Email) => row.email = cell,
+                Some(EImportColumn::Gender) => row.gender = optional,
+                Some(EImportColumn::Address) => row.address = optional,
+                Some(EImportColumn::Role) => row.role = optional,
+                Some(EImportColumn::Status) => row.status = optional,
+                Some(EImportColumn::Language) => row.language = optional,
+                Some(EImportColumn::HireDate) => row.hire_date = optional,
+                Some(EImportColumn::Organization) => row.organization = cell,
+                Some(EImportColumn::Department) => row.department = cell,
+                Some(EImportColumn::Position) => row.position = cell,
+                None => {},
+            }
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
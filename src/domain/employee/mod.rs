@@ -1,11 +1,12 @@
 use crate::domain::entity::{EmployeeEntity, PositionEntity, UserEntity};
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use sea_orm::entity::prelude::*;
 use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
 use serde::{Deserialize, Serialize};
 
 pub mod employee_department;
 pub mod employee_position;
+pub mod import;
 pub mod request;
 pub mod response;
 pub mod services;
@@ -19,9 +20,14 @@ pub struct Model {
     pub id: i64,
     pub employee_uuid: Uuid,
     pub user_id: i64,
+    // Employee this one reports to, the top of the chain of command has none.
+    #[sea_orm(nullable)]
+    pub manager_employee_id: Option<i64>,
     pub image_url: String,
     #[sea_orm(nullable, default_value = 1)]
     pub status: Option<i16>,
+    // First working day, leave accrual, probation and attendance count from it.
+    pub hire_date: NaiveDate,
     pub create_at: NaiveDateTime,
     pub update_at: NaiveDateTime,
 }
@@ -37,6 +43,13 @@ pub enum Relation {
         to = "super::user::Column::Id"
     )]
     User,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ManagerEmployeeId",
+        to = "Column::Id",
+        on_delete = "SetNull"
+    )]
+    Manager,
 }
 
 impl Related<super::department::Entity> for EmployeeEntity {
@@ -64,3 +77,34 @@ impl Related<UserEntity> for EmployeeEntity {
         Relation::User.def()
     }
 }
+
+// Effective dated link of an employee to a department or a position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub id: i64,
+    // Id of the department or position.
+    pub target_id: i64,
+    pub is_primary: bool,
+    pub effective_from: NaiveDate,
+    pub effective_to: Option<NaiveDate>,
+}
+
+// Department and position links share the same history, so they are planned and written by the
+// same code.
+pub trait AssignmentEntity: EntityTrait {
+    // Name of the assigned entity, used in error messages.
+    const TARGET: &'static str;
+
+    fn id_column() -> Self::Column;
+    fn employee_id_column() -> Self::Column;
+    fn effective_to_column() -> Self::Column;
+    fn update_at_column() -> Self::Column;
+    fn to_assignment(model: &Self::Model) -> Assignment;
+    fn new_link(
+        employee_id: i64,
+        target_id: i64,
+        is_primary: bool,
+        effective_from: NaiveDate,
+        reason: Option<String>,
+    ) -> Self::ActiveModel;
+}
@@ -1,5 +1,7 @@
 use crate::domain::user::EGenderUser;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
 use uuid::Uuid;
 use validator::Validate;
 
@@ -9,6 +11,8 @@ pub struct CreateNewEmployeeByUserUuidRequest {
     pub organization_uuid: Uuid,
     pub department_uuid: Uuid,
     pub position_uuid: Uuid,
+    // First working day, today by default.
+    pub hire_date: Option<NaiveDate>,
 }
 
 impl CreateNewEmployeeByUserUuidRequest {
@@ -44,6 +48,8 @@ pub struct CreateNewEmployeeRequest {
     pub position_uuid: Uuid,
     pub department_uuid: Uuid,
     pub organization_uuid: Uuid,
+    // First working day, today by default.
+    pub hire_date: Option<NaiveDate>,
 }
 
 impl CreateNewEmployeeRequest {
@@ -83,6 +89,14 @@ pub struct UpdateEmployeeRequest {
     pub language: Option<String>,
     pub position_uuid: Option<Vec<Uuid>>,
     pub department_uuid: Option<Vec<Uuid>>,
+    // Primary assignment among the given uuids, the first one by default.
+    pub primary_position_uuid: Option<Uuid>,
+    pub primary_department_uuid: Option<Uuid>,
+    // First day of the new assignments, today by default.
+    pub effective_from: Option<NaiveDate>,
+    pub hire_date: Option<NaiveDate>,
+    #[validate(length(max = 255))]
+    pub reason: Option<String>,
 }
 
 impl UpdateEmployeeRequest {
@@ -113,6 +127,28 @@ impl UpdateEmployeeRequest {
     pub fn get_position_uuid(&self) -> Option<Vec<Uuid>> {
         self.position_uuid.clone()
     }
+    pub fn get_primary_department_uuid(&self) -> Option<Uuid> {
+        self.primary_department_uuid
+    }
+    pub fn get_primary_position_uuid(&self) -> Option<Uuid> {
+        self.primary_position_uuid
+    }
+    pub fn get_effective_from(&self) -> Option<NaiveDate> {
+        self.effective_from
+    }
+    pub fn get_hire_date(&self) -> Option<NaiveDate> {
+        self.hire_date
+    }
+    pub fn get_reason(&self) -> &Option<String> {
+        &self.reason
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Validate, utoipa::ToSchema)]
+pub struct AssignManagerRequest {
+    pub employee_uuid: Uuid,
+    // New manager of the employee, the employee reports to nobody when it is missing.
+    pub manager_employee_uuid: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Validate, utoipa::ToSchema)]
@@ -125,3 +161,31 @@ impl DeleteEmployeeRequest {
         self.employee_uuid
     }
 }
+
+#[derive(Debug, Serialize, Deserialize, Default, IntoParams, utoipa::ToSchema)]
+pub struct EmployeeQueryParam {
+    pub department_uuid: Option<Uuid>,
+    pub position_uuid: Option<Uuid>,
+    pub status: Option<i16>,
+    // Part of the full name of the employee, case insensitive.
+    pub name: Option<String>,
+    // Day used to resolve the departments and positions, today by default.
+    pub as_of: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, IntoParams, utoipa::ToSchema)]
+pub struct EmployeeImportQueryParam {
+    // Only check the rows and report their errors, nothing is written.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+// Multipart form of the import endpoint, `file` is a .csv or .xlsx file whose first row
+// names the columns: fullname, email, gender, address, role, status, language, hire_date,
+// organization, department and position. The last three take a uuid or a name, role takes a
+// role name and is `employee` when empty.
+#[derive(Debug, utoipa::ToSchema)]
+pub struct EmployeeImportFileRequest {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
@@ -0,0 +1,262 @@
+use crate::domain::model::{
+    DepartmentModel, EmployeeDepartmentModel, EmployeeModel, EmployeePositionModel,
+    OrganizationModel, PositionModel, UserModel,
+};
+use crate::domain::organization::response::OrganizationResponse;
+use crate::domain::user::response::PublicProfileResponse;
+use crate::domain::user::EGenderUser;
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeUserResponse {
+    pub user_uuid: Uuid,
+    pub email: String,
+    pub full_name: String,
+    pub gender: Option<EGenderUser>,
+    pub phone_number: Option<String>,
+    pub picture: Option<String>,
+}
+
+impl From<UserModel> for EmployeeUserResponse {
+    fn from(user: UserModel) -> Self {
+        EmployeeUserResponse {
+            user_uuid: user.user_uuid,
+            email: user.email,
+            full_name: user.full_name,
+            gender: user.gender,
+            phone_number: user.phone_number,
+            picture: user.picture,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeDepartmentResponse {
+    pub department_uuid: Uuid,
+    pub name: String,
+}
+
+impl From<DepartmentModel> for EmployeeDepartmentResponse {
+    fn from(department: DepartmentModel) -> Self {
+        EmployeeDepartmentResponse {
+            department_uuid: department.department_uuid,
+            name: department.name,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeePositionResponse {
+    pub position_uuid: Uuid,
+    pub name: String,
+}
+
+impl From<PositionModel> for EmployeePositionResponse {
+    fn from(position: PositionModel) -> Self {
+        EmployeePositionResponse { position_uuid: position.position_uuid, name: position.name }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeResponse {
+    pub employee_uuid: Uuid,
+    pub image_url: String,
+    pub status: Option<i16>,
+    pub manager_employee_uuid: Option<Uuid>,
+    pub user: Option<EmployeeUserResponse>,
+    pub departments: Vec<EmployeeDepartmentResponse>,
+    pub positions: Vec<EmployeePositionResponse>,
+    pub hire_date: NaiveDate,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl EmployeeResponse {
+    pub fn new(
+        employee: EmployeeModel,
+        manager_employee_uuid: Option<Uuid>,
+        user: Option<UserModel>,
+        departments: Vec<DepartmentModel>,
+        positions: Vec<PositionModel>,
+    ) -> Self {
+        EmployeeResponse {
+            employee_uuid: employee.employee_uuid,
+            image_url: employee.image_url,
+            status: employee.status,
+            manager_employee_uuid,
+            user: user.map(EmployeeUserResponse::from),
+            departments: departments.into_iter().map(EmployeeDepartmentResponse::from).collect(),
+            positions: positions.into_iter().map(EmployeePositionResponse::from).collect(),
+            hire_date: employee.hire_date,
+            create_at: employee.create_at,
+            update_at: employee.update_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeDetailResponse {
+    pub employee_uuid: Uuid,
+    pub image_url: String,
+    pub status: Option<i16>,
+    pub manager_employee_uuid: Option<Uuid>,
+    pub profile: PublicProfileResponse,
+    pub organization: Option<OrganizationResponse>,
+    pub departments: Vec<EmployeeDepartmentResponse>,
+    pub positions: Vec<EmployeePositionResponse>,
+    pub hire_date: NaiveDate,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl EmployeeDetailResponse {
+    pub fn new(
+        employee: EmployeeModel,
+        manager_employee_uuid: Option<Uuid>,
+        user: UserModel,
+        organization: Option<OrganizationModel>,
+        departments: Vec<DepartmentModel>,
+        positions: Vec<PositionModel>,
+    ) -> Self {
+        EmployeeDetailResponse {
+            employee_uuid: employee.employee_uuid,
+            image_url: employee.image_url,
+            status: employee.status,
+            manager_employee_uuid,
+            profile: user.into(),
+            organization: organization.map(OrganizationResponse::from),
+            departments: departments.into_iter().map(EmployeeDepartmentResponse::from).collect(),
+            positions: positions.into_iter().map(EmployeePositionResponse::from).collect(),
+            hire_date: employee.hire_date,
+            create_at: employee.create_at,
+            update_at: employee.update_at,
+        }
+    }
+}
+
+// One line of an employee export, departments and positions are joined by commas.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeExportRow {
+    pub employee_uuid: Uuid,
+    pub full_name: Option<String>,
+    pub email: Option<String>,
+    pub phone_number: Option<String>,
+    pub status: Option<i16>,
+    pub manager_employee_uuid: Option<Uuid>,
+    pub departments: String,
+    pub positions: String,
+    pub hire_date: NaiveDate,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl EmployeeExportRow {
+    // Fields that can be picked by the `columns` of an employee export.
+    pub const EXPORT_COLUMNS: [&'static str; 11] = [
+        "employee_uuid",
+        "full_name",
+        "email",
+        "phone_number",
+        "status",
+        "manager_employee_uuid",
+        "departments",
+        "positions",
+        "hire_date",
+        "create_at",
+        "update_at",
+    ];
+}
+
+impl From<EmployeeResponse> for EmployeeExportRow {
+    fn from(employee: EmployeeResponse) -> Self {
+        let departments = employee.departments.into_iter().map(|department| department.name);
+        let positions = employee.positions.into_iter().map(|position| position.name);
+        EmployeeExportRow {
+            employee_uuid: employee.employee_uuid,
+            full_name: employee.user.as_ref().map(|user| user.full_name.clone()),
+            email: employee.user.as_ref().map(|user| user.email.clone()),
+            phone_number: employee.user.and_then(|user| user.phone_number),
+            status: employee.status,
+            manager_employee_uuid: employee.manager_employee_uuid,
+            departments: departments.collect::<Vec<_>>().join(", "),
+            positions: positions.collect::<Vec<_>>().join(", "),
+            hire_date: employee.hire_date,
+            create_at: employee.create_at,
+            update_at: employee.update_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display, EnumString, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EAssignmentType {
+    Department,
+    Position,
+}
+
+// One department or position assignment of an employee, `effective_to` is the last day.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeAssignmentResponse {
+    pub assignment_type: EAssignmentType,
+    pub uuid: Uuid,
+    pub name: String,
+    pub effective_from: NaiveDate,
+    pub effective_to: Option<NaiveDate>,
+    pub is_primary: bool,
+    pub reason: Option<String>,
+}
+
+impl EmployeeAssignmentResponse {
+    pub fn from_department(link: EmployeeDepartmentModel, department: DepartmentModel) -> Self {
+        EmployeeAssignmentResponse {
+            assignment_type: EAssignmentType::Department,
+            uuid: department.department_uuid,
+            name: department.name,
+            effective_from: link.effective_from,
+            effective_to: link.effective_to,
+            is_primary: link.is_primary,
+            reason: link.reason,
+        }
+    }
+
+    pub fn from_position(link: EmployeePositionModel, position: PositionModel) -> Self {
+        EmployeeAssignmentResponse {
+            assignment_type: EAssignmentType::Position,
+            uuid: position.position_uuid,
+            name: position.name,
+            effective_from: link.effective_from,
+            effective_to: link.effective_to,
+            is_primary: link.is_primary,
+            reason: link.reason,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SpanOfControlResponse {
+    pub employee_uuid: Uuid,
+    // Active employees reporting directly to the employee.
+    pub direct_reports: u64,
+    // Active employees below the employee at any level, direct reports included.
+    pub total_reports: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeImportRowError {
+    pub row: u64,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmployeeImportResponse {
+    pub dry_run: bool,
+    pub total_rows: u64,
+    // Employees created, nothing is created when a row has errors.
+    pub imported: u64,
+    pub errors: Vec<EmployeeImportRowError>,
+}
@@ -1,36 +1,58 @@
 use crate::core::app_state::AppState;
 use crate::core::error::{AppError, AppResult};
+use crate::domain::audit_log::services::record_audit_log;
+use crate::domain::audit_log::{AuditContext, EAuditAction, EAuditEntity};
+use crate::domain::authenticate::services::service_send_invitation;
 use crate::domain::department::Column;
-use crate::domain::employee::employee_department::ActiveModel;
-use crate::domain::employee::employee_position::ActiveModel as ActiveModelPosition;
+use crate::domain::employee::import::{parse_import_file, EmployeeImportRow};
 use crate::domain::employee::request::{
-    CreateNewEmployeeByUserUuidRequest, CreateNewEmployeeRequest, DeleteEmployeeRequest,
-    UpdateEmployeeRequest,
+    AssignManagerRequest, CreateNewEmployeeByUserUuidRequest, CreateNewEmployeeRequest,
+    DeleteEmployeeRequest, EmployeeQueryParam, UpdateEmployeeRequest,
+};
+use crate::domain::employee::response::{
+    EmployeeAssignmentResponse, EmployeeDetailResponse, EmployeeExportRow, EmployeeImportResponse,
+    EmployeeImportRowError, EmployeeResponse, SpanOfControlResponse,
+};
+use crate::domain::employee::{
+    employee_department, employee_position, Assignment, AssignmentEntity,
 };
-use crate::domain::employee::{employee_department, employee_position};
 use crate::domain::entity::{
     DepartmentEntity, EmployeeDepartmentEntity, EmployeeEntity, EmployeePositionEntity,
-    OrganizationEntity, PositionEntity, UserEntity,
+    OrganizationEntity, PositionEntity, RoleEntity, UserEntity,
 };
-use crate::domain::model::{EmployeeDepartmentModel, EmployeePositionModel};
 use crate::domain::organization::Entity;
+use crate::domain::model::{EmployeeModel, UserModel};
+use crate::domain::user::services::find_creator_id;
+use crate::domain::user::EGenderUser;
 use crate::domain::{department, employee, organization, position, user};
 use crate::infrastructure::persistence::repo_interface::{
     DeleteRepository, ReadRepository, WriteRepository,
 };
+use crate::util::constant::{DEFAULT_LANGUAGE, EMPLOYEE_ROLE};
+use crate::util::export::{fetch_in_batches, pick_columns, ExportQueryParam, ExportRows};
+use crate::util::filter_and_pagination::{EModule, PageQueryParam, Paginated};
+use crate::util::password;
+use crate::util::random::generate_random_string;
 use axum::extract::State;
-use chrono::{NaiveDateTime, Utc};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use futures::{StreamExt, TryStreamExt};
 use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::Expr;
+use serde::Serialize;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait,
-    IntoActiveModel, NotSet, QueryFilter, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbErr,
+    EntityTrait, IntoActiveModel, NotSet, QueryFilter, TransactionTrait,
 };
 use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt::Debug;
 use tracing::info;
 use uuid::Uuid;
+use validator::Validate;
 
 pub async fn service_create_new_employee_by_user_exist(
     state: &AppState,
+    context: &AuditContext,
     req: &CreateNewEmployeeByUserUuidRequest,
 ) -> AppResult<i64> {
     info!("Register a new user request: {req:?}.");
@@ -39,12 +61,20 @@ pub async fn service_create_new_employee_by_user_exist(
     if organization.is_none() {
         return Err(AppError::EntityNotFoundError { detail: req.organization_uuid.to_string() });
     };
-    let new_employee_id = insert_new_employee(&tx, req).await;
-    if new_employee_id.is_err() {
-        return Err(new_employee_id.unwrap_err());
-    }
+    let new_employee_id = insert_new_employee(&tx, req).await?;
+    let after = employee_snapshot(&tx, new_employee_id).await?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::Employee,
+        after.employee.employee_uuid,
+        None,
+        Some(&after),
+    )
+    .await?;
     tx.commit().await?;
-    new_employee_id
+    Ok(new_employee_id)
 }
 
 pub async fn insert_new_employee(
@@ -64,8 +94,14 @@ pub async fn insert_new_employee(
         id: NotSet,
         employee_uuid: Set(Uuid::new_v4()),
         user_id: Set(user.unwrap().id),
+        manager_employee_id: Set(None),
         image_url: Set("image.jpg".to_string()),
         status: Default::default(),
+        hire_date: Set(
+            create_new_employee_by_user_uuid_request
+                .hire_date
+                .unwrap_or_else(|| Utc::now().date_naive()),
+        ),
         create_at: Set(Utc::now().naive_utc()),
         update_at: Set(Utc::now().naive_utc()),
     };
@@ -84,18 +120,29 @@ pub async fn insert_new_employee(
             detail: create_new_employee_by_user_uuid_request.department_uuid.to_string(),
         });
     };
+    if department.as_ref().is_some_and(|department| department.status != Some(1)) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!(
+                "Department is not active {}",
+                create_new_employee_by_user_uuid_request.department_uuid
+            ),
+        });
+    }
     let employee_insert_id = employee_insert.unwrap().last_insert_id;
-    let employee_department_model = employee_department::Model {
-        employee_id: employee_insert_id,
-        department_id: department.unwrap().id,
-        create_at: Utc::now().naive_utc(),
-        update_at: Utc::now().naive_utc(),
+    let employee_department_model = employee_department::ActiveModel {
+        id: NotSet,
+        employee_id: Set(employee_insert_id),
+        department_id: Set(department.unwrap().id),
+        effective_from: Set(Utc::now().date_naive()),
+        effective_to: Set(None),
+        is_primary: Set(true),
+        reason: Set(None),
+        create_at: Set(Utc::now().naive_utc()),
+        update_at: Set(Utc::now().naive_utc()),
     };
     let employee_department_insert =
-        EmployeeDepartmentEntity::insert(employee_department_model.into_active_model())
-            .exec(tx)
-            .await?;
-    if employee_department_insert.last_insert_id.0 < 0 {
+        EmployeeDepartmentEntity::insert(employee_department_model).exec(tx).await?;
+    if employee_department_insert.last_insert_id < 0 {
         return Err(AppError::EntityNotAvailableError {
             detail: "employee_department_insert".to_string(),
         });
@@ -111,15 +158,28 @@ pub async fn insert_new_employee(
             detail: create_new_employee_by_user_uuid_request.position_uuid.to_string(),
         });
     };
-    let employee_position_model = employee_position::Model {
-        employee_id: employee_insert_id,
-        position_id: position.unwrap().id,
-        create_at: Utc::now().naive_utc(),
-        update_at: Utc::now().naive_utc(),
+    if position.as_ref().is_some_and(|position| position.status != Some(1)) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!(
+                "Position is not active {}",
+                create_new_employee_by_user_uuid_request.position_uuid
+            ),
+        });
+    }
+    let employee_position_model = employee_position::ActiveModel {
+        id: NotSet,
+        employee_id: Set(employee_insert_id),
+        position_id: Set(position.unwrap().id),
+        effective_from: Set(Utc::now().date_naive()),
+        effective_to: Set(None),
+        is_primary: Set(true),
+        reason: Set(None),
+        create_at: Set(Utc::now().naive_utc()),
+        update_at: Set(Utc::now().naive_utc()),
     };
 
     let employee_position_insert =
-        EmployeePositionEntity::insert(employee_position_model.into_active_model()).exec(tx).await;
+        EmployeePositionEntity::insert(employee_position_model).exec(tx).await;
     if employee_position_insert.is_err() {
         return Err(AppError::EntityNotAvailableError {
             detail: "employee_position_insert".to_string(),
@@ -131,61 +191,279 @@ pub async fn insert_new_employee(
 
 pub async fn service_create_new_employee(
     state: &AppState,
+    context: &AuditContext,
     req: &CreateNewEmployeeRequest,
 ) -> AppResult<i64> {
     let tx = state.db.begin().await?;
-    let organization = OrganizationEntity::find_data_by_uuid(&tx, &req.organization_uuid).await;
+    let (employee_insert_id, user) = create_new_employee(&tx, context, req).await?;
+    tx.commit().await?;
+    send_invitation(state, &user).await;
+    Ok(employee_insert_id)
+}
+
+// The employee is already saved, so a failed email is only logged, the user can still ask for
+// a forget password code.
+async fn send_invitation(state: &AppState, user: &UserModel) {
+    if user.status != 1 {
+        return;
+    }
+    if let Err(err) = service_send_invitation(state, user).await {
+        tracing::error!("Can not send invitation to user {}: {err:?}.", user.user_uuid);
+    }
+}
+
+// Creates the user and the employee of the request inside the caller's transaction.
+async fn create_new_employee(
+    tx: &DatabaseTransaction,
+    context: &AuditContext,
+    req: &CreateNewEmployeeRequest,
+) -> AppResult<(i64, UserModel)> {
+    let organization = OrganizationEntity::find_data_by_uuid(tx, &req.organization_uuid).await;
     if organization.is_none() {
         return Err(AppError::EntityNotFoundError { detail: req.organization_uuid.to_string() });
     };
 
+    let role_name = req.get_role().as_deref().unwrap_or(EMPLOYEE_ROLE);
+    let role = RoleEntity::find_data_by_name(tx, role_name).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: format!("Role not found by name {role_name}") }
+    })?;
+
     let mut user_model = user::Model {
         id: Default::default(),
-        user_uuid: Default::default(),
-        creator_id: 1,
-        role_id: 0,
+        user_uuid: Uuid::new_v4(),
+        creator_id: find_creator_id(tx, context).await?,
+        role_id: role.id,
         full_name: req.get_fullname().to_string(),
-        password: "ABCDEFGH".to_string(),
+        // Nobody knows this password, the user sets its own with the invitation code.
+        password: password::hash(generate_random_string(32)).await?,
         email: req.get_email().to_string(),
         gender: *req.get_gender(),
         phone_number: None,
         address: req.get_address().to_owned(),
         picture: None,
-        language: None,
-        status: 1,
+        language: Some(req.get_language().clone().unwrap_or(DEFAULT_LANGUAGE.to_string())),
+        status: req.get_status_user().unwrap_or(1),
         last_login: None,
         created_at: Utc::now().naive_utc(),
         updated_at: Utc::now().naive_utc(),
 
         deleted_at: None,
     };
-    let user_insert_id = UserEntity::create(&tx, &user_model).await;
+    let user_insert_id = UserEntity::create(tx, &user_model).await;
     if user_insert_id.is_none() {
         return Err(AppError::EntityNotAvailableError { detail: "User".to_string() });
     }
 
-    let user = UserEntity::find_data_by_id(&tx, user_insert_id.unwrap()).await;
+    let user = UserEntity::find_data_by_id(tx, user_insert_id.unwrap()).await;
     if user.is_none() {
         return Err(AppError::EntityNotFoundError { detail: "fail".to_string() });
     }
+    let user = user.unwrap();
+    record_audit_log(
+        tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::User,
+        user_model.user_uuid,
+        None,
+        Some(&user),
+    )
+    .await?;
 
     let create_new_employee_by_user_exist = CreateNewEmployeeByUserUuidRequest {
-        user_uuid: user.unwrap().user_uuid,
+        user_uuid: user.user_uuid,
         organization_uuid: req.organization_uuid,
         department_uuid: req.department_uuid,
         position_uuid: req.position_uuid,
+        hire_date: req.hire_date,
     };
-    let employee_insert_id = insert_new_employee(&tx, &create_new_employee_by_user_exist).await;
-    if employee_insert_id.is_err() {
-        return Err(employee_insert_id.unwrap_err());
-    };
+    let employee_insert_id = insert_new_employee(tx, &create_new_employee_by_user_exist).await?;
+    let after = employee_snapshot(tx, employee_insert_id).await?;
+    record_audit_log(
+        tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::Employee,
+        after.employee.employee_uuid,
+        None,
+        Some(&after),
+    )
+    .await?;
+    Ok((employee_insert_id, user))
+}
+
+pub async fn service_import_employees(
+    state: &AppState,
+    context: &AuditContext,
+    file_name: &str,
+    content: &[u8],
+    dry_run: bool,
+) -> AppResult<EmployeeImportResponse> {
+    info!("Import employees from file {file_name}, dry run: {dry_run}.");
+    let rows = parse_import_file(file_name, content)?;
+    if rows.is_empty() {
+        return Err(AppError::BadRequestError("The file has no employee rows".to_string()));
+    }
 
+    let tx = state.db.begin().await?;
+    let mut requests = Vec::new();
+    let mut errors = Vec::new();
+    let mut emails = HashSet::new();
+    for row in &rows {
+        let mut row_errors = Vec::new();
+        if !emails.insert(row.email.to_lowercase()) {
+            row_errors.push(format!("Email {} is repeated in the file", row.email));
+        } else if UserEntity::repo_check_is_exists_by_email(&tx, &row.email).await == Some(true) {
+            row_errors.push(format!("Email {} is already used", row.email));
+        }
+        match resolve_import_row(&tx, row).await {
+            Ok(req) => match req.validate() {
+                Ok(()) if row_errors.is_empty() => requests.push((row.row, req)),
+                Ok(()) => {},
+                Err(err) => row_errors.push(err.to_string()),
+            },
+            Err(err) => row_errors.extend(err),
+        }
+        if !row_errors.is_empty() {
+            errors.push(EmployeeImportRowError { row: row.row as u64, errors: row_errors });
+        }
+    }
+
+    let mut response = EmployeeImportResponse {
+        dry_run,
+        total_rows: rows.len() as u64,
+        imported: 0,
+        errors,
+    };
+    if dry_run || !response.errors.is_empty() {
+        return Ok(response);
+    }
+    let mut users = Vec::new();
+    for (row, req) in &requests {
+        match create_new_employee(&tx, context, req).await {
+            Ok((_, user)) => users.push(user),
+            Err(err) => {
+                tracing::warn!("Can not import employee of row {row}: {err:?}.");
+                return Err(err);
+            },
+        }
+    }
     tx.commit().await?;
-    employee_insert_id
+    for user in &users {
+        send_invitation(state, user).await;
+    }
+    response.imported = requests.len() as u64;
+    Ok(response)
+}
+
+// Turns an import row into a create request, organization, department and position are
+// looked up by uuid first and by name otherwise.
+async fn resolve_import_row(
+    tx: &DatabaseTransaction,
+    row: &EmployeeImportRow,
+) -> Result<CreateNewEmployeeRequest, Vec<String>> {
+    let mut errors = Vec::new();
+    let organization = match Uuid::parse_str(&row.organization) {
+        Ok(uuid) => OrganizationEntity::find_data_by_uuid(tx, &uuid).await,
+        Err(_) => OrganizationEntity::find_data_by_name(tx, &row.organization).await,
+    };
+    let Some(organization) = organization else {
+        return Err(vec![format!("Organization `{}` not found", row.organization)]);
+    };
+    let department = match Uuid::parse_str(&row.department) {
+        Ok(uuid) => DepartmentEntity::find_data_by_uuid(tx, &uuid)
+            .await
+            .filter(|department| department.organization_id == organization.id),
+        Err(_) => {
+            DepartmentEntity::find_by_organization_and_name(tx, organization.id, &row.department)
+                .await
+        },
+    };
+    let Some(department) = department else {
+        return Err(vec![format!(
+            "Department `{}` not found in organization {}",
+            row.department, organization.name
+        )]);
+    };
+    if department.status != Some(1) {
+        return Err(vec![format!("Department `{}` is not active", row.department)]);
+    }
+    let position = match Uuid::parse_str(&row.position) {
+        Ok(uuid) => PositionEntity::find_data_by_uuid(tx, &uuid)
+            .await
+            .filter(|position| position.department_id == department.id),
+        Err(_) => {
+            PositionEntity::find_by_department_and_name(tx, department.id, &row.position).await
+        },
+    };
+    match &position {
+        None => errors.push(format!(
+            "Position `{}` not found in department {}",
+            row.position, department.name
+        )),
+        Some(position) if position.status != Some(1) => {
+            errors.push(format!("Position `{}` is not active", row.position));
+        },
+        Some(_) => {},
+    }
+    let gender = match &row.gender {
+        Some(gender) => match gender.to_lowercase().as_str() {
+            "male" => Some(EGenderUser::Male),
+            "female" => Some(EGenderUser::Female),
+            "other" => Some(EGenderUser::Other),
+            _ => {
+                errors.push(format!("Gender `{gender}` must be Male, Female or Other"));
+                None
+            },
+        },
+        None => None,
+    };
+    let status = match &row.status {
+        Some(status) => match status.parse::<i16>() {
+            Ok(status) => Some(status),
+            Err(_) => {
+                errors.push(format!("Status `{status}` must be a number"));
+                None
+            },
+        },
+        None => None,
+    };
+    if let Some(role) = &row.role {
+        if RoleEntity::find_data_by_name(tx, role).await.is_none() {
+            errors.push(format!("Role `{role}` not found"));
+        }
+    }
+    let hire_date = match &row.hire_date {
+        Some(hire_date) => match NaiveDate::parse_from_str(hire_date, "%Y-%m-%d") {
+            Ok(hire_date) => Some(hire_date),
+            Err(_) => {
+                errors.push(format!("Hire date `{hire_date}` must be a YYYY-MM-DD date"));
+                None
+            },
+        },
+        None => None,
+    };
+    match position {
+        Some(position) if errors.is_empty() => Ok(CreateNewEmployeeRequest {
+            fullname: row.fullname.clone(),
+            email: row.email.clone(),
+            gender,
+            address: row.address.clone(),
+            role: row.role.clone(),
+            status,
+            language: row.language.clone(),
+            position_uuid: position.position_uuid,
+            department_uuid: department.department_uuid,
+            organization_uuid: organization.organization_uuid,
+            hire_date,
+        }),
+        _ => Err(errors),
+    }
 }
 
 pub async fn service_update_employee(
     state: &AppState,
+    context: &AuditContext,
     req: &UpdateEmployeeRequest,
 ) -> AppResult<i64> {
     let tx = state.db.begin().await?;
@@ -195,101 +473,739 @@ pub async fn service_update_employee(
     }
 
     let mut employee_active = employee.unwrap();
-    let employee_department_delete = EmployeeDepartmentEntity::delete_many()
-        .filter(employee_department::Column::EmployeeId.eq(employee_active.id))
-        .exec(&tx)
-        .await;
-    if employee_department_delete.is_err() {
-        return Err(AppError::EntityNotAvailableError { detail: "Employee".to_string() });
-    }
-
-    let employee_position_delete = EmployeePositionEntity::delete_many()
-        .filter(employee_position::Column::EmployeeId.eq(employee_active.id))
-        .exec(&tx)
-        .await;
-    if employee_position_delete.is_err() {
-        return Err(AppError::EntityNotAvailableError { detail: "Employee".to_string() });
-    }
+    let before = employee_snapshot(&tx, employee_active.id).await?;
+    let effective_from = req.get_effective_from().unwrap_or_else(|| Utc::now().date_naive());
 
-    if req.get_department_uuid().is_some() {
+    if let Some(department_uuids) = req.get_department_uuid() {
         let departments = DepartmentEntity::find()
-            .filter(department::Column::DepartmentUuid.is_in(req.get_department_uuid().unwrap()))
+            .filter(department::Column::DepartmentUuid.is_in(department_uuids.clone()))
             .all(&tx)
-            .await;
-        if departments.is_err() {
-            return Err(AppError::EntityNotAvailableError { detail: "Employee".to_string() });
-        }
-        let employee_department_insert = EmployeeDepartmentEntity::insert_many(
-            departments?
-                .iter()
-                .map(|employee_department_id| {
-                    EmployeeDepartmentModel {
-                        employee_id: employee_active.id,
-                        department_id: employee_department_id.id,
-                        create_at: Utc::now().naive_utc(),
-                        update_at: Utc::now().naive_utc(),
-                    }
-                    .into_active_model()
-                })
-                .collect::<Vec<ActiveModel>>(),
+            .await?
+            .into_iter()
+            .map(|department| (department.department_uuid, department.id))
+            .collect::<Vec<_>>();
+        let primary_uuid = req.get_primary_department_uuid().or(department_uuids.first().copied());
+        reassign::<EmployeeDepartmentEntity>(
+            &tx,
+            employee_active.id,
+            &department_uuids,
+            &departments,
+            primary_uuid,
+            effective_from,
+            req.get_reason(),
         )
-        .exec(&tx)
-        .await;
-        if employee_department_insert.is_err() {
-            return Err(AppError::InvalidPayloadError { 0: "".to_string() });
-        }
+        .await?;
     }
 
-    if req.get_position_uuid().is_some() {
+    if let Some(position_uuids) = req.get_position_uuid() {
         let positions = PositionEntity::find()
-            .filter(position::Column::PositionUuid.is_in(req.get_position_uuid().unwrap()))
+            .filter(position::Column::PositionUuid.is_in(position_uuids.clone()))
             .all(&tx)
-            .await;
-        if positions.is_err() {
-            return Err(AppError::EntityNotAvailableError { detail: "Employee".to_string() });
-        }
-
-        let employee_position_insert = EmployeePositionEntity::insert_many(
-            positions?
-                .iter()
-                .map(|employee_position_id| {
-                    EmployeePositionModel {
-                        employee_id: employee_active.id,
-                        position_id: employee_position_id.id,
-                        create_at: Utc::now().naive_utc(),
-                        update_at: Utc::now().naive_utc(),
-                    }
-                    .into_active_model()
-                })
-                .collect::<Vec<ActiveModelPosition>>(),
+            .await?
+            .into_iter()
+            .map(|position| (position.position_uuid, position.id))
+            .collect::<Vec<_>>();
+        let primary_uuid = req.get_primary_position_uuid().or(position_uuids.first().copied());
+        reassign::<EmployeePositionEntity>(
+            &tx,
+            employee_active.id,
+            &position_uuids,
+            &positions,
+            primary_uuid,
+            effective_from,
+            req.get_reason(),
         )
-        .exec(&tx)
-        .await;
-        if employee_position_insert.is_err() {
-            return Err(AppError::InvalidPayloadError { 0: "".to_string() });
-        };
+        .await?;
     }
 
+    if let Some(hire_date) = req.get_hire_date() {
+        employee_active.hire_date = hire_date;
+    }
+    if let Some(active) = req.get_status_user() {
+        employee_active.status = Some(if active { 1 } else { 0 });
+        if !active && before.employee.status == Some(1) {
+            release_reports(&tx, context, &employee_active).await?;
+        }
+    }
     employee_active.update_at = Utc::now().naive_utc();
     let data = employee_active.into_active_model().save(&tx).await;
     if data.is_err() {
         return Err(AppError::EntityNotAvailableError { detail: "Employee".to_string() });
     }
+    let employee_id = data?.id.unwrap();
+    let after = employee_snapshot(&tx, employee_id).await?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::Employee,
+        req.get_employee_uuid(),
+        Some(&before),
+        Some(&after),
+    )
+    .await?;
 
     tx.commit().await?;
-    Ok(data?.id.unwrap())
+    Ok(employee_id)
 }
 
 pub async fn service_delete_employee(
     state: &AppState,
+    context: &AuditContext,
     req: &DeleteEmployeeRequest,
 ) -> AppResult<i64> {
     let tx = state.db.begin().await?;
+    let employee = EmployeeEntity::find_data_by_uuid(&tx, &req.get_employee_uuid()).await;
+    if employee.is_none() {
+        return Err(AppError::EntityNotFoundError {
+            detail: format!("Employee not found by uuid {}", req.get_employee_uuid()),
+        });
+    }
+    let before = employee_snapshot(&tx, employee.unwrap().id).await?;
     let employee_delete = EmployeeEntity::delete_data(&tx, req.get_employee_uuid()).await;
     if employee_delete.is_none() {
         return Err(AppError::BadRequestError("Can not delete employee".to_string()));
     }
+    let after = employee_snapshot(&tx, before.employee.id).await?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Delete,
+        EAuditEntity::Employee,
+        req.get_employee_uuid(),
+        Some(&before),
+        Some(&after),
+    )
+    .await?;
+
+    release_reports(&tx, context, &before.employee).await?;
 
     tx.commit().await?;
     Ok(employee_delete.unwrap())
 }
+
+pub async fn service_get_list_employee(
+    state: &AppState,
+    query: EmployeeQueryParam,
+    param: PageQueryParam,
+) -> AppResult<Paginated<EmployeeResponse>> {
+    info!("Get employee list with query: {query:?}, parameter: {param:?}");
+    let employees = EmployeeEntity::search(&*state.db, &query, param).await?;
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+    let items = build_employee_responses(&*state.db, employees.items, as_of).await?;
+    Ok(Paginated { items, pagination: employees.pagination })
+}
+
+pub async fn service_export_employees(
+    state: &AppState,
+    query: EmployeeQueryParam,
+    param: &ExportQueryParam,
+) -> AppResult<(Vec<&'static str>, ExportRows)> {
+    info!("Export employees with query: {query:?}, parameter: {param:?}");
+    let columns = param.select_columns(&EmployeeExportRow::EXPORT_COLUMNS)?;
+    let select = EmployeeEntity::select_for_export(&query, &param.page_query())?;
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+    let db = state.db.clone();
+    let picked = columns.clone();
+    let rows = fetch_in_batches(state.db.clone(), select, param.page_query(), EModule::Employee)
+        .and_then(move |employees| {
+            let db = db.clone();
+            async move { build_employee_responses(&*db, employees, as_of).await }
+        })
+        .map_ok(move |employees| {
+            employees
+                .into_iter()
+                .map(|employee| pick_columns(&EmployeeExportRow::from(employee), &picked))
+                .collect()
+        })
+        .boxed();
+    Ok((columns, rows))
+}
+
+// Loads the users, managers, departments and positions of the employees in one query each.
+async fn build_employee_responses<DB>(
+    conn: &DB,
+    employees: Vec<EmployeeModel>,
+    as_of: NaiveDate,
+) -> AppResult<Vec<EmployeeResponse>>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let employee_ids = employees.iter().map(|employee| employee.id).collect::<Vec<_>>();
+    let user_ids = employees.iter().map(|employee| employee.user_id).collect::<Vec<_>>();
+    let manager_ids =
+        employees.iter().filter_map(|employee| employee.manager_employee_id).collect::<Vec<_>>();
+
+    let users = UserEntity::repo_find_by_ids(conn, &user_ids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let managers = EmployeeEntity::find_by_ids(conn, &manager_ids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let departments = EmployeeEntity::find_departments(conn, &employee_ids, as_of)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let positions = EmployeeEntity::find_positions(conn, &employee_ids, as_of)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+
+    Ok(employees
+        .into_iter()
+        .map(|employee| {
+            let user = users.iter().find(|user| user.id == employee.user_id).cloned();
+            let manager_uuid = managers
+                .iter()
+                .find(|manager| Some(manager.id) == employee.manager_employee_id)
+                .map(|manager| manager.employee_uuid);
+            let employee_departments = departments
+                .iter()
+                .filter(|(employee_id, _)| *employee_id == employee.id)
+                .map(|(_, department)| department.clone())
+                .collect();
+            let employee_positions = positions
+                .iter()
+                .filter(|(employee_id, _)| *employee_id == employee.id)
+                .map(|(_, position)| position.clone())
+                .collect();
+            EmployeeResponse::new(
+                employee,
+                manager_uuid,
+                user,
+                employee_departments,
+                employee_positions,
+            )
+        })
+        .collect())
+}
+
+pub async fn service_get_employee(
+    state: &AppState,
+    employee_uuid: Uuid,
+) -> AppResult<EmployeeDetailResponse> {
+    info!("Get employee with uuid: {employee_uuid}");
+    let employee = EmployeeEntity::find_data_by_uuid(&*state.db, &employee_uuid).await.ok_or_else(
+        || AppError::EntityNotFoundError {
+            detail: format!("Employee not found by uuid {employee_uuid}"),
+        },
+    )?;
+    let user = UserEntity::find_data_by_id(&*state.db, employee.user_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError { detail: "User not found".to_string() })?;
+    let today = Utc::now().date_naive();
+    let departments = EmployeeEntity::find_departments(&*state.db, &[employee.id], today)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?
+        .into_iter()
+        .map(|(_, department)| department)
+        .collect::<Vec<_>>();
+    let positions = EmployeeEntity::find_positions(&*state.db, &[employee.id], today)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?
+        .into_iter()
+        .map(|(_, position)| position)
+        .collect::<Vec<_>>();
+
+    // Every department of an employee belongs to the same organization.
+    let organization = match departments.first() {
+        Some(department) => {
+            OrganizationEntity::find_data_by_id(&*state.db, department.organization_id).await
+        },
+        None => None,
+    };
+    let manager_uuid = match employee.manager_employee_id {
+        Some(manager_id) => EmployeeEntity::find_data_by_id(&*state.db, manager_id)
+            .await
+            .map(|manager| manager.employee_uuid),
+        None => None,
+    };
+    Ok(EmployeeDetailResponse::new(
+        employee,
+        manager_uuid,
+        user,
+        organization,
+        departments,
+        positions,
+    ))
+}
+
+pub async fn service_assign_manager(
+    state: &AppState,
+    context: &AuditContext,
+    req: &AssignManagerRequest,
+) -> AppResult<i64> {
+    info!("Assign manager request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let employee = find_employee_by_uuid(&tx, &req.employee_uuid).await?;
+    let manager_id = match req.manager_employee_uuid {
+        Some(manager_uuid) => {
+            let manager = find_employee_by_uuid(&tx, &manager_uuid).await?;
+            let chain = lock_chain_of_command(&tx, employee.id, manager.id).await?;
+            if chain[0].status != Some(1) {
+                return Err(AppError::BadRequestError(format!(
+                    "Employee {manager_uuid} is not active and can not be a manager"
+                )));
+            }
+            // The chain starts with the manager itself, so this also rejects self management.
+            if chain.iter().any(|above| above.id == employee.id) {
+                return Err(AppError::BadRequestError(
+                    "An employee can not report to itself or to one of its reports".to_string(),
+                ));
+            }
+            Some(manager.id)
+        },
+        None => None,
+    };
+    let employee_id = set_manager(&tx, context, employee, manager_id).await?;
+    tx.commit().await?;
+    Ok(employee_id)
+}
+
+pub async fn service_get_direct_reports(
+    state: &AppState,
+    employee_uuid: Uuid,
+) -> AppResult<Vec<EmployeeResponse>> {
+    info!("Get direct reports of employee with uuid: {employee_uuid}");
+    let employee = find_employee_by_uuid(&*state.db, &employee_uuid).await?;
+    let reports = EmployeeEntity::find_direct_reports(&*state.db, employee.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    build_employee_responses(&*state.db, reports, Utc::now().date_naive()).await
+}
+
+pub async fn service_get_chain_of_command(
+    state: &AppState,
+    employee_uuid: Uuid,
+) -> AppResult<Vec<EmployeeResponse>> {
+    info!("Get chain of command of employee with uuid: {employee_uuid}");
+    let employee = find_employee_by_uuid(&*state.db, &employee_uuid).await?;
+    let managers = EmployeeEntity::find_chain_of_command(&*state.db, employee.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    build_employee_responses(&*state.db, managers, Utc::now().date_naive()).await
+}
+
+pub async fn service_get_span_of_control(
+    state: &AppState,
+    employee_uuid: Uuid,
+) -> AppResult<SpanOfControlResponse> {
+    info!("Get span of control of employee with uuid: {employee_uuid}");
+    let employee = find_employee_by_uuid(&*state.db, &employee_uuid).await?;
+    let subtree = EmployeeEntity::find_reporting_subtree(&*state.db, employee.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let active = |report: &&EmployeeModel| report.id != employee.id && report.status == Some(1);
+    Ok(SpanOfControlResponse {
+        employee_uuid,
+        direct_reports: subtree
+            .iter()
+            .filter(active)
+            .filter(|report| report.manager_employee_id == Some(employee.id))
+            .count() as u64,
+        total_reports: subtree.iter().filter(active).count() as u64,
+    })
+}
+
+// Locks the employee, the new manager and every manager above it, and returns the manager
+// followed by its chain of command. The chain is read again until all of it is locked, so a
+// concurrent assignment can not close a cycle between the check and the update.
+async fn lock_chain_of_command(
+    tx: &DatabaseTransaction,
+    employee_id: i64,
+    manager_id: i64,
+) -> AppResult<Vec<EmployeeModel>> {
+    let mut locked = HashSet::from([employee_id, manager_id]);
+    let manager = EmployeeEntity::lock_by_ids(tx, &[employee_id, manager_id])
+        .await
+        .and_then(|rows| rows.into_iter().find(|row| row.id == manager_id))
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    loop {
+        let chain = EmployeeEntity::find_chain_of_command(tx, manager_id)
+            .await
+            .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+        let missing = chain
+            .iter()
+            .map(|above| above.id)
+            .filter(|id| !locked.contains(id))
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return Ok(std::iter::once(manager).chain(chain).collect());
+        }
+        EmployeeEntity::lock_by_ids(tx, &missing)
+            .await
+            .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+        locked.extend(missing);
+    }
+}
+
+// Moves every report of a deactivated employee up to the employee's own manager.
+async fn release_reports(
+    tx: &DatabaseTransaction,
+    context: &AuditContext,
+    employee: &EmployeeModel,
+) -> AppResult {
+    // Holding the employee row makes a concurrent manager assignment wait and see the new status.
+    EmployeeEntity::lock_by_ids(tx, &[employee.id])
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let reports = EmployeeEntity::find_all_direct_reports(tx, employee.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    for report in reports {
+        set_manager(tx, context, report, employee.manager_employee_id).await?;
+    }
+    Ok(())
+}
+
+// Changes the manager of the employee and records it in the audit log.
+async fn set_manager(
+    tx: &DatabaseTransaction,
+    context: &AuditContext,
+    employee: EmployeeModel,
+    manager_id: Option<i64>,
+) -> AppResult<i64> {
+    let before = employee_snapshot(tx, employee.id).await?;
+    let mut employee_active = employee.into_active_model();
+    employee_active.manager_employee_id = Set(manager_id);
+    employee_active.update_at = Set(Utc::now().naive_utc());
+    let employee = employee_active.update(tx).await?;
+    let after = employee_snapshot(tx, employee.id).await?;
+    record_audit_log(
+        tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::Employee,
+        employee.employee_uuid,
+        Some(&before),
+        Some(&after),
+    )
+    .await?;
+    Ok(employee.id)
+}
+
+pub async fn find_employee_by_uuid<DB>(
+    conn: &DB,
+    employee_uuid: &Uuid,
+) -> AppResult<EmployeeModel>
+where
+    DB: ConnectionTrait + Debug,
+{
+    EmployeeEntity::find_data_by_uuid(conn, employee_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError {
+            detail: format!("Employee not found by uuid {employee_uuid}"),
+        }
+    })
+}
+
+// Employee of a user, e.g. the authenticated one.
+pub async fn find_employee_by_user_uuid<DB>(conn: &DB, user_uuid: &Uuid) -> AppResult<EmployeeModel>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let user = UserEntity::find_data_by_uuid(conn, user_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: format!("User not found by id {user_uuid}") }
+    })?;
+    EmployeeEntity::find_by_user_id(conn, user.id).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: format!("User is not an employee {user_uuid}") }
+    })
+}
+
+// Organization of the primary department of the employee on the given day.
+pub async fn find_employee_organization_id<DB>(
+    conn: &DB,
+    employee: &EmployeeModel,
+    as_of: NaiveDate,
+) -> AppResult<i64>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let departments = EmployeeEntity::find_departments(conn, &[employee.id], as_of)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    departments.first().map(|(_, department)| department.organization_id).ok_or_else(|| {
+        AppError::EntityNotAvailableError {
+            detail: format!("Employee has no department on {as_of} {}", employee.employee_uuid),
+        }
+    })
+}
+
+// Whether `manager` is the manager of the employee or the head of one of its departments on the
+// given day. Nobody manages itself.
+pub async fn is_manager_or_department_head<DB>(
+    conn: &DB,
+    manager: &EmployeeModel,
+    employee: &EmployeeModel,
+    as_of: NaiveDate,
+) -> AppResult<bool>
+where
+    DB: ConnectionTrait + Debug,
+{
+    if manager.id == employee.id {
+        return Ok(false);
+    }
+    if employee.manager_employee_id == Some(manager.id) {
+        return Ok(true);
+    }
+    let departments = EmployeeEntity::find_departments(conn, &[employee.id], as_of)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    Ok(departments.iter().any(|(_, department)| department.head_employee_id == Some(manager.id)))
+}
+
+pub async fn service_get_employee_history(
+    state: &AppState,
+    employee_uuid: Uuid,
+) -> AppResult<Vec<EmployeeAssignmentResponse>> {
+    info!("Get assignment history of employee with uuid: {employee_uuid}");
+    let employee = EmployeeEntity::find_data_by_uuid(&*state.db, &employee_uuid).await.ok_or_else(
+        || AppError::EntityNotFoundError {
+            detail: format!("Employee not found by uuid {employee_uuid}"),
+        },
+    )?;
+    let departments = EmployeeEntity::find_department_history(&*state.db, employee.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let positions = EmployeeEntity::find_position_history(&*state.db, employee.id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+
+    let mut history = departments
+        .into_iter()
+        .map(|(link, department)| EmployeeAssignmentResponse::from_department(link, department))
+        .chain(
+            positions
+                .into_iter()
+                .map(|(link, position)| EmployeeAssignmentResponse::from_position(link, position)),
+        )
+        .collect::<Vec<_>>();
+    history.sort_by_key(|entry| std::cmp::Reverse(entry.effective_from));
+    Ok(history)
+}
+
+// Replaces the departments or positions of the employee from `effective_from` on. `found` holds
+// the (uuid, id) pairs of the requested uuids that exist.
+async fn reassign<E>(
+    tx: &DatabaseTransaction,
+    employee_id: i64,
+    requested: &[Uuid],
+    found: &[(Uuid, i64)],
+    primary: Option<Uuid>,
+    effective_from: NaiveDate,
+    reason: &Option<String>,
+) -> AppResult
+where
+    E: AssignmentEntity,
+{
+    let id_of = |uuid: &Uuid| found.iter().find(|(found, _)| found == uuid).map(|(_, id)| *id);
+    let wanted = requested
+        .iter()
+        .map(|uuid| {
+            id_of(uuid).ok_or_else(|| AppError::EntityNotFoundError {
+                detail: format!("Assigned {} not found by uuid {uuid}", E::TARGET),
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+    let primary = match primary {
+        Some(uuid) => Some(id_of(&uuid).filter(|id| wanted.contains(id)).ok_or_else(|| {
+            AppError::BadRequestError(format!(
+                "Primary {} {uuid} is not one of the assigned {}s",
+                E::TARGET,
+                E::TARGET
+            ))
+        })?),
+        None => None,
+    };
+
+    let current = EmployeeEntity::find_assignments_since::<E>(tx, employee_id, effective_from)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let plan = plan_assignments(&current, &wanted, primary, effective_from);
+    if !plan.deleted.is_empty() {
+        E::delete_many().filter(E::id_column().is_in(plan.deleted)).exec(tx).await?;
+    }
+    if !plan.closed.is_empty() {
+        E::update_many()
+            .col_expr(E::effective_to_column(), Expr::value(plan.closed_on))
+            .col_expr(E::update_at_column(), Expr::value(Utc::now().naive_utc()))
+            .filter(E::id_column().is_in(plan.closed))
+            .exec(tx)
+            .await?;
+    }
+    if !plan.inserted.is_empty() {
+        E::insert_many(plan.inserted.iter().map(|(target_id, is_primary)| {
+            E::new_link(employee_id, *target_id, *is_primary, effective_from, reason.clone())
+        }))
+        .exec(tx)
+        .await?;
+    }
+    Ok(())
+}
+
+// Changes to apply on the assignments of one kind that are in effect on `effective_from` or start
+// later.
+#[derive(Debug, Default, PartialEq)]
+struct AssignmentPlan {
+    // Links that start on or after `effective_from`, replaced as a whole.
+    deleted: Vec<i64>,
+    // Links that started before `effective_from` and now end the day before it.
+    closed: Vec<i64>,
+    closed_on: NaiveDate,
+    // Target id and primary flag of the links starting on `effective_from`.
+    inserted: Vec<(i64, bool)>,
+}
+
+// `current` holds every assignment that ends on or after `effective_from`, `wanted` the target ids
+// the employee is assigned to from `effective_from` on. An open link that already started is kept
+// while its target is still wanted with the same primary flag, every other link is trimmed so
+// that the history never holds two overlapping assignments.
+fn plan_assignments(
+    current: &[Assignment],
+    wanted: &[i64],
+    primary: Option<i64>,
+    effective_from: NaiveDate,
+) -> AssignmentPlan {
+    let mut plan = AssignmentPlan {
+        closed_on: effective_from.pred_opt().unwrap_or(effective_from),
+        ..Default::default()
+    };
+    let is_kept = |link: &Assignment| {
+        wanted.contains(&link.target_id)
+            && (primary == Some(link.target_id)) == link.is_primary
+            && link.effective_to.is_none()
+            && link.effective_from <= effective_from
+    };
+    let mut kept = Vec::new();
+    for link in current {
+        if is_kept(link) && !kept.contains(&link.target_id) {
+            kept.push(link.target_id);
+        } else if link.effective_from >= effective_from {
+            plan.deleted.push(link.id);
+        } else {
+            plan.closed.push(link.id);
+        }
+    }
+    for target in wanted {
+        if !kept.contains(target) && !plan.inserted.iter().any(|(inserted, _)| inserted == target) {
+            plan.inserted.push((*target, primary == Some(*target)));
+        }
+    }
+    plan
+}
+
+// Employee with the uuids of its departments and positions, as written to the audit log.
+#[derive(Debug, Serialize)]
+struct EmployeeAuditSnapshot {
+    #[serde(flatten)]
+    employee: employee::Model,
+    department_uuids: Vec<Uuid>,
+    position_uuids: Vec<Uuid>,
+}
+
+async fn employee_snapshot(
+    tx: &DatabaseTransaction,
+    employee_id: i64,
+) -> AppResult<EmployeeAuditSnapshot> {
+    let employee = EmployeeEntity::find_data_by_id(tx, employee_id).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: format!("Employee not found by id {employee_id}") }
+    })?;
+    let today = Utc::now().date_naive();
+    let departments = EmployeeEntity::find_departments(tx, &[employee_id], today)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let positions = EmployeeEntity::find_positions(tx, &[employee_id], today)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    Ok(EmployeeAuditSnapshot {
+        employee,
+        department_uuids: departments
+            .into_iter()
+            .map(|(_, department)| department.department_uuid)
+            .collect(),
+        position_uuids: positions.into_iter().map(|(_, position)| position.position_uuid).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    fn link(id: i64, target_id: i64, is_primary: bool, from: u32, to: Option<u32>) -> Assignment {
+        Assignment {
+            id,
+            target_id,
+            is_primary,
+            effective_from: date(from),
+            effective_to: to.map(date),
+        }
+    }
+
+    #[test]
+    fn test_plan_keeps_unchanged_assignments() {
+        let current = [link(1, 10, true, 1, None), link(2, 20, false, 1, None)];
+        let plan = plan_assignments(&current, &[10, 20], Some(10), date(15));
+        assert_eq!(plan, AssignmentPlan { closed_on: date(14), ..Default::default() });
+    }
+
+    #[test]
+    fn test_plan_closes_removed_and_inserts_added_assignments() {
+        let current = [link(1, 10, true, 1, None), link(2, 20, false, 1, None)];
+        let plan = plan_assignments(&current, &[10, 30], Some(30), date(15));
+        assert_eq!(plan.closed, vec![1, 2]);
+        assert_eq!(plan.closed_on, date(14));
+        assert!(plan.deleted.is_empty());
+        assert_eq!(plan.inserted, vec![(10, false), (30, true)]);
+    }
+
+    #[test]
+    fn test_plan_replaces_assignments_starting_on_the_same_day() {
+        let current = [link(1, 10, true, 15, None)];
+        let plan = plan_assignments(&current, &[20], Some(20), date(15));
+        assert_eq!(plan.deleted, vec![1]);
+        assert!(plan.closed.is_empty());
+        assert_eq!(plan.inserted, vec![(20, true)]);
+    }
+
+    #[test]
+    fn test_plan_backdated_change_supersedes_later_assignments() {
+        // History: 10 from the 1st to the 19th, then 20 from the 20th, and 30 planned for the 25th.
+        let current = [
+            link(1, 10, true, 1, Some(19)),
+            link(2, 20, true, 20, Some(24)),
+            link(3, 30, true, 25, None),
+        ];
+        let plan = plan_assignments(&current, &[40], Some(40), date(10));
+        assert_eq!(plan.closed, vec![1]);
+        assert_eq!(plan.closed_on, date(9));
+        assert_eq!(plan.deleted, vec![2, 3]);
+        assert_eq!(plan.inserted, vec![(40, true)]);
+    }
+
+    #[test]
+    fn test_plan_reopens_an_assignment_ending_later() {
+        // The wanted department ends on the 20th, so it is trimmed and started again open ended.
+        let current = [link(1, 10, true, 1, Some(20))];
+        let plan = plan_assignments(&current, &[10], Some(10), date(10));
+        assert_eq!(plan.closed, vec![1]);
+        assert_eq!(plan.inserted, vec![(10, true)]);
+    }
+
+    #[test]
+    fn test_plan_drops_a_future_duplicate_of_a_kept_assignment() {
+        let current = [link(1, 10, true, 1, None), link(2, 10, true, 20, None)];
+        let plan = plan_assignments(&current, &[10], Some(10), date(10));
+        assert_eq!(plan.deleted, vec![2]);
+        assert!(plan.closed.is_empty());
+        assert!(plan.inserted.is_empty());
+    }
+
+    #[test]
+    fn test_plan_changes_the_primary_flag() {
+        let current = [link(1, 10, true, 1, None), link(2, 20, false, 1, None)];
+        let plan = plan_assignments(&current, &[10, 20], Some(20), date(10));
+        assert_eq!(plan.closed, vec![1, 2]);
+        assert_eq!(plan.inserted, vec![(10, false), (20, true)]);
+    }
+}
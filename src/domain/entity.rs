@@ -1,3 +1,4 @@
+pub use super::audit_log::Entity as AuditLogEntity;
 pub use super::employee::Entity as EmployeeEntity;
 pub use super::user::Entity as UserEntity;
 pub use super::department::Entity as DepartmentEntity;
@@ -5,3 +6,12 @@ pub use super::position::Entity as PositionEntity;
 pub use super::employee::employee_department::Entity as EmployeeDepartmentEntity;
 pub use super::employee::employee_position::Entity as EmployeePositionEntity;
 pub use super::organization::Entity as OrganizationEntity;
+pub use super::role::Entity as RoleEntity;
+pub use super::role::permission::Entity as PermissionEntity;
+pub use super::role::role_permission::Entity as RolePermissionEntity;
+pub use super::leave::Entity as LeaveTypeEntity;
+pub use super::leave::leave_request::Entity as LeaveRequestEntity;
+pub use super::attendance::Entity as AttendanceEventEntity;
+pub use super::shift::Entity as ShiftTemplateEntity;
+pub use super::shift::shift_assignment::Entity as ShiftAssignmentEntity;
+pub use super::calendar::Entity as HolidayEntity;
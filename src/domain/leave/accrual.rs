@@ -0,0 +1,190 @@
+use crate::domain::leave::EAccrualPeriod;
+use crate::domain::model::LeaveTypeModel;
+use chrono::{Datelike, Months, NaiveDate};
+use std::collections::HashMap;
+
+// Days of one leave type of an employee for the year of the balance day.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LeaveBalance {
+    // Unused days of the previous years, within the carry-over cap.
+    pub carried_over: f64,
+    // Days credited in the year up to the balance day.
+    pub accrued: f64,
+    // Approved leave starting in the year.
+    pub used: f64,
+    // Leave waiting for approval starting in the year.
+    pub pending: f64,
+}
+
+impl LeaveBalance {
+    pub fn available(&self) -> f64 {
+        self.carried_over + self.accrued - self.used - self.pending
+    }
+}
+
+// First day after the probation of the leave type.
+pub fn probation_end(leave_type: &LeaveTypeModel, hire_date: NaiveDate) -> NaiveDate {
+    let months = u32::try_from(leave_type.probation_months).unwrap_or_default();
+    hire_date.checked_add_months(Months::new(months)).unwrap_or(NaiveDate::MAX)
+}
+
+// Balance on `as_of`, years before the hire year are ignored. `used` holds the approved days of
+// every year and `pending` the days waiting for approval in the year of `as_of`.
+pub fn compute_balance(
+    leave_type: &LeaveTypeModel,
+    hire_date: NaiveDate,
+    as_of: NaiveDate,
+    used: &HashMap<i32, f64>,
+    pending: f64,
+) -> LeaveBalance {
+    let used_in = |year: i32| used.get(&year).copied().unwrap_or_default();
+    let mut carried_over = 0.0;
+    for year in hire_date.year()..as_of.year() {
+        let year_end = NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or(as_of);
+        let unused = carried_over + accrued_in_year(leave_type, hire_date, year, year_end)
+            - used_in(year);
+        carried_over = unused.max(0.0).min(leave_type.carry_over_cap.unwrap_or(f64::INFINITY));
+    }
+    LeaveBalance {
+        carried_over,
+        accrued: accrued_in_year(leave_type, hire_date, as_of.year(), as_of),
+        used: used_in(as_of.year()),
+        pending,
+    }
+}
+
+// Days credited in `year` up to `until`. Nothing accrues before the end of the probation, a
+// monthly accrual is credited on the first day of every month and a yearly one is credited
+// once, in proportion of the months left after the probation.
+fn accrued_in_year(
+    leave_type: &LeaveTypeModel,
+    hire_date: NaiveDate,
+    year: i32,
+    until: NaiveDate,
+) -> f64 {
+    let (Some(year_start), Some(year_end)) =
+        (NaiveDate::from_ymd_opt(year, 1, 1), NaiveDate::from_ymd_opt(year, 12, 31))
+    else {
+        return 0.0;
+    };
+    let start = year_start.max(hire_date).max(probation_end(leave_type, hire_date));
+    if start > until {
+        return 0.0;
+    }
+    match leave_type.accrual_period() {
+        EAccrualPeriod::None => 0.0,
+        EAccrualPeriod::Monthly => {
+            leave_type.accrual_days * month_starts(start, until.min(year_end)) as f64
+        },
+        EAccrualPeriod::Yearly => {
+            leave_type.accrual_days * month_starts(start, year_end) as f64 / 12.0
+        },
+    }
+}
+
+// Number of first days of a month between both days.
+fn month_starts(from: NaiveDate, to: NaiveDate) -> u32 {
+    let months = |date: NaiveDate| date.year() * 12 + date.month0() as i32;
+    let first = if from.day() == 1 { months(from) } else { months(from) + 1 };
+    u32::try_from(months(to) - first + 1).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+    use uuid::Uuid;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn leave_type(
+        accrual_period: EAccrualPeriod,
+        accrual_days: f64,
+        carry_over_cap: Option<f64>,
+        probation_months: i32,
+    ) -> LeaveTypeModel {
+        LeaveTypeModel {
+            id: 1,
+            leave_type_uuid: Uuid::nil(),
+            organization_id: 1,
+            name: "Annual leave".to_string(),
+            description: String::new(),
+            accrual_period: accrual_period.to_string(),
+            accrual_days,
+            carry_over_cap,
+            probation_months,
+            status: Some(1),
+            create_at: NaiveDateTime::default(),
+            update_at: NaiveDateTime::default(),
+        }
+    }
+
+    #[test]
+    fn test_yearly_accrual_is_credited_for_the_whole_year() {
+        let leave_type = leave_type(EAccrualPeriod::Yearly, 12.0, None, 0);
+        let balance =
+            compute_balance(&leave_type, date(2024, 1, 1), date(2024, 6, 15), &HashMap::new(), 0.0);
+        assert_eq!(balance, LeaveBalance { accrued: 12.0, ..Default::default() });
+    }
+
+    #[test]
+    fn test_yearly_accrual_is_prorated_from_the_month_after_the_hire_date() {
+        let leave_type = leave_type(EAccrualPeriod::Yearly, 12.0, None, 0);
+        let balance =
+            compute_balance(&leave_type, date(2024, 3, 10), date(2024, 6, 1), &HashMap::new(), 0.0);
+        assert_eq!(balance.accrued, 9.0);
+    }
+
+    #[test]
+    fn test_monthly_accrual_starts_after_the_probation() {
+        let leave_type = leave_type(EAccrualPeriod::Monthly, 1.5, None, 2);
+        let hire_date = date(2024, 1, 15);
+        assert_eq!(probation_end(&leave_type, hire_date), date(2024, 3, 15));
+        let balance =
+            compute_balance(&leave_type, hire_date, date(2024, 6, 20), &HashMap::new(), 0.0);
+        assert_eq!(balance.accrued, 4.5);
+    }
+
+    #[test]
+    fn test_probation_end_is_clamped_to_the_end_of_the_month() {
+        let leave_type = leave_type(EAccrualPeriod::Monthly, 1.0, None, 1);
+        assert_eq!(probation_end(&leave_type, date(2024, 1, 31)), date(2024, 2, 29));
+    }
+
+    #[test]
+    fn test_unused_days_are_carried_over_within_the_cap() {
+        let used = HashMap::from([(2023, 2.0), (2024, 1.0)]);
+        let capped = leave_type(EAccrualPeriod::Yearly, 12.0, Some(5.0), 0);
+        let balance = compute_balance(&capped, date(2023, 1, 1), date(2024, 2, 1), &used, 2.0);
+        assert_eq!(
+            balance,
+            LeaveBalance { carried_over: 5.0, accrued: 12.0, used: 1.0, pending: 2.0 }
+        );
+        assert_eq!(balance.available(), 14.0);
+
+        let uncapped = leave_type(EAccrualPeriod::Yearly, 12.0, None, 0);
+        let balance = compute_balance(&uncapped, date(2023, 1, 1), date(2024, 2, 1), &used, 2.0);
+        assert_eq!(balance.carried_over, 10.0);
+    }
+
+    #[test]
+    fn test_overused_year_carries_nothing_over() {
+        let used = HashMap::from([(2023, 15.0)]);
+        let leave_type = leave_type(EAccrualPeriod::Yearly, 12.0, None, 0);
+        let balance =
+            compute_balance(&leave_type, date(2023, 1, 1), date(2024, 2, 1), &used, 0.0);
+        assert_eq!(balance.carried_over, 0.0);
+    }
+
+    #[test]
+    fn test_leave_without_accrual_has_no_balance() {
+        let leave_type = leave_type(EAccrualPeriod::None, 0.0, None, 0);
+        let used = HashMap::from([(2024, 3.0)]);
+        let balance =
+            compute_balance(&leave_type, date(2023, 1, 1), date(2024, 6, 1), &used, 0.0);
+        assert_eq!(balance.carried_over + balance.accrued, 0.0);
+        assert_eq!(balance.used, 3.0);
+    }
+}
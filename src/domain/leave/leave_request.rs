@@ -0,0 +1,79 @@
+use crate::domain::leave::ELeaveRequestStatus;
+use chrono::{NaiveDate, NaiveDateTime};
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "leave_requests", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique, indexed)]
+    pub leave_request_uuid: Uuid,
+    #[sea_orm()]
+    pub employee_id: i64,
+    #[sea_orm(indexed)]
+    pub leave_type_id: i64,
+    // First and last day of the leave.
+    #[sea_orm()]
+    pub start_date: NaiveDate,
+    #[sea_orm()]
+    pub end_date: NaiveDate,
+    // Working days of the organization between both dates.
+    #[sea_orm()]
+    pub days: f64,
+    #[sea_orm(nullable)]
+    pub reason: Option<String>,
+    // One of `ELeaveRequestStatus`.
+    #[sea_orm()]
+    pub status: String,
+    // Employee who approved or rejected the request.
+    #[sea_orm(nullable)]
+    pub approver_employee_id: Option<i64>,
+    #[sea_orm(nullable)]
+    pub decided_at: Option<NaiveDateTime>,
+    #[sea_orm(nullable)]
+    pub decision_note: Option<String>,
+    #[sea_orm()]
+    pub create_at: NaiveDateTime,
+    #[sea_orm()]
+    pub update_at: NaiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::domain::employee::Entity",
+        from = "Column::EmployeeId",
+        to = "crate::domain::employee::Column::Id"
+    )]
+    Employee,
+    #[sea_orm(
+        belongs_to = "crate::domain::leave::Entity",
+        from = "Column::LeaveTypeId",
+        to = "crate::domain::leave::Column::Id"
+    )]
+    LeaveType,
+}
+
+impl Related<crate::domain::employee::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Employee.def()
+    }
+}
+
+impl Related<crate::domain::leave::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LeaveType.def()
+    }
+}
+
+impl Model {
+    pub fn status(&self) -> ELeaveRequestStatus {
+        self.status.parse().unwrap_or(ELeaveRequestStatus::Cancelled)
+    }
+}
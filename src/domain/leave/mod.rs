@@ -0,0 +1,120 @@
+use crate::domain::entity::{LeaveRequestEntity, LeaveTypeEntity, OrganizationEntity};
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
+use serde::{Deserialize, Serialize};
+
+pub mod accrual;
+pub mod leave_request;
+pub mod request;
+pub mod response;
+pub mod services;
+
+// Kind of leave an organization grants, e.g. annual or sick leave, with its accrual rule.
+#[derive(Debug, PartialEq, Clone, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "leave_types", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique, indexed)]
+    pub leave_type_uuid: Uuid,
+    #[sea_orm(indexed)]
+    pub organization_id: i64,
+    #[sea_orm()]
+    pub name: String,
+    #[sea_orm()]
+    pub description: String,
+    // One of `EAccrualPeriod`.
+    #[sea_orm()]
+    pub accrual_period: String,
+    // Days credited every period, the yearly amount for a yearly accrual.
+    #[sea_orm()]
+    pub accrual_days: f64,
+    // Most days moved from one year to the next, every unused day when missing.
+    #[sea_orm(nullable)]
+    pub carry_over_cap: Option<f64>,
+    // Months after the hire date during which nothing accrues and no leave can be taken.
+    #[sea_orm(default_value = 0)]
+    pub probation_months: i32,
+    #[sea_orm(nullable, default_value = 1)]
+    pub status: Option<i16>,
+    #[sea_orm()]
+    pub create_at: NaiveDateTime,
+    #[sea_orm()]
+    pub update_at: NaiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::organization::Entity",
+        from = "Column::OrganizationId",
+        to = "super::organization::Column::Id"
+    )]
+    Organization,
+    #[sea_orm(has_many = "leave_request::Entity")]
+    LeaveRequest,
+}
+
+impl Related<OrganizationEntity> for LeaveTypeEntity {
+    fn to() -> RelationDef {
+        Relation::Organization.def()
+    }
+}
+
+impl Related<LeaveRequestEntity> for LeaveTypeEntity {
+    fn to() -> RelationDef {
+        Relation::LeaveRequest.def()
+    }
+}
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+    utoipa::ToSchema,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EAccrualPeriod {
+    // No balance is kept, e.g. unpaid leave.
+    None,
+    Monthly,
+    Yearly,
+}
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+    utoipa::ToSchema,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ELeaveRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+impl Model {
+    pub fn accrual_period(&self) -> EAccrualPeriod {
+        self.accrual_period.parse().unwrap_or(EAccrualPeriod::None)
+    }
+}
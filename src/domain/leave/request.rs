@@ -0,0 +1,83 @@
+use crate::domain::leave::{EAccrualPeriod, ELeaveRequestStatus};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateLeaveTypeRequest {
+    pub organization_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+    pub accrual_period: EAccrualPeriod,
+    // Days credited every period, the yearly amount for a yearly accrual.
+    #[validate(range(min = 0.0, max = 366.0))]
+    pub accrual_days: f64,
+    // Most days moved to the next year, every unused day when missing.
+    #[validate(range(min = 0.0))]
+    pub carry_over_cap: Option<f64>,
+    #[validate(range(min = 0, max = 120))]
+    pub probation_months: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateLeaveTypeRequest {
+    pub leave_type_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+    pub accrual_period: Option<EAccrualPeriod>,
+    #[validate(range(min = 0.0, max = 366.0))]
+    pub accrual_days: Option<f64>,
+    #[validate(range(min = 0.0))]
+    pub carry_over_cap: Option<f64>,
+    #[validate(range(min = 0, max = 120))]
+    pub probation_months: Option<i32>,
+    #[validate(range(min = 0, max = 1))]
+    pub status: Option<i16>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams)]
+pub struct LeaveTypeQueryParam {
+    pub organization_uuid: Uuid,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct SubmitLeaveRequest {
+    pub leave_type_uuid: Uuid,
+    // First and last day of the leave, both in the same year.
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    #[validate(length(max = 500))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CancelLeaveRequest {
+    pub leave_request_uuid: Uuid,
+}
+
+// Approval or rejection of a pending leave request.
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct DecideLeaveRequest {
+    pub leave_request_uuid: Uuid,
+    #[validate(length(max = 500))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, ToSchema, IntoParams)]
+pub struct LeaveRequestQueryParam {
+    pub organization_uuid: Uuid,
+    pub employee_uuid: Option<Uuid>,
+    pub status: Option<ELeaveRequestStatus>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, ToSchema, IntoParams)]
+pub struct LeaveBalanceQueryParam {
+    // Day the balances are computed on, today by default.
+    pub as_of: Option<NaiveDate>,
+}
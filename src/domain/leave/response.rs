@@ -0,0 +1,121 @@
+use crate::domain::leave::accrual::LeaveBalance;
+use crate::domain::leave::{EAccrualPeriod, ELeaveRequestStatus};
+use crate::domain::model::{LeaveRequestModel, LeaveTypeModel};
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LeaveTypeResponse {
+    pub leave_type_uuid: Uuid,
+    pub organization_uuid: Uuid,
+    pub name: String,
+    pub description: String,
+    pub accrual_period: EAccrualPeriod,
+    pub accrual_days: f64,
+    pub carry_over_cap: Option<f64>,
+    pub probation_months: i32,
+    pub status: Option<i16>,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl LeaveTypeResponse {
+    pub fn new(leave_type: LeaveTypeModel, organization_uuid: Uuid) -> Self {
+        LeaveTypeResponse {
+            leave_type_uuid: leave_type.leave_type_uuid,
+            organization_uuid,
+            accrual_period: leave_type.accrual_period(),
+            name: leave_type.name,
+            description: leave_type.description,
+            accrual_days: leave_type.accrual_days,
+            carry_over_cap: leave_type.carry_over_cap,
+            probation_months: leave_type.probation_months,
+            status: leave_type.status,
+            create_at: leave_type.create_at,
+            update_at: leave_type.update_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LeaveRequestResponse {
+    pub leave_request_uuid: Uuid,
+    pub employee_uuid: Uuid,
+    pub leave_type_uuid: Uuid,
+    pub leave_type_name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub days: f64,
+    pub reason: Option<String>,
+    pub status: ELeaveRequestStatus,
+    pub approver_employee_uuid: Option<Uuid>,
+    pub decided_at: Option<NaiveDateTime>,
+    pub decision_note: Option<String>,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl LeaveRequestResponse {
+    pub fn new(
+        request: LeaveRequestModel,
+        employee_uuid: Uuid,
+        leave_type: &LeaveTypeModel,
+        approver_employee_uuid: Option<Uuid>,
+    ) -> Self {
+        LeaveRequestResponse {
+            leave_request_uuid: request.leave_request_uuid,
+            employee_uuid,
+            leave_type_uuid: leave_type.leave_type_uuid,
+            leave_type_name: leave_type.name.clone(),
+            status: request.status(),
+            start_date: request.start_date,
+            end_date: request.end_date,
+            days: request.days,
+            reason: request.reason,
+            approver_employee_uuid,
+            decided_at: request.decided_at,
+            decision_note: request.decision_note,
+            create_at: request.create_at,
+            update_at: request.update_at,
+        }
+    }
+}
+
+// Balance of one leave type for the year of the balance day.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LeaveBalanceResponse {
+    pub leave_type_uuid: Uuid,
+    pub name: String,
+    pub accrual_period: EAccrualPeriod,
+    pub year: i32,
+    pub carried_over: f64,
+    pub accrued: f64,
+    pub used: f64,
+    pub pending: f64,
+    // Days that can still be requested, unlimited when the leave type keeps no balance.
+    pub available: Option<f64>,
+}
+
+impl LeaveBalanceResponse {
+    pub fn new(leave_type: &LeaveTypeModel, year: i32, balance: LeaveBalance) -> Self {
+        let accrual_period = leave_type.accrual_period();
+        LeaveBalanceResponse {
+            leave_type_uuid: leave_type.leave_type_uuid,
+            name: leave_type.name.clone(),
+            accrual_period,
+            year,
+            carried_over: round(balance.carried_over),
+            accrued: round(balance.accrued),
+            used: round(balance.used),
+            pending: round(balance.pending),
+            available: Some(round(balance.available()))
+                .filter(|_| accrual_period != EAccrualPeriod::None),
+        }
+    }
+}
+
+fn round(days: f64) -> f64 {
+    (days * 100.0).round() / 100.0
+}
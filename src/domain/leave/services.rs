@@ -0,0 +1,587 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::domain::audit_log::services::record_audit_log;
+use crate::domain::audit_log::{AuditContext, EAuditAction, EAuditEntity};
+use crate::domain::calendar::services::find_work_calendar;
+use crate::domain::employee::services::{
+    find_employee_by_user_uuid, find_employee_by_uuid, find_employee_organization_id,
+    is_manager_or_department_head,
+};
+use crate::domain::entity::{
+    EmployeeEntity, LeaveRequestEntity, LeaveTypeEntity, OrganizationEntity,
+};
+use crate::domain::leave::accrual::{
+    compute_balance, probation_end, LeaveBalance,
+};
+use crate::domain::leave::request::{
+    CancelLeaveRequest, CreateLeaveTypeRequest, DecideLeaveRequest, LeaveRequestQueryParam,
+    SubmitLeaveRequest, UpdateLeaveTypeRequest,
+};
+use crate::domain::leave::response::{
+    LeaveBalanceResponse, LeaveRequestResponse, LeaveTypeResponse,
+};
+use crate::domain::leave::{EAccrualPeriod, ELeaveRequestStatus};
+use crate::domain::model::{EmployeeModel, LeaveRequestModel, LeaveTypeModel};
+use crate::domain::organization::services::find_organization_by_uuid;
+use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
+use crate::util::filter_and_pagination::{PageQueryParam, Paginated};
+use chrono::{Datelike, NaiveDate, Utc};
+use sea_orm::{ConnectionTrait, DatabaseTransaction, TransactionTrait};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use uuid::Uuid;
+
+pub async fn service_create_leave_type(
+    state: &AppState,
+    context: &AuditContext,
+    req: CreateLeaveTypeRequest,
+) -> AppResult<LeaveTypeResponse> {
+    tracing::info!("Create new leave type request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let organization = find_organization_by_uuid(&tx, &req.organization_uuid).await?;
+    check_unique_name(&tx, organization.id, &req.name, None).await?;
+
+    let leave_type = LeaveTypeModel {
+        id: Default::default(),
+        leave_type_uuid: Uuid::new_v4(),
+        organization_id: organization.id,
+        name: req.name,
+        description: req.description.unwrap_or_default(),
+        accrual_period: req.accrual_period.to_string(),
+        accrual_days: req.accrual_days,
+        carry_over_cap: req.carry_over_cap,
+        probation_months: req.probation_months.unwrap_or_default(),
+        status: Some(1),
+        create_at: Utc::now().naive_utc(),
+        update_at: Utc::now().naive_utc(),
+    };
+    let leave_type_id = LeaveTypeEntity::create(&tx, &leave_type)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Leave type".to_string() })?;
+    let leave_type = LeaveTypeModel { id: leave_type_id, ..leave_type };
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::LeaveType,
+        leave_type.leave_type_uuid,
+        None,
+        Some(&leave_type),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(LeaveTypeResponse::new(leave_type, organization.organization_uuid))
+}
+
+pub async fn service_update_leave_type(
+    state: &AppState,
+    context: &AuditContext,
+    req: UpdateLeaveTypeRequest,
+) -> AppResult<LeaveTypeResponse> {
+    tracing::info!("Update leave type request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut leave_type = find_leave_type_by_uuid(&tx, &req.leave_type_uuid).await?;
+    let before = leave_type.clone();
+    let organization = OrganizationEntity::find_data_by_id(&tx, leave_type.organization_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Organization not found".to_string(),
+        })?;
+
+    if let Some(name) = req.name {
+        leave_type.name = name;
+    }
+    if let Some(description) = req.description {
+        leave_type.description = description;
+    }
+    if let Some(accrual_period) = req.accrual_period {
+        leave_type.accrual_period = accrual_period.to_string();
+    }
+    if let Some(accrual_days) = req.accrual_days {
+        leave_type.accrual_days = accrual_days;
+    }
+    if let Some(carry_over_cap) = req.carry_over_cap {
+        leave_type.carry_over_cap = Some(carry_over_cap);
+    }
+    if let Some(probation_months) = req.probation_months {
+        leave_type.probation_months = probation_months;
+    }
+    if let Some(status) = req.status {
+        leave_type.status = Some(status);
+    }
+    // Renaming or reactivating must not clash with another active leave type.
+    if leave_type.status == Some(1) {
+        check_unique_name(&tx, organization.id, &leave_type.name, Some(leave_type.id)).await?;
+    }
+    leave_type.update_at = Utc::now().naive_utc();
+
+    LeaveTypeEntity::update(&tx, leave_type.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Leave type".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::LeaveType,
+        leave_type.leave_type_uuid,
+        Some(&before),
+        Some(&leave_type),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(LeaveTypeResponse::new(leave_type, organization.organization_uuid))
+}
+
+pub async fn service_get_list_leave_type(
+    state: &AppState,
+    organization_uuid: Uuid,
+    param: PageQueryParam,
+) -> AppResult<Paginated<LeaveTypeResponse>> {
+    tracing::info!("Get leave type list of organization {organization_uuid}: {param:?}");
+    let organization = find_organization_by_uuid(&*state.db, &organization_uuid).await?;
+    let leave_types =
+        LeaveTypeEntity::find_by_organization(&*state.db, organization.id, param).await?;
+    Ok(leave_types.map(|leave_type| LeaveTypeResponse::new(leave_type, organization_uuid)))
+}
+
+pub async fn service_submit_leave_request(
+    state: &AppState,
+    context: &AuditContext,
+    req: SubmitLeaveRequest,
+) -> AppResult<LeaveRequestResponse> {
+    tracing::info!("Submit leave request: {req:?}.");
+    if req.end_date < req.start_date {
+        return Err(AppError::BadRequestError("End date is before start date".to_string()));
+    }
+    if req.end_date.year() != req.start_date.year() {
+        return Err(AppError::BadRequestError(
+            "A leave request can not span two years, submit one request per year".to_string(),
+        ));
+    }
+    let tx = state.db.begin().await?;
+    let employee = find_employee_by_user_uuid(&tx, &context.actor_uuid).await?;
+    // Requests of one employee are checked one after another, so two of them can not both
+    // pass the overlap and balance checks.
+    lock_employee(&tx, &employee).await?;
+    if employee.status != Some(1) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!("Employee is not active {}", employee.employee_uuid),
+        });
+    }
+    let leave_type = find_leave_type_by_uuid(&tx, &req.leave_type_uuid).await?;
+    let organization_id = find_employee_organization_id(&tx, &employee, req.start_date).await?;
+    if leave_type.organization_id != organization_id || leave_type.status != Some(1) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!("Leave type is not available {}", req.leave_type_uuid),
+        });
+    }
+    let available_from = probation_end(&leave_type, employee.hire_date);
+    if req.start_date < available_from {
+        return Err(AppError::BadRequestError(format!(
+            "{} can not be taken before {available_from}",
+            leave_type.name
+        )));
+    }
+
+    let calendar = find_work_calendar(&tx, organization_id).await?;
+    let days = f64::from(calendar.count_working_days(req.start_date, req.end_date));
+    if days == 0.0 {
+        return Err(AppError::BadRequestError("The leave has no working day".to_string()));
+    }
+    let overlapping =
+        LeaveRequestEntity::find_overlapping(&tx, employee.id, req.start_date, req.end_date)
+            .await
+            .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    if !overlapping.is_empty() {
+        return Err(AppError::ConflictError(
+            "The leave overlaps another pending or approved leave".to_string(),
+        ));
+    }
+    let balance = find_balance(&tx, &employee, &leave_type, req.end_date).await?;
+    if leave_type.accrual_period() != EAccrualPeriod::None && balance.available() < days {
+        return Err(AppError::BadRequestError(format!(
+            "Not enough {} balance, {} days available",
+            leave_type.name,
+            balance.available()
+        )));
+    }
+
+    let leave_request = LeaveRequestModel {
+        id: Default::default(),
+        leave_request_uuid: Uuid::new_v4(),
+        employee_id: employee.id,
+        leave_type_id: leave_type.id,
+        start_date: req.start_date,
+        end_date: req.end_date,
+        days,
+        reason: req.reason,
+        status: ELeaveRequestStatus::Pending.to_string(),
+        approver_employee_id: None,
+        decided_at: None,
+        decision_note: None,
+        create_at: Utc::now().naive_utc(),
+        update_at: Utc::now().naive_utc(),
+    };
+    let leave_request_id = LeaveRequestEntity::create(&tx, &leave_request)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Leave request".to_string() })?;
+    let leave_request = LeaveRequestModel { id: leave_request_id, ..leave_request };
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::LeaveRequest,
+        leave_request.leave_request_uuid,
+        None,
+        Some(&leave_request),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(LeaveRequestResponse::new(leave_request, employee.employee_uuid, &leave_type, None))
+}
+
+// The employee cancels a pending request, or an approved one that has not started yet.
+pub async fn service_cancel_leave_request(
+    state: &AppState,
+    context: &AuditContext,
+    req: CancelLeaveRequest,
+) -> AppResult<LeaveRequestResponse> {
+    tracing::info!("Cancel leave request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let employee = find_employee_by_user_uuid(&tx, &context.actor_uuid).await?;
+    // Read after the lock, so a decision taken meanwhile is seen.
+    lock_employee(&tx, &employee).await?;
+    let mut leave_request = find_leave_request_by_uuid(&tx, &req.leave_request_uuid).await?;
+    if leave_request.employee_id != employee.id {
+        return Err(AppError::PermissionDeniedError(
+            "Only the employee can cancel the leave request".to_string(),
+        ));
+    }
+    let today = Utc::now().date_naive();
+    let cancellable = match leave_request.status() {
+        ELeaveRequestStatus::Pending => true,
+        ELeaveRequestStatus::Approved => leave_request.start_date > today,
+        _ => false,
+    };
+    if !cancellable {
+        return Err(AppError::BadRequestError(format!(
+            "A {} leave request can not be cancelled",
+            leave_request.status
+        )));
+    }
+
+    let before = leave_request.clone();
+    leave_request.status = ELeaveRequestStatus::Cancelled.to_string();
+    leave_request.update_at = Utc::now().naive_utc();
+    LeaveRequestEntity::update(&tx, leave_request.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Leave request".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::LeaveRequest,
+        leave_request.leave_request_uuid,
+        Some(&before),
+        Some(&leave_request),
+    )
+    .await?;
+    let leave_type = find_leave_type_by_id(&tx, leave_request.leave_type_id).await?;
+    let approver_uuid = find_employee_uuid(&tx, leave_request.approver_employee_id).await;
+    tx.commit().await?;
+    Ok(LeaveRequestResponse::new(
+        leave_request,
+        employee.employee_uuid,
+        &leave_type,
+        approver_uuid,
+    ))
+}
+
+pub async fn service_approve_leave_request(
+    state: &AppState,
+    context: &AuditContext,
+    req: DecideLeaveRequest,
+) -> AppResult<LeaveRequestResponse> {
+    tracing::info!("Approve leave request: {req:?}.");
+    decide_leave_request(state, context, req, ELeaveRequestStatus::Approved).await
+}
+
+pub async fn service_reject_leave_request(
+    state: &AppState,
+    context: &AuditContext,
+    req: DecideLeaveRequest,
+) -> AppResult<LeaveRequestResponse> {
+    tracing::info!("Reject leave request: {req:?}.");
+    decide_leave_request(state, context, req, ELeaveRequestStatus::Rejected).await
+}
+
+// A pending request is decided by the manager of the employee or by the head of one of the
+// departments of the employee.
+async fn decide_leave_request(
+    state: &AppState,
+    context: &AuditContext,
+    req: DecideLeaveRequest,
+    status: ELeaveRequestStatus,
+) -> AppResult<LeaveRequestResponse> {
+    let tx = state.db.begin().await?;
+    let approver = find_employee_by_user_uuid(&tx, &context.actor_uuid).await?;
+    let leave_request = find_leave_request_by_uuid(&tx, &req.leave_request_uuid).await?;
+    let employee = EmployeeEntity::find_data_by_id(&tx, leave_request.employee_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Employee not found".to_string(),
+        })?;
+    // Every change of the requests of the employee holds this lock, the request is read again
+    // so another decision or a cancel taken meanwhile is seen.
+    lock_employee(&tx, &employee).await?;
+    let mut leave_request = find_leave_request_by_uuid(&tx, &req.leave_request_uuid).await?;
+    if leave_request.status() != ELeaveRequestStatus::Pending {
+        return Err(AppError::BadRequestError(format!(
+            "The leave request is already {}",
+            leave_request.status
+        )));
+    }
+    if !is_manager_or_department_head(&tx, &approver, &employee, leave_request.start_date).await? {
+        return Err(AppError::PermissionDeniedError(
+            "Only the manager or a department head of the employee can decide the leave request"
+                .to_string(),
+        ));
+    }
+    let leave_type = find_leave_type_by_id(&tx, leave_request.leave_type_id).await?;
+    if status == ELeaveRequestStatus::Approved
+        && leave_type.accrual_period() != EAccrualPeriod::None
+    {
+        // The pending days of the balance already count this request.
+        let balance = find_balance(&tx, &employee, &leave_type, leave_request.end_date).await?;
+        if balance.available() < 0.0 {
+            return Err(AppError::BadRequestError(format!(
+                "Not enough {} balance to approve {} days",
+                leave_type.name, leave_request.days
+            )));
+        }
+    }
+
+    let before = leave_request.clone();
+    leave_request.status = status.to_string();
+    leave_request.approver_employee_id = Some(approver.id);
+    leave_request.decided_at = Some(Utc::now().naive_utc());
+    leave_request.decision_note = req.note;
+    leave_request.update_at = Utc::now().naive_utc();
+    LeaveRequestEntity::update(&tx, leave_request.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Leave request".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::LeaveRequest,
+        leave_request.leave_request_uuid,
+        Some(&before),
+        Some(&leave_request),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(LeaveRequestResponse::new(
+        leave_request,
+        employee.employee_uuid,
+        &leave_type,
+        Some(approver.employee_uuid),
+    ))
+}
+
+pub async fn service_get_list_leave_request(
+    state: &AppState,
+    query: LeaveRequestQueryParam,
+    param: PageQueryParam,
+) -> AppResult<Paginated<LeaveRequestResponse>> {
+    tracing::info!("Get leave request list with query: {query:?}, parameter: {param:?}");
+    let organization = find_organization_by_uuid(&*state.db, &query.organization_uuid).await?;
+    let employee_id = match query.employee_uuid {
+        Some(employee_uuid) => Some(find_employee_by_uuid(&*state.db, &employee_uuid).await?.id),
+        None => None,
+    };
+    let leave_requests =
+        LeaveRequestEntity::search(&*state.db, organization.id, employee_id, query.status, param)
+            .await?;
+
+    let leave_type_ids =
+        leave_requests.items.iter().map(|request| request.leave_type_id).collect::<Vec<_>>();
+    let employee_ids = leave_requests
+        .items
+        .iter()
+        .flat_map(|request| [Some(request.employee_id), request.approver_employee_id])
+        .flatten()
+        .collect::<Vec<_>>();
+    let leave_types = LeaveTypeEntity::find_by_ids(&*state.db, &leave_type_ids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let employees = EmployeeEntity::find_by_ids(&*state.db, &employee_ids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let employee_uuid = |id: i64| {
+        employees.iter().find(|employee| employee.id == id).map(|employee| employee.employee_uuid)
+    };
+
+    let mut items = Vec::new();
+    for request in leave_requests.items {
+        let leave_type =
+            leave_types.iter().find(|item| item.id == request.leave_type_id).ok_or_else(|| {
+                AppError::EntityNotFoundError { detail: "Leave type not found".to_string() }
+            })?;
+        let approver_uuid = request.approver_employee_id.and_then(employee_uuid);
+        let requester_uuid = employee_uuid(request.employee_id).unwrap_or_default();
+        items.push(LeaveRequestResponse::new(request, requester_uuid, leave_type, approver_uuid));
+    }
+    Ok(Paginated { items, pagination: leave_requests.pagination })
+}
+
+pub async fn service_get_my_leave_balance(
+    state: &AppState,
+    context: &AuditContext,
+    as_of: Option<NaiveDate>,
+) -> AppResult<Vec<LeaveBalanceResponse>> {
+    tracing::info!("Get leave balances of user {}", context.actor_uuid);
+    let employee = find_employee_by_user_uuid(&*state.db, &context.actor_uuid).await?;
+    leave_balances(&*state.db, &employee, as_of.unwrap_or_else(|| Utc::now().date_naive())).await
+}
+
+pub async fn service_get_leave_balance(
+    state: &AppState,
+    employee_uuid: Uuid,
+    as_of: Option<NaiveDate>,
+) -> AppResult<Vec<LeaveBalanceResponse>> {
+    tracing::info!("Get leave balances of employee {employee_uuid}");
+    let employee = find_employee_by_uuid(&*state.db, &employee_uuid).await?;
+    leave_balances(&*state.db, &employee, as_of.unwrap_or_else(|| Utc::now().date_naive())).await
+}
+
+// Balances of every active leave type of the organization of the employee.
+async fn leave_balances<DB>(
+    conn: &DB,
+    employee: &EmployeeModel,
+    as_of: NaiveDate,
+) -> AppResult<Vec<LeaveBalanceResponse>>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let organization_id = find_employee_organization_id(conn, employee, as_of).await?;
+    let leave_types = LeaveTypeEntity::find_active_by_organization(conn, organization_id)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let leave_requests = find_active_leave(conn, employee, as_of).await?;
+    Ok(leave_types
+        .iter()
+        .map(|leave_type| {
+            let balance = balance_of(leave_type, employee, as_of, &leave_requests);
+            LeaveBalanceResponse::new(leave_type, as_of.year(), balance)
+        })
+        .collect())
+}
+
+async fn find_balance<DB>(
+    conn: &DB,
+    employee: &EmployeeModel,
+    leave_type: &LeaveTypeModel,
+    as_of: NaiveDate,
+) -> AppResult<LeaveBalance>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let leave_requests = find_active_leave(conn, employee, as_of).await?;
+    Ok(balance_of(leave_type, employee, as_of, &leave_requests))
+}
+
+// Pending and approved leave of the employee up to the end of the year of `as_of`.
+async fn find_active_leave<DB>(
+    conn: &DB,
+    employee: &EmployeeModel,
+    as_of: NaiveDate,
+) -> AppResult<Vec<LeaveRequestModel>>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let year_end = NaiveDate::from_ymd_opt(as_of.year(), 12, 31).unwrap_or(as_of);
+    LeaveRequestEntity::find_active_by_employee(conn, employee.id, year_end)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))
+}
+
+fn balance_of(
+    leave_type: &LeaveTypeModel,
+    employee: &EmployeeModel,
+    as_of: NaiveDate,
+    leave_requests: &[LeaveRequestModel],
+) -> LeaveBalance {
+    let mut used = HashMap::new();
+    let mut pending = 0.0;
+    for request in leave_requests.iter().filter(|request| request.leave_type_id == leave_type.id) {
+        match request.status() {
+            ELeaveRequestStatus::Approved => {
+                *used.entry(request.start_date.year()).or_insert(0.0) += request.days;
+            },
+            ELeaveRequestStatus::Pending if request.start_date.year() == as_of.year() => {
+                pending += request.days;
+            },
+            _ => {},
+        }
+    }
+    compute_balance(leave_type, employee.hire_date, as_of, &used, pending)
+}
+
+async fn lock_employee(tx: &DatabaseTransaction, employee: &EmployeeModel) -> AppResult {
+    EmployeeEntity::lock_by_ids(tx, &[employee.id])
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    Ok(())
+}
+
+async fn find_employee_uuid(tx: &DatabaseTransaction, employee_id: Option<i64>) -> Option<Uuid> {
+    EmployeeEntity::find_data_by_id(tx, employee_id?).await.map(|employee| employee.employee_uuid)
+}
+
+async fn find_leave_type_by_uuid(
+    tx: &DatabaseTransaction,
+    leave_type_uuid: &Uuid,
+) -> AppResult<LeaveTypeModel> {
+    LeaveTypeEntity::find_data_by_uuid(tx, leave_type_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError {
+            detail: format!("Leave type not found by uuid {leave_type_uuid}"),
+        }
+    })
+}
+
+async fn find_leave_type_by_id(
+    tx: &DatabaseTransaction,
+    leave_type_id: i64,
+) -> AppResult<LeaveTypeModel> {
+    LeaveTypeEntity::find_data_by_id(tx, leave_type_id).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: "Leave type not found".to_string() }
+    })
+}
+
+async fn find_leave_request_by_uuid(
+    tx: &DatabaseTransaction,
+    leave_request_uuid: &Uuid,
+) -> AppResult<LeaveRequestModel> {
+    LeaveRequestEntity::find_by_uuid(tx, leave_request_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError {
+            detail: format!("Leave request not found by uuid {leave_request_uuid}"),
+        }
+    })
+}
+
+// Active leave type names are unique inside an organization, `leave_type_id` is the one being
+// renamed.
+async fn check_unique_name(
+    tx: &DatabaseTransaction,
+    organization_id: i64,
+    name: &str,
+    leave_type_id: Option<i64>,
+) -> AppResult {
+    match LeaveTypeEntity::find_by_organization_and_name(tx, organization_id, name).await {
+        Some(exists) if Some(exists.id) != leave_type_id => Err(AppError::EntityExistsError {
+            detail: "Leave type name is already exists in this organization!".to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
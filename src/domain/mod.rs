@@ -1,8 +1,14 @@
+pub mod attendance;
+pub mod audit_log;
 pub mod authenticate;
+pub mod calendar;
 pub mod employee;
 pub mod user;
 pub mod department;
+pub mod leave;
 pub mod organization;
 pub mod position;
+pub mod role;
+pub mod shift;
 pub mod entity;
 pub mod model;
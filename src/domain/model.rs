@@ -1,3 +1,4 @@
+pub use super::audit_log::Model as AuditLogModel;
 pub use super::employee::Model as EmployeeModel;
 pub use super::user::Model as UserModel;
 pub use super::department::Model as DepartmentModel;
@@ -5,3 +6,12 @@ pub use super::position::Model as PositionModel;
 pub use super::employee::employee_department::Model as EmployeeDepartmentModel;
 pub use super::employee::employee_position::Model as EmployeePositionModel;
 pub use super::organization::Model as OrganizationModel;
+pub use super::role::Model as RoleModel;
+pub use super::role::permission::Model as PermissionModel;
+pub use super::role::role_permission::Model as RolePermissionModel;
+pub use super::leave::Model as LeaveTypeModel;
+pub use super::leave::leave_request::Model as LeaveRequestModel;
+pub use super::attendance::Model as AttendanceEventModel;
+pub use super::shift::Model as ShiftTemplateModel;
+pub use super::shift::shift_assignment::Model as ShiftAssignmentModel;
+pub use super::calendar::Model as HolidayModel;
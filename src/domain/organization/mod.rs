@@ -1,12 +1,16 @@
 use crate::domain::entity::{DepartmentEntity, OrganizationEntity};
-use chrono::{DateTime, Utc};
+use crate::util::constant::DEFAULT_TIMEZONE;
+use chrono::{DateTime, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
 use sea_orm::entity::prelude::*;
 use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
+pub mod org_chart;
 pub mod request;
 pub mod response;
-mod services;
+pub mod services;
 
 #[derive(
     Debug, PartialEq, Eq, PartialOrd, Ord, Clone, DeriveEntityModel, Deserialize, Serialize,
@@ -21,6 +25,26 @@ pub struct Model {
     pub name: String,
     #[sea_orm()]
     pub description: String,
+    #[sea_orm(default_value = "vi")]
+    pub default_language: String,
+    #[sea_orm(default_value = "Asia/Ho_Chi_Minh")]
+    pub timezone: String,
+    // Comma separated working days, e.g. "mon,tue,wed,thu,fri".
+    #[sea_orm(default_value = "mon,tue,wed,thu,fri")]
+    pub working_week: String,
+    // Working hours of a working day, in the timezone of the organization.
+    #[sea_orm(default_value = "08:00:00")]
+    pub work_start_time: NaiveTime,
+    #[sea_orm(default_value = "17:00:00")]
+    pub work_end_time: NaiveTime,
+    // Minutes after the start of the working hours before an arrival counts as late.
+    #[sea_orm(default_value = 0)]
+    pub late_grace_minutes: i32,
+    // Most hours of shifts an employee can be scheduled for in a week.
+    #[sea_orm(default_value = 48)]
+    pub max_weekly_hours: i32,
+    #[sea_orm(default_value = "VND")]
+    pub currency: String,
     #[sea_orm()]
     pub create_at: DateTime<Utc>,
     #[sea_orm()]
@@ -42,3 +66,64 @@ impl Related<DepartmentEntity> for OrganizationEntity {
         Relation::Department.def()
     }
 }
+
+impl Model {
+    pub fn working_days(&self) -> Vec<EWeekday> {
+        self.working_week.split(',').filter_map(|day| EWeekday::from_str(day.trim()).ok()).collect()
+    }
+
+    pub fn time_zone(&self) -> Tz {
+        self.timezone.parse().unwrap_or_else(|_| DEFAULT_TIMEZONE.parse().unwrap_or(Tz::UTC))
+    }
+}
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Deserialize,
+    Serialize,
+    utoipa::ToSchema,
+    Clone,
+    Copy,
+    Hash,
+    EnumIter,
+    strum::EnumString,
+    strum::Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum EWeekday {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+impl EWeekday {
+    pub fn join(days: &[EWeekday]) -> String {
+        let mut days = days.to_vec();
+        days.sort();
+        days.dedup();
+        days.iter().map(ToString::to_string).collect::<Vec<_>>().join(",")
+    }
+}
+
+impl From<EWeekday> for Weekday {
+    fn from(value: EWeekday) -> Self {
+        match value {
+            EWeekday::Mon => Weekday::Mon,
+            EWeekday::Tue => Weekday::Tue,
+            EWeekday::Wed => Weekday::Wed,
+            EWeekday::Thu => Weekday::Thu,
+            EWeekday::Fri => Weekday::Fri,
+            EWeekday::Sat => Weekday::Sat,
+            EWeekday::Sun => Weekday::Sun,
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use crate::domain::model::OrganizationModel;
+use crate::domain::organization::response::{EOrgChartNodeType, OrgChartNodeResponse};
+use crate::infrastructure::persistence::department_repo::Metadata;
+use crate::infrastructure::persistence::employee_repo::PositionHolder;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+const NODE_WIDTH: f64 = 160.0;
+const NODE_HEIGHT: f64 = 40.0;
+const HORIZONTAL_GAP: f64 = 20.0;
+const VERTICAL_GAP: f64 = 40.0;
+const MARGIN: f64 = 20.0;
+const MAX_LABEL_CHARS: usize = 22;
+
+// Organization -> departments (nested by parent) -> positions -> employees. `rows` are
+// ordered by department, so positions of a department are adjacent. Positions of a
+// department come before its sub departments.
+pub fn build_org_chart(
+    organization: &OrganizationModel,
+    rows: Vec<Metadata>,
+    holders: &[PositionHolder],
+    depth: Option<u32>,
+) -> OrgChartNodeResponse {
+    let mut departments: Vec<(i64, Option<i64>, OrgChartNodeResponse)> = Vec::new();
+    for row in rows {
+        if departments.last().map(|(id, _, _)| *id) != Some(row.department_id) {
+            departments.push((
+                row.department_id,
+                row.parent_department_id,
+                OrgChartNodeResponse::new(
+                    EOrgChartNodeType::Department,
+                    row.department_uuid,
+                    row.department_name,
+                ),
+            ));
+        }
+        if let (Some(position_id), Some(position_uuid), Some(name), Some((_, _, department))) =
+            (row.position_id, row.position_uuid, row.position_name, departments.last_mut())
+        {
+            let mut position =
+                OrgChartNodeResponse::new(EOrgChartNodeType::Position, position_uuid, name);
+            position.children = holders
+                .iter()
+                .filter(|holder| holder.position_id == position_id)
+                .map(|holder| {
+                    OrgChartNodeResponse::new(
+                        EOrgChartNodeType::Employee,
+                        holder.employee_uuid,
+                        holder.full_name.clone(),
+                    )
+                })
+                .collect();
+            department.children.push(position);
+        }
+    }
+
+    // A department whose parent is inactive is shown right below the organization.
+    let ids = departments.iter().map(|(id, _, _)| *id).collect::<HashSet<_>>();
+    let mut by_parent: HashMap<Option<i64>, Vec<(i64, OrgChartNodeResponse)>> = HashMap::new();
+    for (id, parent, node) in departments {
+        by_parent.entry(parent.filter(|parent| ids.contains(parent))).or_default().push((id, node));
+    }
+
+    let mut root = OrgChartNodeResponse::new(
+        EOrgChartNodeType::Organization,
+        organization.organization_uuid,
+        organization.name.clone(),
+    );
+    root.children = nest_departments(None, &mut by_parent);
+    if let Some(depth) = depth {
+        root.truncate(depth);
+    }
+    root
+}
+
+fn nest_departments(
+    parent: Option<i64>,
+    by_parent: &mut HashMap<Option<i64>, Vec<(i64, OrgChartNodeResponse)>>,
+) -> Vec<OrgChartNodeResponse> {
+    by_parent
+        .remove(&parent)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(id, mut node)| {
+            node.children.extend(nest_departments(Some(id), by_parent));
+            node
+        })
+        .collect()
+}
+
+// Graphviz source, nodes are numbered since an employee may hold several positions.
+pub fn render_dot(root: &OrgChartNodeResponse) -> String {
+    let mut dot = String::from(
+        "digraph org_chart {\n    rankdir=TB;\n    \
+         node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n",
+    );
+    let mut next_id = 0;
+    write_dot_node(root, &mut dot, &mut next_id);
+    dot.push_str("}\n");
+    dot
+}
+
+fn write_dot_node(node: &OrgChartNodeResponse, dot: &mut String, next_id: &mut usize) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+    let _ = writeln!(
+        dot,
+        "    n{id} [label=\"{}\", fillcolor=\"{}\"];",
+        escape_dot(&node.name),
+        fill_color(node.node_type)
+    );
+    for child in &node.children {
+        let child_id = write_dot_node(child, dot, next_id);
+        let _ = writeln!(dot, "    n{id} -> n{child_id};");
+    }
+    id
+}
+
+// Standalone SVG drawing of the chart: leaves take one column each from left to right and
+// every parent is centered above its children.
+pub fn render_svg(root: &OrgChartNodeResponse) -> String {
+    let mut layout = SvgLayout { boxes: Vec::new(), edges: Vec::new(), columns: 0.0 };
+    place(root, 0, &mut layout);
+    let levels = layout.boxes.iter().map(|(_, level, _)| *level).max().unwrap_or(0) + 1;
+    let width = layout.columns * (NODE_WIDTH + HORIZONTAL_GAP) - HORIZONTAL_GAP + 2.0 * MARGIN;
+    let height = levels as f64 * (NODE_HEIGHT + VERTICAL_GAP) - VERTICAL_GAP + 2.0 * MARGIN;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" font-family=\"Helvetica, Arial, sans-serif\" \
+         font-size=\"12\">"
+    );
+    for (parent, child) in &layout.edges {
+        let (parent_x, parent_y) = box_origin(&layout.boxes[*parent]);
+        let (child_x, child_y) = box_origin(&layout.boxes[*child]);
+        let from_x = parent_x + NODE_WIDTH / 2.0;
+        let from_y = parent_y + NODE_HEIGHT;
+        let to_x = child_x + NODE_WIDTH / 2.0;
+        let middle_y = from_y + VERTICAL_GAP / 2.0;
+        let _ = writeln!(
+            svg,
+            "  <path d=\"M{from_x} {from_y} V{middle_y} H{to_x} V{child_y}\" fill=\"none\" \
+             stroke=\"#888888\"/>"
+        );
+    }
+    for node_box in &layout.boxes {
+        let (x, y) = box_origin(node_box);
+        let node = node_box.2;
+        let _ = writeln!(
+            svg,
+            "  <g><title>{}</title><rect x=\"{x}\" y=\"{y}\" width=\"{NODE_WIDTH}\" \
+             height=\"{NODE_HEIGHT}\" rx=\"6\" fill=\"{}\" stroke=\"#555555\"/>\
+             <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>\
+             </g>",
+            escape_xml(&node.name),
+            fill_color(node.node_type),
+            x + NODE_WIDTH / 2.0,
+            y + NODE_HEIGHT / 2.0,
+            escape_xml(&shorten(&node.name))
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+struct SvgLayout<'a> {
+    // Column (center, in node widths), level and node of every box.
+    boxes: Vec<(f64, usize, &'a OrgChartNodeResponse)>,
+    // Indexes in `boxes` of the parent and the child.
+    edges: Vec<(usize, usize)>,
+    columns: f64,
+}
+
+fn place<'a>(node: &'a OrgChartNodeResponse, level: usize, layout: &mut SvgLayout<'a>) -> usize {
+    let children =
+        node.children.iter().map(|child| place(child, level + 1, layout)).collect::<Vec<_>>();
+    let column = match (children.first(), children.last()) {
+        (Some(first), Some(last)) => (layout.boxes[*first].0 + layout.boxes[*last].0) / 2.0,
+        _ => {
+            layout.columns += 1.0;
+            layout.columns - 1.0
+        },
+    };
+    let index = layout.boxes.len();
+    layout.boxes.push((column, level, node));
+    layout.edges.extend(children.into_iter().map(|child| (index, child)));
+    index
+}
+
+fn box_origin((column, level, _): &(f64, usize, &OrgChartNodeResponse)) -> (f64, f64) {
+    (
+        MARGIN + column * (NODE_WIDTH + HORIZONTAL_GAP),
+        MARGIN + *level as f64 * (NODE_HEIGHT + VERTICAL_GAP),
+    )
+}
+
+fn fill_color(node_type: EOrgChartNodeType) -> &'static str {
+    match node_type {
+        EOrgChartNodeType::Organization => "#cfe2f3",
+        EOrgChartNodeType::Department => "#d9ead3",
+        EOrgChartNodeType::Position => "#fff2cc",
+        EOrgChartNodeType::Employee => "#f3f3f3",
+    }
+}
+
+fn shorten(name: &str) -> String {
+    if name.chars().count() <= MAX_LABEL_CHARS {
+        return name.to_string();
+    }
+    let mut short = name.chars().take(MAX_LABEL_CHARS - 1).collect::<String>();
+    short.push('…');
+    short
+}
+
+fn escape_dot(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveTime, Utc};
+    use uuid::Uuid;
+
+    fn organization() -> OrganizationModel {
+        OrganizationModel {
+            id: 1,
+            organization_uuid: Uuid::new_v4(),
+            name: "Acme".to_string(),
+            description: String::new(),
+            default_language: "vi".to_string(),
+            timezone: "Asia/Ho_Chi_Minh".to_string(),
+            working_week: "mon,tue,wed,thu,fri".to_string(),
+            work_start_time: NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            work_end_time: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            late_grace_minutes: 0,
+            max_weekly_hours: 48,
+            currency: "VND".to_string(),
+            create_at: Utc::now(),
+            update_at: Utc::now(),
+        }
+    }
+
+    fn row(department_id: i64, parent: Option<i64>, name: &str, position: Option<i64>) -> Metadata {
+        Metadata {
+            organization_id: 1,
+            organization_name: "Acme".to_string(),
+            department_id,
+            parent_department_id: parent,
+            department_uuid: Uuid::new_v4(),
+            department_name: name.to_string(),
+            position_id: position,
+            position_uuid: position.map(|_| Uuid::new_v4()),
+            position_name: position.map(|id| format!("Position {id}")),
+        }
+    }
+
+    // Engineering holds a position and the Platform sub department, Support hangs below a
+    // department that is not part of the chart.
+    fn chart(depth: Option<u32>) -> OrgChartNodeResponse {
+        let rows = vec![
+            row(1, None, "Engineering", Some(10)),
+            row(2, Some(1), "Platform", None),
+            row(3, Some(99), "Support", None),
+        ];
+        let holders = [PositionHolder {
+            position_id: 10,
+            employee_uuid: Uuid::new_v4(),
+            full_name: "An".to_string(),
+        }];
+        build_org_chart(&organization(), rows, &holders, depth)
+    }
+
+    fn names(nodes: &[OrgChartNodeResponse]) -> Vec<&str> {
+        nodes.iter().map(|node| node.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_departments_nest_below_their_parent_after_its_positions() {
+        let root = chart(None);
+        assert_eq!(names(&root.children), vec!["Engineering", "Support"]);
+        let engineering = &root.children[0];
+        assert_eq!(names(&engineering.children), vec!["Position 10", "Platform"]);
+        assert_eq!(engineering.children[0].node_type, EOrgChartNodeType::Position);
+        assert_eq!(names(&engineering.children[0].children), vec!["An"]);
+    }
+
+    #[test]
+    fn test_department_with_an_inactive_parent_is_shown_below_the_organization() {
+        let root = chart(None);
+        let support = &root.children[1];
+        assert_eq!(support.name, "Support");
+        assert_eq!(support.node_type, EOrgChartNodeType::Department);
+        assert!(support.children.is_empty());
+    }
+
+    #[test]
+    fn test_depth_cuts_the_chart() {
+        assert!(chart(Some(0)).children.is_empty());
+        let root = chart(Some(1));
+        assert_eq!(names(&root.children), vec!["Engineering", "Support"]);
+        assert!(root.children.iter().all(|department| department.children.is_empty()));
+        let root = chart(Some(2));
+        assert_eq!(names(&root.children[0].children), vec!["Position 10", "Platform"]);
+        assert!(root.children[0].children[0].children.is_empty());
+    }
+
+    #[test]
+    fn test_escape_dot() {
+        assert_eq!(escape_dot(r#"R"&D \ <Lab>"#), r#"R\"&D \\ <Lab>"#);
+        assert_eq!(escape_dot("a\r\nb"), r"a\r\nb");
+        let mut root = chart(Some(0));
+        root.name = "Acme \"Inc\"\r\n".to_string();
+        assert!(render_dot(&root).contains(r#"label="Acme \"Inc\"\r\n""#));
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml(r#"R"&D \ <Lab>'"#), "R&quot;&amp;D \\ &lt;Lab&gt;&apos;");
+        let mut root = chart(Some(0));
+        root.name = "<script>".to_string();
+        let svg = render_svg(&root);
+        assert!(svg.contains("<title>&lt;script&gt;</title>"));
+        assert!(!svg.contains("<script>"));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::domain::organization::EWeekday;
+use chrono::NaiveTime;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateOrganizationRequest {
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+    #[validate(length(min = 2, max = 10))]
+    pub default_language: Option<String>,
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: Option<String>,
+    #[validate(length(min = 1, max = 7))]
+    pub working_week: Option<Vec<EWeekday>>,
+    // Working hours, the start must be before the end.
+    pub work_start_time: Option<NaiveTime>,
+    pub work_end_time: Option<NaiveTime>,
+    #[validate(range(min = 0, max = 240))]
+    pub late_grace_minutes: Option<i32>,
+    #[validate(range(min = 1, max = 168))]
+    pub max_weekly_hours: Option<i32>,
+    #[validate(custom(function = "validate_currency"))]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateOrganizationRequest {
+    pub organization_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+    #[validate(length(min = 2, max = 10))]
+    pub default_language: Option<String>,
+    #[validate(custom(function = "validate_timezone"))]
+    pub timezone: Option<String>,
+    #[validate(length(min = 1, max = 7))]
+    pub working_week: Option<Vec<EWeekday>>,
+    // Working hours, the start must be before the end.
+    pub work_start_time: Option<NaiveTime>,
+    pub work_end_time: Option<NaiveTime>,
+    #[validate(range(min = 0, max = 240))]
+    pub late_grace_minutes: Option<i32>,
+    #[validate(range(min = 1, max = 168))]
+    pub max_weekly_hours: Option<i32>,
+    #[validate(custom(function = "validate_currency"))]
+    pub currency: Option<String>,
+}
+
+// Timezone must be an IANA name, e.g. "Asia/Ho_Chi_Minh".
+fn validate_timezone(timezone: &str) -> Result<(), ValidationError> {
+    match timezone.parse::<Tz>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("timezone")),
+    }
+}
+
+// Currency must be an ISO 4217 code, e.g. "VND".
+fn validate_currency(currency: &str) -> Result<(), ValidationError> {
+    if currency.len() == 3 && currency.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("currency"))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EOrgChartFormat {
+    #[default]
+    Json,
+    Dot,
+    Svg,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema, IntoParams)]
+pub struct OrgChartQueryParam {
+    #[serde(default)]
+    pub format: EOrgChartFormat,
+    // Levels shown below the organization, a sub department is one level below its parent.
+    #[validate(range(min = 1))]
+    pub depth: Option<u32>,
+}
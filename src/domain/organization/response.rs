@@ -0,0 +1,127 @@
+use crate::domain::model::OrganizationModel;
+use crate::domain::organization::EWeekday;
+use crate::infrastructure::persistence::department_repo::Metadata;
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationSettingResponse {
+    pub default_language: String,
+    pub timezone: String,
+    pub working_week: Vec<EWeekday>,
+    pub work_start_time: NaiveTime,
+    pub work_end_time: NaiveTime,
+    pub late_grace_minutes: i32,
+    pub max_weekly_hours: i32,
+    pub currency: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationResponse {
+    pub organization_uuid: Uuid,
+    pub name: String,
+    pub description: String,
+    pub settings: OrganizationSettingResponse,
+    pub create_at: DateTime<Utc>,
+    pub update_at: DateTime<Utc>,
+}
+
+impl From<OrganizationModel> for OrganizationResponse {
+    fn from(organization: OrganizationModel) -> Self {
+        OrganizationResponse {
+            settings: OrganizationSettingResponse {
+                working_week: organization.working_days(),
+                work_start_time: organization.work_start_time,
+                work_end_time: organization.work_end_time,
+                late_grace_minutes: organization.late_grace_minutes,
+                max_weekly_hours: organization.max_weekly_hours,
+                default_language: organization.default_language,
+                timezone: organization.timezone,
+                currency: organization.currency,
+            },
+            organization_uuid: organization.organization_uuid,
+            name: organization.name,
+            description: organization.description,
+            create_at: organization.create_at,
+            update_at: organization.update_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PositionNodeResponse {
+    pub position_uuid: Uuid,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DepartmentNodeResponse {
+    pub department_uuid: Uuid,
+    pub name: String,
+    pub positions: Vec<PositionNodeResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationDetailResponse {
+    pub organization: OrganizationResponse,
+    pub departments: Vec<DepartmentNodeResponse>,
+}
+
+impl OrganizationDetailResponse {
+    // `rows` are ordered by department, so positions of a department are adjacent.
+    pub fn new(organization: OrganizationModel, rows: Vec<Metadata>) -> Self {
+        let mut departments: Vec<DepartmentNodeResponse> = Vec::new();
+        for row in rows {
+            if departments.last().map(|d| d.department_uuid) != Some(row.department_uuid) {
+                departments.push(DepartmentNodeResponse {
+                    department_uuid: row.department_uuid,
+                    name: row.department_name,
+                    positions: Vec::new(),
+                });
+            }
+            if let (Some(position_uuid), Some(name)) = (row.position_uuid, row.position_name) {
+                if let Some(department) = departments.last_mut() {
+                    department.positions.push(PositionNodeResponse { position_uuid, name });
+                }
+            }
+        }
+        OrganizationDetailResponse { organization: organization.into(), departments }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EOrgChartNodeType {
+    Organization,
+    Department,
+    Position,
+    Employee,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrgChartNodeResponse {
+    pub node_type: EOrgChartNodeType,
+    pub uuid: Uuid,
+    pub name: String,
+    #[schema(no_recursion)]
+    pub children: Vec<OrgChartNodeResponse>,
+}
+
+impl OrgChartNodeResponse {
+    pub fn new(node_type: EOrgChartNodeType, uuid: Uuid, name: String) -> Self {
+        OrgChartNodeResponse { node_type, uuid, name, children: Vec::new() }
+    }
+
+    // Drops every node more than `depth` levels below this one.
+    pub fn truncate(&mut self, depth: u32) {
+        if depth == 0 {
+            self.children.clear();
+            return;
+        }
+        for child in &mut self.children {
+            child.truncate(depth - 1);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::domain::entity::{DepartmentEntity, EmployeeEntity, OrganizationEntity};
+use crate::domain::model::OrganizationModel;
+use crate::domain::organization::org_chart::build_org_chart;
+use crate::domain::organization::request::{CreateOrganizationRequest, UpdateOrganizationRequest};
+use crate::domain::organization::response::{
+    OrgChartNodeResponse, OrganizationDetailResponse, OrganizationResponse,
+};
+use crate::domain::organization::EWeekday;
+use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
+use crate::util::constant::{
+    DEFAULT_CURRENCY, DEFAULT_LANGUAGE, DEFAULT_TIMEZONE, DEFAULT_WORKING_WEEK,
+    DEFAULT_MAX_WEEKLY_HOURS, DEFAULT_WORK_END_HOUR, DEFAULT_WORK_START_HOUR,
+};
+use crate::util::filter_and_pagination::{PageQueryParam, Paginated};
+use chrono::{NaiveTime, Utc};
+use sea_orm::{ConnectionTrait, DatabaseTransaction, TransactionTrait};
+use std::fmt::Debug;
+use uuid::Uuid;
+
+pub async fn service_create_organization(
+    state: &AppState,
+    req: CreateOrganizationRequest,
+) -> AppResult<OrganizationResponse> {
+    tracing::info!("Create new organization request: {req:?}.");
+    let tx = state.db.begin().await?;
+    check_unique_name(&tx, &req.name, None).await?;
+
+    let organization_model = OrganizationModel {
+        id: Default::default(),
+        organization_uuid: Uuid::new_v4(),
+        name: req.name,
+        description: req.description.unwrap_or_default(),
+        default_language: req.default_language.unwrap_or(DEFAULT_LANGUAGE.to_string()),
+        timezone: req.timezone.unwrap_or(DEFAULT_TIMEZONE.to_string()),
+        working_week: req
+            .working_week
+            .map(|days| EWeekday::join(&days))
+            .unwrap_or(DEFAULT_WORKING_WEEK.to_string()),
+        work_start_time: req.work_start_time.unwrap_or_else(|| {
+            NaiveTime::from_hms_opt(DEFAULT_WORK_START_HOUR, 0, 0).unwrap_or_default()
+        }),
+        work_end_time: req.work_end_time.unwrap_or_else(|| {
+            NaiveTime::from_hms_opt(DEFAULT_WORK_END_HOUR, 0, 0).unwrap_or_default()
+        }),
+        late_grace_minutes: req.late_grace_minutes.unwrap_or_default(),
+        max_weekly_hours: req.max_weekly_hours.unwrap_or(DEFAULT_MAX_WEEKLY_HOURS),
+        currency: req.currency.unwrap_or(DEFAULT_CURRENCY.to_string()),
+        create_at: Utc::now(),
+        update_at: Utc::now(),
+    };
+    check_working_hours(&organization_model)?;
+    let organization_id = OrganizationEntity::create(&tx, &organization_model)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Organization".to_string() })?;
+    tx.commit().await?;
+    Ok(OrganizationModel { id: organization_id, ..organization_model }.into())
+}
+
+pub async fn service_get_organization(
+    state: &AppState,
+    organization_uuid: Uuid,
+) -> AppResult<OrganizationDetailResponse> {
+    tracing::info!("Get organization with uuid: {organization_uuid}");
+    let organization = find_organization_by_uuid(&*state.db, &organization_uuid).await?;
+    let rows = DepartmentEntity::get_important_information(&*state.db, organization_uuid)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    Ok(OrganizationDetailResponse::new(organization, rows))
+}
+
+pub async fn service_get_org_chart(
+    state: &AppState,
+    organization_uuid: Uuid,
+    depth: Option<u32>,
+) -> AppResult<OrgChartNodeResponse> {
+    tracing::info!("Get org chart of organization {organization_uuid} with depth {depth:?}");
+    let organization = find_organization_by_uuid(&*state.db, &organization_uuid).await?;
+    let rows = DepartmentEntity::get_important_information(&*state.db, organization_uuid)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let position_ids = rows.iter().filter_map(|row| row.position_id).collect::<Vec<_>>();
+    let holders =
+        EmployeeEntity::find_position_holders(&*state.db, &position_ids, Utc::now().date_naive())
+            .await
+            .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    Ok(build_org_chart(&organization, rows, &holders, depth))
+}
+
+pub async fn service_get_list_organization(
+    state: &AppState,
+    param: PageQueryParam,
+) -> AppResult<Paginated<OrganizationResponse>> {
+    tracing::info!("Get organization list with parameter: {param:?}");
+    let organizations = OrganizationEntity::find_all(&*state.db, param).await?;
+    Ok(organizations.map(OrganizationResponse::from))
+}
+
+pub async fn service_update_organization(
+    state: &AppState,
+    req: UpdateOrganizationRequest,
+) -> AppResult<OrganizationResponse> {
+    tracing::info!("Update organization request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut organization = find_organization_by_uuid(&tx, &req.organization_uuid).await?;
+
+    if let Some(name) = req.name {
+        check_unique_name(&tx, &name, Some(organization.id)).await?;
+        organization.name = name;
+    }
+    if let Some(description) = req.description {
+        organization.description = description;
+    }
+    if let Some(default_language) = req.default_language {
+        organization.default_language = default_language;
+    }
+    if let Some(timezone) = req.timezone {
+        organization.timezone = timezone;
+    }
+    if let Some(working_week) = req.working_week {
+        organization.working_week = EWeekday::join(&working_week);
+    }
+    if let Some(work_start_time) = req.work_start_time {
+        organization.work_start_time = work_start_time;
+    }
+    if let Some(work_end_time) = req.work_end_time {
+        organization.work_end_time = work_end_time;
+    }
+    if let Some(late_grace_minutes) = req.late_grace_minutes {
+        organization.late_grace_minutes = late_grace_minutes;
+    }
+    if let Some(max_weekly_hours) = req.max_weekly_hours {
+        organization.max_weekly_hours = max_weekly_hours;
+    }
+    if let Some(currency) = req.currency {
+        organization.currency = currency;
+    }
+    check_working_hours(&organization)?;
+    organization.update_at = Utc::now();
+
+    OrganizationEntity::update(&tx, organization.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Organization".to_string() })?;
+    tx.commit().await?;
+    Ok(organization.into())
+}
+
+pub async fn find_organization_by_uuid<DB>(
+    conn: &DB,
+    organization_uuid: &Uuid,
+) -> AppResult<OrganizationModel>
+where
+    DB: ConnectionTrait + Debug,
+{
+    OrganizationEntity::find_data_by_uuid(conn, organization_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError {
+            detail: format!("Organization not found by uuid {organization_uuid}"),
+        }
+    })
+}
+
+async fn check_unique_name(
+    tx: &DatabaseTransaction,
+    name: &str,
+    organization_id: Option<i64>,
+) -> AppResult {
+    match OrganizationEntity::find_data_by_name(tx, name).await {
+        Some(exists) if Some(exists.id) != organization_id => Err(AppError::EntityExistsError {
+            detail: "Organization name is already exists!".to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn check_working_hours(organization: &OrganizationModel) -> AppResult {
+    if organization.work_start_time >= organization.work_end_time {
+        return Err(AppError::BadRequestError(
+            "Working hours must start before they end".to_string(),
+        ));
+    }
+    Ok(())
+}
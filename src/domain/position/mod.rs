@@ -7,7 +7,7 @@ use crate::domain::entity::{DepartmentEntity, EmployeeEntity, PositionEntity};
 
 pub mod request;
 pub mod response;
-mod services;
+pub mod services;
 
 #[derive(
     Debug, PartialEq, Eq, PartialOrd, Ord, Clone, DeriveEntityModel, Deserialize, Serialize,
@@ -20,7 +20,7 @@ pub struct Model {
     pub position_uuid: Uuid,
     #[sea_orm()]
     pub department_id: i64,
-    #[sea_orm(indexd, unique)]
+    #[sea_orm(indexed)]
     pub name: String,
     #[sea_orm(nulable)]
     pub description: String,
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreatePositionRequest {
+    pub department_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdatePositionRequest {
+    pub position_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+    #[validate(range(min = 0, max = 1))]
+    pub status: Option<i16>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct DeactivatePositionRequest {
+    pub position_uuid: Uuid,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams)]
+pub struct PositionQueryParam {
+    pub department_uuid: Uuid,
+}
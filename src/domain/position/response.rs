@@ -0,0 +1,30 @@
+use crate::domain::model::PositionModel;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PositionResponse {
+    pub position_uuid: Uuid,
+    pub department_uuid: Uuid,
+    pub name: String,
+    pub description: String,
+    pub status: Option<i16>,
+    pub create_at: DateTime<Utc>,
+    pub update_at: DateTime<Utc>,
+}
+
+impl PositionResponse {
+    pub fn new(position: PositionModel, department_uuid: Uuid) -> Self {
+        PositionResponse {
+            position_uuid: position.position_uuid,
+            department_uuid,
+            name: position.name,
+            description: position.description,
+            status: position.status,
+            create_at: position.create_at,
+            update_at: position.update_at,
+        }
+    }
+}
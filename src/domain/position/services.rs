@@ -0,0 +1,170 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::domain::audit_log::services::record_audit_log;
+use crate::domain::audit_log::{AuditContext, EAuditAction, EAuditEntity};
+use crate::domain::department::services::find_department_by_uuid;
+use crate::domain::entity::{DepartmentEntity, PositionEntity};
+use crate::domain::model::PositionModel;
+use crate::domain::position::request::{
+    CreatePositionRequest, DeactivatePositionRequest, UpdatePositionRequest,
+};
+use crate::domain::position::response::PositionResponse;
+use crate::infrastructure::persistence::repo_interface::{
+    DeleteRepository, ReadRepository, WriteRepository,
+};
+use crate::util::filter_and_pagination::{PageQueryParam, Paginated};
+use chrono::Utc;
+use sea_orm::{DatabaseTransaction, TransactionTrait};
+use uuid::Uuid;
+
+pub async fn service_create_position(
+    state: &AppState,
+    context: &AuditContext,
+    req: CreatePositionRequest,
+) -> AppResult<PositionResponse> {
+    tracing::info!("Create new position request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let department = find_department_by_uuid(&tx, &req.department_uuid).await?;
+    if department.status != Some(1) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!("Department is not active {}", req.department_uuid),
+        });
+    }
+    check_unique_name(&tx, department.id, &req.name, None).await?;
+
+    let position_model = PositionModel {
+        id: Default::default(),
+        position_uuid: Uuid::new_v4(),
+        department_id: department.id,
+        name: req.name,
+        description: req.description.unwrap_or_default(),
+        status: Some(1),
+        create_at: Utc::now(),
+        update_at: Utc::now(),
+    };
+    let position_id = PositionEntity::create(&tx, &position_model)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Position".to_string() })?;
+    let position_model = PositionModel { id: position_id, ..position_model };
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::Position,
+        position_model.position_uuid,
+        None,
+        Some(&position_model),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(PositionResponse::new(position_model, department.department_uuid))
+}
+
+pub async fn service_get_list_position(
+    state: &AppState,
+    department_uuid: Uuid,
+    param: PageQueryParam,
+) -> AppResult<Paginated<PositionResponse>> {
+    tracing::info!("Get position list of department {department_uuid}: {param:?}");
+    let department = find_department_by_uuid(&*state.db, &department_uuid).await?;
+    let positions = PositionEntity::find_by_department(&*state.db, department.id, param).await?;
+    Ok(positions.map(|position| PositionResponse::new(position, department_uuid)))
+}
+
+pub async fn service_update_position(
+    state: &AppState,
+    context: &AuditContext,
+    req: UpdatePositionRequest,
+) -> AppResult<PositionResponse> {
+    tracing::info!("Update position request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut position = find_position_by_uuid(&tx, &req.position_uuid).await?;
+    let before = position.clone();
+    let department = DepartmentEntity::find_data_by_id(&tx, position.department_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Department not found".to_string(),
+        })?;
+
+    if let Some(name) = req.name {
+        position.name = name;
+    }
+    if let Some(description) = req.description {
+        position.description = description;
+    }
+    if let Some(status) = req.status {
+        position.status = Some(status);
+    }
+    if position.status == Some(1) {
+        check_unique_name(&tx, department.id, &position.name, Some(position.id)).await?;
+    }
+    position.update_at = Utc::now();
+
+    PositionEntity::update(&tx, position.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Position".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::Position,
+        position.position_uuid,
+        Some(&before),
+        Some(&position),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(PositionResponse::new(position, department.department_uuid))
+}
+
+pub async fn service_deactivate_position(
+    state: &AppState,
+    context: &AuditContext,
+    req: DeactivatePositionRequest,
+) -> AppResult<i64> {
+    tracing::info!("Deactivate position request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let before = find_position_by_uuid(&tx, &req.position_uuid).await?;
+    let position_id = PositionEntity::delete_data(&tx, req.position_uuid)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Can not deactivate position".to_string()))?;
+    let after = find_position_by_uuid(&tx, &req.position_uuid).await?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Delete,
+        EAuditEntity::Position,
+        req.position_uuid,
+        Some(&before),
+        Some(&after),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(position_id)
+}
+
+async fn find_position_by_uuid(
+    tx: &DatabaseTransaction,
+    position_uuid: &Uuid,
+) -> AppResult<PositionModel> {
+    PositionEntity::find_data_by_uuid(tx, position_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError {
+            detail: format!("Position not found by uuid {position_uuid}"),
+        }
+    })
+}
+
+// Position names are unique inside a department, `position_id` is the position being renamed.
+async fn check_unique_name(
+    tx: &DatabaseTransaction,
+    department_id: i64,
+    name: &str,
+    position_id: Option<i64>,
+) -> AppResult {
+    match PositionEntity::find_by_department_and_name(tx, department_id, name).await {
+        Some(exists) if Some(exists.id) != position_id => Err(AppError::EntityExistsError {
+            detail: "Position name is already exists in this department!".to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,48 @@
+use crate::domain::entity::{PermissionEntity, RoleEntity};
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
+use serde::{Deserialize, Serialize};
+
+pub mod permission;
+pub mod request;
+pub mod response;
+pub mod role_permission;
+pub mod services;
+
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Clone, DeriveEntityModel, Deserialize, Serialize,
+)]
+#[sea_orm(table_name = "roles", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique, indexed)]
+    pub role_uuid: Uuid,
+    #[sea_orm(unique, indexed)]
+    pub name: String,
+    #[sea_orm()]
+    pub description: String,
+    #[sea_orm(nullable, default_value = 1)]
+    pub status: Option<i16>,
+    #[sea_orm()]
+    pub create_at: NaiveDateTime,
+    #[sea_orm()]
+    pub update_at: NaiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl Related<PermissionEntity> for RoleEntity {
+    fn to() -> RelationDef {
+        role_permission::Relation::Permission.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(role_permission::Relation::Role.def().rev())
+    }
+}
@@ -0,0 +1,142 @@
+use crate::domain::entity::{PermissionEntity, RoleEntity};
+use crate::domain::role::role_permission;
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Clone, DeriveEntityModel, Deserialize, Serialize,
+)]
+#[sea_orm(table_name = "permissions", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique, indexed)]
+    pub permission_uuid: Uuid,
+    #[sea_orm(unique, indexed)]
+    pub code: String,
+    #[sea_orm()]
+    pub description: String,
+    #[sea_orm()]
+    pub create_at: NaiveDateTime,
+    #[sea_orm()]
+    pub update_at: NaiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl Related<RoleEntity> for PermissionEntity {
+    fn to() -> RelationDef {
+        role_permission::Relation::Role.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(role_permission::Relation::Permission.def().rev())
+    }
+}
+
+// The code of every permission checked by the application, stored in `permissions.code`.
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Deserialize,
+    Serialize,
+    utoipa::ToSchema,
+    Clone,
+    Copy,
+    Hash,
+    strum::EnumString,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum EPermission {
+    #[serde(rename = "user:create")]
+    #[strum(serialize = "user:create")]
+    UserCreate,
+    #[serde(rename = "user:read")]
+    #[strum(serialize = "user:read")]
+    UserRead,
+    #[serde(rename = "user:update")]
+    #[strum(serialize = "user:update")]
+    UserUpdate,
+    #[serde(rename = "employee:create")]
+    #[strum(serialize = "employee:create")]
+    EmployeeCreate,
+    #[serde(rename = "employee:read")]
+    #[strum(serialize = "employee:read")]
+    EmployeeRead,
+    #[serde(rename = "employee:update")]
+    #[strum(serialize = "employee:update")]
+    EmployeeUpdate,
+    #[serde(rename = "employee:delete")]
+    #[strum(serialize = "employee:delete")]
+    EmployeeDelete,
+    #[serde(rename = "role:read")]
+    #[strum(serialize = "role:read")]
+    RoleRead,
+    #[serde(rename = "role:write")]
+    #[strum(serialize = "role:write")]
+    RoleWrite,
+    #[serde(rename = "department:read")]
+    #[strum(serialize = "department:read")]
+    DepartmentRead,
+    #[serde(rename = "department:write")]
+    #[strum(serialize = "department:write")]
+    DepartmentWrite,
+    #[serde(rename = "position:read")]
+    #[strum(serialize = "position:read")]
+    PositionRead,
+    #[serde(rename = "position:write")]
+    #[strum(serialize = "position:write")]
+    PositionWrite,
+    #[serde(rename = "organization:read")]
+    #[strum(serialize = "organization:read")]
+    OrganizationRead,
+    #[serde(rename = "organization:write")]
+    #[strum(serialize = "organization:write")]
+    OrganizationWrite,
+    #[serde(rename = "audit_log:read")]
+    #[strum(serialize = "audit_log:read")]
+    AuditLogRead,
+    #[serde(rename = "leave:read")]
+    #[strum(serialize = "leave:read")]
+    LeaveRead,
+    #[serde(rename = "leave:write")]
+    #[strum(serialize = "leave:write")]
+    LeaveWrite,
+    #[serde(rename = "leave:request")]
+    #[strum(serialize = "leave:request")]
+    LeaveRequest,
+    #[serde(rename = "leave:approve")]
+    #[strum(serialize = "leave:approve")]
+    LeaveApprove,
+    #[serde(rename = "attendance:read")]
+    #[strum(serialize = "attendance:read")]
+    AttendanceRead,
+    #[serde(rename = "attendance:record")]
+    #[strum(serialize = "attendance:record")]
+    AttendanceRecord,
+    #[serde(rename = "attendance:manage")]
+    #[strum(serialize = "attendance:manage")]
+    AttendanceManage,
+    #[serde(rename = "shift:read")]
+    #[strum(serialize = "shift:read")]
+    ShiftRead,
+    #[serde(rename = "shift:write")]
+    #[strum(serialize = "shift:write")]
+    ShiftWrite,
+    #[serde(rename = "calendar:read")]
+    #[strum(serialize = "calendar:read")]
+    CalendarRead,
+    #[serde(rename = "calendar:write")]
+    #[strum(serialize = "calendar:write")]
+    CalendarWrite,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateRoleRequest {
+    #[validate(length(min = 2, max = 50))]
+    pub name: String,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+    pub permission_uuids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateRoleRequest {
+    pub role_uuid: Uuid,
+    #[validate(length(min = 2, max = 50))]
+    pub name: Option<String>,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+    #[validate(range(min = 0, max = 1))]
+    pub status: Option<i16>,
+    pub permission_uuids: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct DeleteRoleRequest {
+    pub role_uuid: Uuid,
+}
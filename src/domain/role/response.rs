@@ -0,0 +1,47 @@
+use crate::domain::model::{PermissionModel, RoleModel};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PermissionResponse {
+    pub permission_uuid: Uuid,
+    pub code: String,
+    pub description: String,
+}
+
+impl From<PermissionModel> for PermissionResponse {
+    fn from(permission: PermissionModel) -> Self {
+        PermissionResponse {
+            permission_uuid: permission.permission_uuid,
+            code: permission.code,
+            description: permission.description,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RoleResponse {
+    pub role_uuid: Uuid,
+    pub name: String,
+    pub description: String,
+    pub status: Option<i16>,
+    pub permissions: Vec<PermissionResponse>,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl RoleResponse {
+    pub fn new(role: RoleModel, permissions: Vec<PermissionModel>) -> Self {
+        RoleResponse {
+            role_uuid: role.role_uuid,
+            name: role.name,
+            description: role.description,
+            status: role.status,
+            permissions: permissions.into_iter().map(PermissionResponse::from).collect(),
+            create_at: role.create_at,
+            update_at: role.update_at,
+        }
+    }
+}
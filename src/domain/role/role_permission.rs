@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use sea_orm::DerivePrimaryKey;
+use sea_orm::EntityTrait;
+use sea_orm::PrimaryKeyTrait;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel, DeriveRelation, EnumIter};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, Clone, DeriveEntityModel, Deserialize, Serialize,
+)]
+#[sea_orm(table_name = "role_permission", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub role_id: i64,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub permission_id: i64,
+    pub create_at: NaiveDateTime,
+}
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::domain::role::Entity",
+        from = "Column::RoleId",
+        to = "crate::domain::role::Column::Id"
+    )]
+    Role,
+    #[sea_orm(
+        belongs_to = "crate::domain::role::permission::Entity",
+        from = "Column::PermissionId",
+        to = "crate::domain::role::permission::Column::Id"
+    )]
+    Permission,
+}
@@ -0,0 +1,170 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::domain::entity::{PermissionEntity, RoleEntity};
+use crate::domain::model::{PermissionModel, RoleModel};
+use crate::domain::role::request::{CreateRoleRequest, DeleteRoleRequest, UpdateRoleRequest};
+use crate::domain::role::response::{PermissionResponse, RoleResponse};
+use crate::infrastructure::persistence::repo_interface::{
+    DeleteRepository, ReadRepository, WriteRepository,
+};
+use crate::util::filter_and_pagination::{PageQueryParam, Paginated};
+use chrono::Utc;
+use sea_orm::{ConnectionTrait, DatabaseTransaction, TransactionTrait};
+use std::fmt::Debug;
+use uuid::Uuid;
+
+pub async fn service_create_role(
+    state: &AppState,
+    req: CreateRoleRequest,
+) -> AppResult<RoleResponse> {
+    tracing::info!("Create new role request: {req:?}.");
+    let tx = state.db.begin().await?;
+    if RoleEntity::find_data_by_name(&tx, &req.name).await.is_some() {
+        return Err(AppError::EntityExistsError { detail: "Role name is already exists!".to_string() });
+    }
+    let permissions = find_permissions_by_uuids(&tx, &req.permission_uuids).await?;
+
+    let role_model = RoleModel {
+        id: Default::default(),
+        role_uuid: Uuid::new_v4(),
+        name: req.name,
+        description: req.description.unwrap_or_default(),
+        status: Some(1),
+        create_at: Utc::now().naive_utc(),
+        update_at: Utc::now().naive_utc(),
+    };
+    let role_id = RoleEntity::create(&tx, &role_model)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Role".to_string() })?;
+    RoleEntity::replace_permissions(&tx, role_id, &permissions)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Role permission".to_string() })?;
+    tx.commit().await?;
+    Ok(RoleResponse::new(RoleModel { id: role_id, ..role_model }, permissions))
+}
+
+pub async fn service_get_role(state: &AppState, role_uuid: Uuid) -> AppResult<RoleResponse> {
+    tracing::info!("Get role with uuid: {role_uuid}");
+    let role = RoleEntity::find_data_by_uuid(&*state.db, &role_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: format!("Role not found by uuid {role_uuid}") }
+    })?;
+    let permissions = RoleEntity::find_permissions(&*state.db, &role)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    Ok(RoleResponse::new(role, permissions))
+}
+
+pub async fn service_get_list_role(
+    state: &AppState,
+    param: PageQueryParam,
+) -> AppResult<Paginated<RoleResponse>> {
+    tracing::info!("Get role list with parameter: {param:?}");
+    let roles = RoleEntity::find_all(&*state.db, param).await?;
+    let mut results = Vec::with_capacity(roles.items.len());
+    for role in roles.items {
+        let permissions = RoleEntity::find_permissions(&*state.db, &role)
+            .await
+            .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+        results.push(RoleResponse::new(role, permissions));
+    }
+    Ok(Paginated { items: results, pagination: roles.pagination })
+}
+
+pub async fn service_update_role(
+    state: &AppState,
+    req: UpdateRoleRequest,
+) -> AppResult<RoleResponse> {
+    tracing::info!("Update role request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut role = RoleEntity::find_data_by_uuid(&tx, &req.role_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError { detail: format!("Role not found by uuid {}", req.role_uuid) }
+    })?;
+
+    if let Some(name) = req.name {
+        if let Some(exists) = RoleEntity::find_data_by_name(&tx, &name).await {
+            if exists.id != role.id {
+                return Err(AppError::EntityExistsError {
+                    detail: "Role name is already exists!".to_string(),
+                });
+            }
+        }
+        role.name = name;
+    }
+    if let Some(description) = req.description {
+        role.description = description;
+    }
+    if let Some(status) = req.status {
+        role.status = Some(status);
+    }
+    role.update_at = Utc::now().naive_utc();
+
+    if let Some(permission_uuids) = req.permission_uuids {
+        let permissions = find_permissions_by_uuids(&tx, &permission_uuids).await?;
+        RoleEntity::replace_permissions(&tx, role.id, &permissions).await.ok_or_else(|| {
+            AppError::EntityNotAvailableError { detail: "Role permission".to_string() }
+        })?;
+    }
+    RoleEntity::update(&tx, role.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Role".to_string() })?;
+    let permissions = RoleEntity::find_permissions(&tx, &role)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    tx.commit().await?;
+    Ok(RoleResponse::new(role, permissions))
+}
+
+pub async fn service_delete_role(state: &AppState, req: DeleteRoleRequest) -> AppResult<i64> {
+    tracing::info!("Delete role request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let role_id = RoleEntity::delete_data(&tx, req.role_uuid)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Can not delete role".to_string()))?;
+    tx.commit().await?;
+    Ok(role_id)
+}
+
+pub async fn service_get_list_permission(state: &AppState) -> AppResult<Vec<PermissionResponse>> {
+    tracing::info!("Get all permissions");
+    match PermissionEntity::find_all_permissions(&*state.db).await {
+        Some(result) => Ok(result.into_iter().map(PermissionResponse::from).collect()),
+        None => Err(AppError::BadRequestError("Something went wrong!".to_string())),
+    }
+}
+
+async fn find_permissions_by_uuids<DB>(
+    conn: &DB,
+    permission_uuids: &[Uuid],
+) -> AppResult<Vec<PermissionModel>>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let permissions = PermissionEntity::find_by_uuids(conn, permission_uuids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    if let Some(missing) = permission_uuids
+        .iter()
+        .find(|uuid| !permissions.iter().any(|permission| permission.permission_uuid == **uuid))
+    {
+        return Err(AppError::EntityNotFoundError {
+            detail: format!("Permission not found by uuid {missing}"),
+        });
+    }
+    Ok(permissions)
+}
+
+// Used by account creation to map the requested role to `users.role_id`.
+pub async fn find_active_role_by_uuid(
+    tx: &DatabaseTransaction,
+    role_uuid: &Uuid,
+) -> AppResult<RoleModel> {
+    match RoleEntity::find_data_by_uuid(tx, role_uuid).await {
+        Some(role) if role.status == Some(1) => Ok(role),
+        Some(_) => Err(AppError::EntityNotAvailableError {
+            detail: format!("Role is not active {role_uuid}"),
+        }),
+        None => Err(AppError::EntityNotFoundError {
+            detail: format!("Role not found by uuid {role_uuid}"),
+        }),
+    }
+}
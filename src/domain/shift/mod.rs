@@ -0,0 +1,98 @@
+use crate::domain::entity::{DepartmentEntity, ShiftAssignmentEntity, ShiftTemplateEntity};
+use crate::domain::organization::EWeekday;
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub mod request;
+pub mod response;
+pub mod roster;
+pub mod services;
+pub mod shift_assignment;
+
+// Shift a department runs on some days of the week, employees are assigned to it over a range
+// of days.
+#[derive(Debug, PartialEq, Clone, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "shift_templates", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique, indexed)]
+    pub shift_template_uuid: Uuid,
+    #[sea_orm(indexed)]
+    pub department_id: i64,
+    #[sea_orm()]
+    pub name: String,
+    // A shift ending before it starts ends the next day.
+    #[sea_orm()]
+    pub start_time: NaiveTime,
+    #[sea_orm()]
+    pub end_time: NaiveTime,
+    // Unpaid minutes inside the shift.
+    #[sea_orm(default_value = 0)]
+    pub break_minutes: i32,
+    // Comma separated days the shift starts on, e.g. "mon,tue,wed,thu,fri".
+    #[sea_orm()]
+    pub days_of_week: String,
+    #[sea_orm(nullable, default_value = 1)]
+    pub status: Option<i16>,
+    #[sea_orm()]
+    pub create_at: NaiveDateTime,
+    #[sea_orm()]
+    pub update_at: NaiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::department::Entity",
+        from = "Column::DepartmentId",
+        to = "super::department::Column::Id"
+    )]
+    Department,
+    #[sea_orm(has_many = "shift_assignment::Entity")]
+    ShiftAssignment,
+}
+
+impl Related<DepartmentEntity> for ShiftTemplateEntity {
+    fn to() -> RelationDef {
+        Relation::Department.def()
+    }
+}
+
+impl Related<ShiftAssignmentEntity> for ShiftTemplateEntity {
+    fn to() -> RelationDef {
+        Relation::ShiftAssignment.def()
+    }
+}
+
+impl Model {
+    pub fn days(&self) -> Vec<EWeekday> {
+        self.days_of_week.split(',').filter_map(|day| EWeekday::from_str(day.trim()).ok()).collect()
+    }
+
+    pub fn runs_on(&self, date: NaiveDate) -> bool {
+        self.days().into_iter().map(Weekday::from).any(|day| day == date.weekday())
+    }
+
+    // Start and end of the shift starting on the given day.
+    pub fn occurrence(&self, date: NaiveDate) -> (NaiveDateTime, NaiveDateTime) {
+        let end_date = if self.end_time <= self.start_time {
+            date.checked_add_days(Days::new(1)).unwrap_or(date)
+        } else {
+            date
+        };
+        (date.and_time(self.start_time), end_date.and_time(self.end_time))
+    }
+
+    // Minutes of work of one shift, without the break.
+    pub fn worked_minutes(&self) -> i64 {
+        let (start, end) = self.occurrence(NaiveDate::MIN);
+        (end - start).num_minutes() - i64::from(self.break_minutes)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::domain::organization::EWeekday;
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateShiftTemplateRequest {
+    pub department_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+    // A shift ending before it starts ends the next day.
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    #[validate(range(min = 0, max = 480))]
+    pub break_minutes: Option<i32>,
+    #[validate(length(min = 1, max = 7))]
+    pub days_of_week: Vec<EWeekday>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct UpdateShiftTemplateRequest {
+    pub shift_template_uuid: Uuid,
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    #[validate(range(min = 0, max = 480))]
+    pub break_minutes: Option<i32>,
+    #[validate(length(min = 1, max = 7))]
+    pub days_of_week: Option<Vec<EWeekday>>,
+    #[validate(range(min = 0, max = 1))]
+    pub status: Option<i16>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams)]
+pub struct ShiftTemplateQueryParam {
+    pub department_uuid: Uuid,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct CreateShiftAssignmentRequest {
+    pub shift_template_uuid: Uuid,
+    pub employee_uuid: Uuid,
+    // First and last day the employee works the shift.
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, Serialize, Validate, ToSchema)]
+pub struct DeleteShiftAssignmentRequest {
+    pub shift_assignment_uuid: Uuid,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, ToSchema, IntoParams)]
+pub struct RosterQueryParam {
+    // Any day of the week, the roster starts on its monday. The current week by default.
+    pub week_of: Option<NaiveDate>,
+}
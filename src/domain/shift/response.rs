@@ -0,0 +1,120 @@
+use crate::domain::model::{ShiftAssignmentModel, ShiftTemplateModel};
+use crate::domain::organization::EWeekday;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ShiftTemplateResponse {
+    pub shift_template_uuid: Uuid,
+    pub department_uuid: Uuid,
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub break_minutes: i32,
+    pub worked_minutes: i64,
+    pub days_of_week: Vec<EWeekday>,
+    pub status: Option<i16>,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl ShiftTemplateResponse {
+    pub fn new(shift_template: ShiftTemplateModel, department_uuid: Uuid) -> Self {
+        ShiftTemplateResponse {
+            shift_template_uuid: shift_template.shift_template_uuid,
+            department_uuid,
+            worked_minutes: shift_template.worked_minutes(),
+            days_of_week: shift_template.days(),
+            name: shift_template.name,
+            start_time: shift_template.start_time,
+            end_time: shift_template.end_time,
+            break_minutes: shift_template.break_minutes,
+            status: shift_template.status,
+            create_at: shift_template.create_at,
+            update_at: shift_template.update_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ShiftAssignmentResponse {
+    pub shift_assignment_uuid: Uuid,
+    pub shift_template_uuid: Uuid,
+    pub employee_uuid: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub status: Option<i16>,
+    pub create_at: NaiveDateTime,
+    pub update_at: NaiveDateTime,
+}
+
+impl ShiftAssignmentResponse {
+    pub fn new(
+        assignment: ShiftAssignmentModel,
+        shift_template_uuid: Uuid,
+        employee_uuid: Uuid,
+    ) -> Self {
+        ShiftAssignmentResponse {
+            shift_assignment_uuid: assignment.shift_assignment_uuid,
+            shift_template_uuid,
+            employee_uuid,
+            start_date: assignment.start_date,
+            end_date: assignment.end_date,
+            status: assignment.status,
+            create_at: assignment.create_at,
+            update_at: assignment.update_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EShiftConflictKind {
+    // Another shift of the employee overlaps.
+    Overlap,
+    // The employee has a pending or approved leave.
+    Leave,
+    // The week goes over the most weekly hours of the organization.
+    WeeklyHours,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShiftConflictResponse {
+    pub kind: EShiftConflictKind,
+    // Day of the shift, the monday of the week for weekly hours.
+    pub date: NaiveDate,
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RosterEmployeeResponse {
+    pub employee_uuid: Uuid,
+    pub full_name: String,
+    // An approved leave covers the day.
+    pub on_leave: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RosterShiftResponse {
+    pub shift_template_uuid: Uuid,
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub employees: Vec<RosterEmployeeResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RosterDayResponse {
+    pub date: NaiveDate,
+    pub shifts: Vec<RosterShiftResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RosterResponse {
+    pub department_uuid: Uuid,
+    pub week_start: NaiveDate,
+    pub week_end: NaiveDate,
+    pub days: Vec<RosterDayResponse>,
+}
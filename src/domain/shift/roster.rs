@@ -0,0 +1,229 @@
+use crate::domain::model::{ShiftAssignmentModel, ShiftTemplateModel};
+use crate::domain::shift::response::{EShiftConflictKind, ShiftConflictResponse};
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime};
+use std::collections::BTreeMap;
+
+// One shift worked by an employee.
+#[derive(Debug, Clone)]
+pub struct ShiftOccurrence {
+    pub date: NaiveDate,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub worked_minutes: i64,
+    pub name: String,
+}
+
+// Shifts of the assignment starting between both days, both included.
+pub fn occurrences(
+    shift_template: &ShiftTemplateModel,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<ShiftOccurrence> {
+    let (first, last) = (start_date.max(from), end_date.min(to));
+    first
+        .iter_days()
+        .take_while(|day| *day <= last)
+        .filter(|day| shift_template.runs_on(*day))
+        .map(|day| {
+            let (start, end) = shift_template.occurrence(day);
+            ShiftOccurrence {
+                date: day,
+                start,
+                end,
+                worked_minutes: shift_template.worked_minutes(),
+                name: shift_template.name.clone(),
+            }
+        })
+        .collect()
+}
+
+// Shifts of existing assignments between both days, assignments without their template are
+// skipped.
+pub fn assignment_occurrences(
+    assignments: &[ShiftAssignmentModel],
+    shift_templates: &[ShiftTemplateModel],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<ShiftOccurrence> {
+    assignments
+        .iter()
+        .filter_map(|assignment| {
+            let shift_template = shift_templates
+                .iter()
+                .find(|shift_template| shift_template.id == assignment.shift_template_id)?;
+            Some(occurrences(shift_template, assignment.start_date, assignment.end_date, from, to))
+        })
+        .flatten()
+        .collect()
+}
+
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    let offset = u64::from(date.weekday().num_days_from_monday());
+    date.checked_sub_days(Days::new(offset)).unwrap_or(date)
+}
+
+// Conflicts of new shifts with the existing shifts and leaves of the same employee. `leaves`
+// holds the first and last days of the leaves. Every week holding a new shift is checked
+// against the most weekly minutes.
+pub fn find_conflicts(
+    new_shifts: &[ShiftOccurrence],
+    existing_shifts: &[ShiftOccurrence],
+    leaves: &[(NaiveDate, NaiveDate)],
+    max_weekly_minutes: i64,
+) -> Vec<ShiftConflictResponse> {
+    let mut conflicts = Vec::new();
+    for shift in new_shifts {
+        for existing in existing_shifts {
+            if shift.start < existing.end && existing.start < shift.end {
+                conflicts.push(ShiftConflictResponse {
+                    kind: EShiftConflictKind::Overlap,
+                    date: shift.date,
+                    detail: format!(
+                        "{} overlaps {} starting at {}",
+                        shift.name, existing.name, existing.start
+                    ),
+                });
+            }
+        }
+        if leaves.iter().any(|(start, end)| *start <= shift.date && shift.date <= *end) {
+            conflicts.push(ShiftConflictResponse {
+                kind: EShiftConflictKind::Leave,
+                date: shift.date,
+                detail: format!("The employee is on leave on {}", shift.date),
+            });
+        }
+    }
+
+    let mut weeks = BTreeMap::new();
+    for shift in new_shifts {
+        *weeks.entry(week_start(shift.date)).or_insert(0) += shift.worked_minutes;
+    }
+    for shift in existing_shifts {
+        if let Some(minutes) = weeks.get_mut(&week_start(shift.date)) {
+            *minutes += shift.worked_minutes;
+        }
+    }
+    for (week, minutes) in weeks {
+        if minutes > max_weekly_minutes {
+            conflicts.push(ShiftConflictResponse {
+                kind: EShiftConflictKind::WeeklyHours,
+                date: week,
+                detail: format!(
+                    "{:.1} hours in the week of {week}, at most {} hours are allowed",
+                    minutes as f64 / 60.0,
+                    max_weekly_minutes / 60
+                ),
+            });
+        }
+    }
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+    use uuid::Uuid;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn shift_template(
+        name: &str,
+        start: (u32, u32),
+        end: (u32, u32),
+        days: &str,
+    ) -> ShiftTemplateModel {
+        ShiftTemplateModel {
+            id: 1,
+            shift_template_uuid: Uuid::nil(),
+            department_id: 1,
+            name: name.to_string(),
+            start_time: NaiveTime::from_hms_opt(start.0, start.1, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end.0, end.1, 0).unwrap(),
+            break_minutes: 0,
+            days_of_week: days.to_string(),
+            status: Some(1),
+            create_at: date(2025, 1, 1).and_hms_opt(0, 0, 0).unwrap(),
+            update_at: date(2025, 1, 1).and_hms_opt(0, 0, 0).unwrap(),
+        }
+    }
+
+    // Shifts of an assignment from the first to the last day.
+    fn shifts(
+        shift_template: &ShiftTemplateModel,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Vec<ShiftOccurrence> {
+        occurrences(shift_template, first, last, first, last)
+    }
+
+    #[test]
+    fn test_occurrences_stay_inside_the_assignment_and_the_range() {
+        let shift = shift_template("Day", (9, 0), (17, 0), "mon,wed,sat");
+        // Assignment from monday 3 to sunday 16 March 2025, read from wednesday 5 to 10 March.
+        let (start_date, end_date) = (date(2025, 3, 3), date(2025, 3, 16));
+        let days = occurrences(&shift, start_date, end_date, date(2025, 3, 5), date(2025, 3, 10))
+            .into_iter()
+            .map(|occurrence| occurrence.date)
+            .collect::<Vec<_>>();
+        assert_eq!(days, vec![date(2025, 3, 5), date(2025, 3, 8), date(2025, 3, 10)]);
+        assert_eq!(week_start(date(2025, 3, 9)), date(2025, 3, 3));
+    }
+
+    #[test]
+    fn test_overnight_shift_overlaps_the_next_morning_but_not_when_they_only_touch() {
+        let night = shift_template("Night", (22, 0), (6, 0), "mon");
+        let early = shift_template("Early", (5, 0), (13, 0), "tue");
+        let morning = shift_template("Morning", (6, 0), (14, 0), "tue");
+        let monday = date(2025, 3, 3);
+        let new_shifts = shifts(&night, monday, monday);
+        let tuesday = date(2025, 3, 4);
+
+        let existing = shifts(&early, tuesday, tuesday);
+        let conflicts = find_conflicts(&new_shifts, &existing, &[], 45 * 60);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].kind, EShiftConflictKind::Overlap);
+        assert_eq!(conflicts[0].date, monday);
+
+        let existing = shifts(&morning, tuesday, tuesday);
+        assert!(find_conflicts(&new_shifts, &existing, &[], 45 * 60).is_empty());
+    }
+
+    #[test]
+    fn test_shifts_during_a_leave_conflict_once_per_day() {
+        let shift = shift_template("Day", (9, 0), (17, 0), "mon,tue,wed,thu,fri");
+        let new_shifts = shifts(&shift, date(2025, 3, 3), date(2025, 3, 7));
+        let leaves = [(date(2025, 3, 5), date(2025, 3, 6))];
+        let conflicts = find_conflicts(&new_shifts, &[], &leaves, 45 * 60);
+        let days = conflicts
+            .iter()
+            .filter(|conflict| conflict.kind == EShiftConflictKind::Leave)
+            .map(|conflict| conflict.date)
+            .collect::<Vec<_>>();
+        assert_eq!(days, vec![date(2025, 3, 5), date(2025, 3, 6)]);
+        assert_eq!(conflicts.len(), 2);
+    }
+
+    #[test]
+    fn test_weekly_hours_count_the_existing_shifts_of_the_same_week_only() {
+        let weekdays = shift_template("Day", (9, 0), (17, 0), "mon,tue,wed,thu,fri");
+        let saturday = shift_template("Weekend", (9, 0), (17, 0), "sat");
+        let new_shifts = shifts(&weekdays, date(2025, 3, 3), date(2025, 3, 7));
+        // 40 hours of new shifts are allowed on their own.
+        assert!(find_conflicts(&new_shifts, &[], &[], 45 * 60).is_empty());
+
+        // A saturday of the week after is not counted.
+        let next_week = shifts(&saturday, date(2025, 3, 15), date(2025, 3, 15));
+        assert!(find_conflicts(&new_shifts, &next_week, &[], 45 * 60).is_empty());
+
+        let same_week = shifts(&saturday, date(2025, 3, 8), date(2025, 3, 8));
+        let conflicts = find_conflicts(&new_shifts, &same_week, &[], 45 * 60);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].kind, EShiftConflictKind::WeeklyHours);
+        assert_eq!(conflicts[0].date, date(2025, 3, 3));
+    }
+}
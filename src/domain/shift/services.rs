@@ -0,0 +1,559 @@
+use crate::core::app_state::AppState;
+use crate::core::error::{AppError, AppResult};
+use crate::domain::audit_log::services::record_audit_log;
+use crate::domain::audit_log::{AuditContext, EAuditAction, EAuditEntity};
+use crate::domain::department::services::find_department_by_uuid;
+use crate::domain::employee::services::find_employee_by_uuid;
+use crate::domain::entity::{
+    DepartmentEntity, EmployeeEntity, LeaveRequestEntity, OrganizationEntity,
+    ShiftAssignmentEntity, ShiftTemplateEntity, UserEntity,
+};
+use crate::domain::model::{EmployeeModel, ShiftAssignmentModel, ShiftTemplateModel};
+use crate::domain::organization::EWeekday;
+use crate::domain::shift::request::{
+    CreateShiftAssignmentRequest, CreateShiftTemplateRequest, DeleteShiftAssignmentRequest,
+    RosterQueryParam, ShiftTemplateQueryParam, UpdateShiftTemplateRequest,
+};
+use crate::domain::shift::response::{
+    RosterDayResponse, RosterEmployeeResponse, RosterResponse, RosterShiftResponse,
+    ShiftAssignmentResponse, ShiftConflictResponse, ShiftTemplateResponse,
+};
+use crate::domain::shift::roster::{assignment_occurrences, find_conflicts, occurrences, week_start};
+use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
+use crate::util::filter_and_pagination::{PageQueryParam, Paginated};
+use chrono::{Days, NaiveDate, Utc};
+use sea_orm::{ConnectionTrait, DatabaseTransaction, TransactionTrait};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use uuid::Uuid;
+
+// Longest range of days of one assignment.
+const MAX_ASSIGNMENT_DAYS: i64 = 366;
+
+pub async fn service_create_shift_template(
+    state: &AppState,
+    context: &AuditContext,
+    req: CreateShiftTemplateRequest,
+) -> AppResult<ShiftTemplateResponse> {
+    tracing::info!("Create new shift template request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let department = find_department_by_uuid(&tx, &req.department_uuid).await?;
+    check_unique_name(&tx, department.id, &req.name, None).await?;
+
+    let shift_template = ShiftTemplateModel {
+        id: Default::default(),
+        shift_template_uuid: Uuid::new_v4(),
+        department_id: department.id,
+        name: req.name,
+        start_time: req.start_time,
+        end_time: req.end_time,
+        break_minutes: req.break_minutes.unwrap_or_default(),
+        days_of_week: EWeekday::join(&req.days_of_week),
+        status: Some(1),
+        create_at: Utc::now().naive_utc(),
+        update_at: Utc::now().naive_utc(),
+    };
+    check_shift_hours(&shift_template)?;
+    let shift_template_id = ShiftTemplateEntity::create(&tx, &shift_template)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Shift template".to_string() })?;
+    let shift_template = ShiftTemplateModel { id: shift_template_id, ..shift_template };
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::ShiftTemplate,
+        shift_template.shift_template_uuid,
+        None,
+        Some(&shift_template),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(ShiftTemplateResponse::new(shift_template, department.department_uuid))
+}
+
+pub async fn service_update_shift_template(
+    state: &AppState,
+    context: &AuditContext,
+    req: UpdateShiftTemplateRequest,
+) -> AppResult<ShiftTemplateResponse> {
+    tracing::info!("Update shift template request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut shift_template = find_shift_template_by_uuid(&tx, &req.shift_template_uuid).await?;
+    let before = shift_template.clone();
+    let department = DepartmentEntity::find_data_by_id(&tx, shift_template.department_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Department not found".to_string(),
+        })?;
+
+    let rescheduled = req.start_time.is_some()
+        || req.end_time.is_some()
+        || req.break_minutes.is_some()
+        || req.days_of_week.is_some();
+    if let Some(name) = req.name {
+        shift_template.name = name;
+    }
+    if let Some(start_time) = req.start_time {
+        shift_template.start_time = start_time;
+    }
+    if let Some(end_time) = req.end_time {
+        shift_template.end_time = end_time;
+    }
+    if let Some(break_minutes) = req.break_minutes {
+        shift_template.break_minutes = break_minutes;
+    }
+    if let Some(days_of_week) = req.days_of_week {
+        shift_template.days_of_week = EWeekday::join(&days_of_week);
+    }
+    if let Some(status) = req.status {
+        shift_template.status = Some(status);
+    }
+    if shift_template.status == Some(1) {
+        check_unique_name(&tx, department.id, &shift_template.name, Some(shift_template.id))
+            .await?;
+    }
+    check_shift_hours(&shift_template)?;
+    shift_template.update_at = Utc::now().naive_utc();
+
+    // Upcoming assignments of an active shift must not get conflicts they did not have before.
+    let today = Utc::now().date_naive();
+    let (assignments, employees) = if rescheduled && shift_template.status == Some(1) {
+        let assignments =
+            ShiftAssignmentEntity::find_by_template_from(&tx, shift_template.id, today)
+                .await
+                .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+        let employee_ids =
+            assignments.iter().map(|assignment| assignment.employee_id).collect::<Vec<_>>();
+        let employees = EmployeeEntity::lock_by_ids(&tx, &employee_ids)
+            .await
+            .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?
+            .into_iter()
+            .map(|employee| (employee.id, employee.employee_uuid))
+            .collect::<HashMap<_, _>>();
+        (assignments, employees)
+    } else {
+        (Vec::new(), HashMap::new())
+    };
+    let conflicts_before = upcoming_conflicts(&tx, &before, &assignments, today).await?;
+
+    ShiftTemplateEntity::update(&tx, shift_template.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Shift template".to_string() })?;
+    let conflicts_after = upcoming_conflicts(&tx, &shift_template, &assignments, today).await?;
+    let details = assignments
+        .iter()
+        .zip(conflicts_after.into_iter().zip(conflicts_before))
+        .flat_map(|(assignment, (after, before))| {
+            let employee_uuid = employees.get(&assignment.employee_id).copied().unwrap_or_default();
+            after
+                .into_iter()
+                .filter(move |conflict| {
+                    !before.iter().any(|existing| {
+                        existing.kind == conflict.kind && existing.date == conflict.date
+                    })
+                })
+                .map(move |conflict| format!("Employee {employee_uuid}: {}", conflict.detail))
+        })
+        .collect::<Vec<_>>();
+    if !details.is_empty() {
+        return Err(AppError::ConflictError(details.join("; ")));
+    }
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::ShiftTemplate,
+        shift_template.shift_template_uuid,
+        Some(&before),
+        Some(&shift_template),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(ShiftTemplateResponse::new(shift_template, department.department_uuid))
+}
+
+pub async fn service_get_list_shift_template(
+    state: &AppState,
+    query: ShiftTemplateQueryParam,
+    param: PageQueryParam,
+) -> AppResult<Paginated<ShiftTemplateResponse>> {
+    tracing::info!("Get shift template list of department {}: {param:?}", query.department_uuid);
+    let department = find_department_by_uuid(&*state.db, &query.department_uuid).await?;
+    let shift_templates =
+        ShiftTemplateEntity::find_by_department(&*state.db, department.id, param).await?;
+    Ok(shift_templates.map(|shift_template| {
+        ShiftTemplateResponse::new(shift_template, department.department_uuid)
+    }))
+}
+
+// Conflicts the assignment would have, without creating it.
+pub async fn service_check_shift_assignment(
+    state: &AppState,
+    req: CreateShiftAssignmentRequest,
+) -> AppResult<Vec<ShiftConflictResponse>> {
+    tracing::info!("Check shift assignment request: {req:?}.");
+    let shift_template = find_shift_template_by_uuid(&*state.db, &req.shift_template_uuid).await?;
+    let employee = find_employee_by_uuid(&*state.db, &req.employee_uuid).await?;
+    assignment_conflicts(&*state.db, &shift_template, &employee, req.start_date, req.end_date)
+        .await
+}
+
+pub async fn service_create_shift_assignment(
+    state: &AppState,
+    context: &AuditContext,
+    req: CreateShiftAssignmentRequest,
+) -> AppResult<ShiftAssignmentResponse> {
+    tracing::info!("Create shift assignment request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let shift_template = find_shift_template_by_uuid(&tx, &req.shift_template_uuid).await?;
+    let employee = find_employee_by_uuid(&tx, &req.employee_uuid).await?;
+    // Two assignments of the same employee must not be checked against each other at once.
+    let employee = EmployeeEntity::lock_by_ids(&tx, &[employee.id])
+        .await
+        .and_then(|employees| employees.into_iter().next())
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let conflicts =
+        assignment_conflicts(&tx, &shift_template, &employee, req.start_date, req.end_date)
+            .await?;
+    if !conflicts.is_empty() {
+        let details = conflicts.into_iter().map(|conflict| conflict.detail).collect::<Vec<_>>();
+        return Err(AppError::ConflictError(details.join("; ")));
+    }
+
+    let assignment = ShiftAssignmentModel {
+        id: Default::default(),
+        shift_assignment_uuid: Uuid::new_v4(),
+        shift_template_id: shift_template.id,
+        employee_id: employee.id,
+        start_date: req.start_date,
+        end_date: req.end_date,
+        status: Some(1),
+        create_at: Utc::now().naive_utc(),
+        update_at: Utc::now().naive_utc(),
+    };
+    let assignment_id = ShiftAssignmentEntity::create(&tx, &assignment).await.ok_or_else(|| {
+        AppError::EntityNotAvailableError { detail: "Shift assignment".to_string() }
+    })?;
+    let assignment = ShiftAssignmentModel { id: assignment_id, ..assignment };
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::ShiftAssignment,
+        assignment.shift_assignment_uuid,
+        None,
+        Some(&assignment),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(ShiftAssignmentResponse::new(
+        assignment,
+        shift_template.shift_template_uuid,
+        employee.employee_uuid,
+    ))
+}
+
+pub async fn service_delete_shift_assignment(
+    state: &AppState,
+    context: &AuditContext,
+    req: DeleteShiftAssignmentRequest,
+) -> AppResult<ShiftAssignmentResponse> {
+    tracing::info!("Delete shift assignment request: {req:?}.");
+    let tx = state.db.begin().await?;
+    let mut assignment = ShiftAssignmentEntity::find_by_uuid(&tx, &req.shift_assignment_uuid)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: format!("Shift assignment not found by uuid {}", req.shift_assignment_uuid),
+        })?;
+    if assignment.status != Some(1) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!("Shift assignment is already deleted {}", req.shift_assignment_uuid),
+        });
+    }
+    let shift_template = ShiftTemplateEntity::find_data_by_id(&tx, assignment.shift_template_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Shift template not found".to_string(),
+        })?;
+    let employee = EmployeeEntity::find_data_by_id(&tx, assignment.employee_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Employee not found".to_string(),
+        })?;
+    let before = assignment.clone();
+    assignment.status = Some(0);
+    assignment.update_at = Utc::now().naive_utc();
+
+    ShiftAssignmentEntity::update(&tx, assignment.clone()).await.ok_or_else(|| {
+        AppError::EntityNotAvailableError { detail: "Shift assignment".to_string() }
+    })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Delete,
+        EAuditEntity::ShiftAssignment,
+        assignment.shift_assignment_uuid,
+        Some(&before),
+        Some(&assignment),
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(ShiftAssignmentResponse::new(
+        assignment,
+        shift_template.shift_template_uuid,
+        employee.employee_uuid,
+    ))
+}
+
+// Employees working each active shift of the department on each day of the week, from monday to
+// sunday.
+pub async fn service_get_roster(
+    state: &AppState,
+    department_uuid: Uuid,
+    query: RosterQueryParam,
+) -> AppResult<RosterResponse> {
+    tracing::info!("Get roster of department {department_uuid}: {query:?}");
+    let department = find_department_by_uuid(&*state.db, &department_uuid).await?;
+    let week_start = week_start(query.week_of.unwrap_or_else(|| Utc::now().date_naive()));
+    let week_end = week_start.checked_add_days(Days::new(6)).unwrap_or(week_start);
+    let shift_templates =
+        ShiftTemplateEntity::find_active_by_department(&*state.db, department.id)
+            .await
+            .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let shift_template_ids = shift_templates.iter().map(|template| template.id).collect::<Vec<_>>();
+    let assignments = ShiftAssignmentEntity::find_by_templates_between(
+        &*state.db,
+        &shift_template_ids,
+        week_start,
+        week_end,
+    )
+    .await
+    .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+
+    let employee_ids =
+        assignments.iter().map(|assignment| assignment.employee_id).collect::<Vec<_>>();
+    let employees = EmployeeEntity::find_by_ids(&*state.db, &employee_ids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let user_ids = employees.iter().map(|employee| employee.user_id).collect::<Vec<_>>();
+    let full_names = UserEntity::repo_find_by_ids(&*state.db, &user_ids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?
+        .into_iter()
+        .map(|user| (user.id, user.full_name))
+        .collect::<HashMap<_, _>>();
+    let employees = employees
+        .into_iter()
+        .map(|employee| (employee.id, employee))
+        .collect::<HashMap<_, _>>();
+    let leaves =
+        LeaveRequestEntity::find_approved_between(&*state.db, &employee_ids, week_start, week_end)
+            .await
+            .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+
+    let days = week_start
+        .iter_days()
+        .take_while(|day| *day <= week_end)
+        .map(|day| RosterDayResponse {
+            date: day,
+            shifts: shift_templates
+                .iter()
+                .filter(|shift_template| shift_template.runs_on(day))
+                .map(|shift_template| RosterShiftResponse {
+                    shift_template_uuid: shift_template.shift_template_uuid,
+                    name: shift_template.name.clone(),
+                    start_time: shift_template.start_time,
+                    end_time: shift_template.end_time,
+                    employees: assignments
+                        .iter()
+                        .filter(|assignment| {
+                            assignment.shift_template_id == shift_template.id
+                                && assignment.start_date <= day
+                                && day <= assignment.end_date
+                        })
+                        .filter_map(|assignment| employees.get(&assignment.employee_id))
+                        .map(|employee| RosterEmployeeResponse {
+                            employee_uuid: employee.employee_uuid,
+                            full_name: full_names
+                                .get(&employee.user_id)
+                                .cloned()
+                                .unwrap_or_default(),
+                            on_leave: leaves.iter().any(|leave| {
+                                leave.employee_id == employee.id
+                                    && leave.start_date <= day
+                                    && day <= leave.end_date
+                            }),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect();
+    Ok(RosterResponse { department_uuid, week_start, week_end, days })
+}
+
+// Overlaps with the other shifts of the employee, pending or approved leave and weeks over the
+// most weekly hours of the organization.
+async fn assignment_conflicts<DB>(
+    conn: &DB,
+    shift_template: &ShiftTemplateModel,
+    employee: &EmployeeModel,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> AppResult<Vec<ShiftConflictResponse>>
+where
+    DB: ConnectionTrait + Debug,
+{
+    if shift_template.status != Some(1) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!("Shift template is not active {}", shift_template.shift_template_uuid),
+        });
+    }
+    if employee.status != Some(1) {
+        return Err(AppError::EntityNotAvailableError {
+            detail: format!("Employee is not active {}", employee.employee_uuid),
+        });
+    }
+    if end_date < start_date {
+        return Err(AppError::BadRequestError("The last day is before the first day".to_string()));
+    }
+    if (end_date - start_date).num_days() >= MAX_ASSIGNMENT_DAYS {
+        return Err(AppError::BadRequestError(format!(
+            "An assignment covers at most {MAX_ASSIGNMENT_DAYS} days"
+        )));
+    }
+    let departments = EmployeeEntity::find_departments(conn, &[employee.id], start_date)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    if !departments.iter().any(|(_, department)| department.id == shift_template.department_id) {
+        return Err(AppError::BadRequestError(format!(
+            "Employee is not in the department of the shift on {start_date}"
+        )));
+    }
+    schedule_conflicts(conn, shift_template, employee.id, start_date, end_date, None).await
+}
+
+// Conflicts of the shifts between both days with the other shifts and leaves of the employee,
+// the skipped assignment is not counted among the other shifts.
+async fn schedule_conflicts<DB>(
+    conn: &DB,
+    shift_template: &ShiftTemplateModel,
+    employee_id: i64,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    skipped_assignment_id: Option<i64>,
+) -> AppResult<Vec<ShiftConflictResponse>>
+where
+    DB: ConnectionTrait + Debug,
+{
+    let department = DepartmentEntity::find_data_by_id(conn, shift_template.department_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Department not found".to_string(),
+        })?;
+    let organization = OrganizationEntity::find_data_by_id(conn, department.organization_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotFoundError {
+            detail: "Organization not found".to_string(),
+        })?;
+
+    // A week earlier and later keep the overnight shifts and the whole first and last weeks.
+    let from = start_date.checked_sub_days(Days::new(7)).unwrap_or(start_date);
+    let to = end_date.checked_add_days(Days::new(7)).unwrap_or(end_date);
+    let assignments = ShiftAssignmentEntity::find_by_employee_between(conn, employee_id, from, to)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?
+        .into_iter()
+        .filter(|assignment| Some(assignment.id) != skipped_assignment_id)
+        .collect::<Vec<_>>();
+    let shift_template_ids =
+        assignments.iter().map(|assignment| assignment.shift_template_id).collect::<Vec<_>>();
+    let shift_templates = ShiftTemplateEntity::find_by_ids(conn, &shift_template_ids)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?;
+    let leaves = LeaveRequestEntity::find_overlapping(conn, employee_id, start_date, end_date)
+        .await
+        .ok_or_else(|| AppError::BadRequestError("Something went wrong!".to_string()))?
+        .into_iter()
+        .map(|leave| (leave.start_date, leave.end_date))
+        .collect::<Vec<_>>();
+
+    let new_shifts = occurrences(shift_template, start_date, end_date, start_date, end_date);
+    let existing_shifts = assignment_occurrences(&assignments, &shift_templates, from, to);
+    let max_weekly_minutes = i64::from(organization.max_weekly_hours) * 60;
+    Ok(find_conflicts(&new_shifts, &existing_shifts, &leaves, max_weekly_minutes))
+}
+
+// Conflicts of each assignment from today on with the shifts of the template.
+async fn upcoming_conflicts(
+    tx: &DatabaseTransaction,
+    shift_template: &ShiftTemplateModel,
+    assignments: &[ShiftAssignmentModel],
+    today: NaiveDate,
+) -> AppResult<Vec<Vec<ShiftConflictResponse>>> {
+    let mut conflicts = Vec::with_capacity(assignments.len());
+    for assignment in assignments {
+        let start_date = assignment.start_date.max(today);
+        conflicts.push(
+            schedule_conflicts(
+                tx,
+                shift_template,
+                assignment.employee_id,
+                start_date,
+                assignment.end_date,
+                Some(assignment.id),
+            )
+            .await?,
+        );
+    }
+    Ok(conflicts)
+}
+
+// A shift lasts longer than its break, a shift from and to the same time would last a whole day.
+fn check_shift_hours(shift_template: &ShiftTemplateModel) -> AppResult {
+    if shift_template.start_time == shift_template.end_time {
+        return Err(AppError::BadRequestError(
+            "The shift must end at another time than it starts".to_string(),
+        ));
+    }
+    if shift_template.worked_minutes() <= 0 {
+        return Err(AppError::BadRequestError(
+            "The break must be shorter than the shift".to_string(),
+        ));
+    }
+    if shift_template.days().is_empty() {
+        return Err(AppError::BadRequestError(
+            "The shift must run on at least one day".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+async fn find_shift_template_by_uuid<DB>(
+    conn: &DB,
+    shift_template_uuid: &Uuid,
+) -> AppResult<ShiftTemplateModel>
+where
+    DB: ConnectionTrait + Debug,
+{
+    ShiftTemplateEntity::find_data_by_uuid(conn, shift_template_uuid).await.ok_or_else(|| {
+        AppError::EntityNotFoundError {
+            detail: format!("Shift template not found by uuid {shift_template_uuid}"),
+        }
+    })
+}
+
+async fn check_unique_name(
+    tx: &DatabaseTransaction,
+    department_id: i64,
+    name: &str,
+    shift_template_id: Option<i64>,
+) -> AppResult {
+    match ShiftTemplateEntity::find_by_department_and_name(tx, department_id, name).await {
+        Some(exists) if Some(exists.id) != shift_template_id => Err(AppError::EntityExistsError {
+            detail: "Shift template name is already exists in this department!".to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,60 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
+use serde::{Deserialize, Serialize};
+
+// Employee working a shift on every day of the shift between both days.
+#[derive(Debug, PartialEq, Clone, DeriveEntityModel, Deserialize, Serialize)]
+#[sea_orm(table_name = "shift_assignments", rename_all = "snake_case")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    #[sea_orm(unique, indexed)]
+    pub shift_assignment_uuid: Uuid,
+    #[sea_orm(indexed)]
+    pub shift_template_id: i64,
+    #[sea_orm()]
+    pub employee_id: i64,
+    #[sea_orm()]
+    pub start_date: NaiveDate,
+    #[sea_orm()]
+    pub end_date: NaiveDate,
+    // 0 when the assignment was removed.
+    #[sea_orm(nullable, default_value = 1)]
+    pub status: Option<i16>,
+    #[sea_orm()]
+    pub create_at: NaiveDateTime,
+    #[sea_orm()]
+    pub update_at: NaiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::domain::employee::Entity",
+        from = "Column::EmployeeId",
+        to = "crate::domain::employee::Column::Id"
+    )]
+    Employee,
+    #[sea_orm(
+        belongs_to = "crate::domain::shift::Entity",
+        from = "Column::ShiftTemplateId",
+        to = "crate::domain::shift::Column::Id"
+    )]
+    ShiftTemplate,
+}
+
+impl Related<crate::domain::employee::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Employee.def()
+    }
+}
+
+impl Related<crate::domain::shift::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ShiftTemplate.def()
+    }
+}
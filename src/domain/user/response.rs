@@ -41,3 +41,22 @@ impl From<user::Model> for PublicProfileResponse {
         }
     }
 }
+
+impl PublicProfileResponse {
+    // Fields that can be picked by the `columns` of a user export.
+    pub const EXPORT_COLUMNS: [&'static str; 13] = [
+        "user_id",
+        "email",
+        "full_name",
+        "gender",
+        "phone_number",
+        "address",
+        "language",
+        "status",
+        "role_id",
+        "last_login",
+        "created_at",
+        "updated_at",
+        "deleted_at",
+    ];
+}
@@ -1,17 +1,22 @@
 use crate::core::app_state::AppState;
 use crate::core::error::{AppError, AppResult};
+use crate::domain::audit_log::services::record_audit_log;
+use crate::domain::audit_log::{AuditContext, EAuditAction, EAuditEntity};
 use crate::domain::user;
 use crate::domain::user::request::AdminCreateAccountRequest;
 use crate::domain::user::request::UpdateProfileRequest;
+use crate::domain::role::services::find_active_role_by_uuid;
 use crate::domain::user::response::PublicProfileResponse;
 use crate::domain::user::{EGenderUser, UserEntity};
 use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
 use crate::util::constant::CODE_LEN;
-use crate::util::filter_and_pagination::{EModule, PageQueryParam};
+use crate::util::export::{fetch_in_batches, pick_columns, ExportQueryParam, ExportRows};
+use crate::util::filter_and_pagination::{EModule, PageQueryParam, Paginated};
 use crate::util::password;
 use crate::util::random::generate_random_string;
 use chrono::Utc;
-use sea_orm::{ActiveModelTrait, IntoActiveModel, Set};
+use futures::StreamExt;
+use sea_orm::Set;
 use sea_orm::{DatabaseTransaction, TransactionTrait};
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -32,6 +37,7 @@ pub async fn service_get_profile(
 
 pub async fn service_update_profile(
     state: &AppState,
+    context: &AuditContext,
     user_uid: Uuid,
     req: UpdateProfileRequest,
 ) -> AppResult {
@@ -44,6 +50,7 @@ pub async fn service_update_profile(
     let mut curr_user = UserEntity::find_data_by_uuid(&tx, &user_uid).await.ok_or_else(|| {
         AppError::EntityNotFoundError { detail: format!("User not found by id {}", user_uid) }
     })?;
+    let before = curr_user.clone();
 
     if let Some(full_name) = req.full_name {
         curr_user.full_name = full_name;
@@ -63,7 +70,20 @@ pub async fn service_update_profile(
     if let Some(status) = req.status {
         curr_user.status = status;
     }
-    curr_user.into_active_model().update(&tx).await?;
+    curr_user.updated_at = Utc::now().naive_utc();
+    UserEntity::update(&tx, curr_user.clone())
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "User".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Update,
+        EAuditEntity::User,
+        user_uid,
+        Some(&before),
+        Some(&curr_user),
+    )
+    .await?;
     tx.commit().await?;
     Ok(())
 }
@@ -72,6 +92,7 @@ pub async fn service_update_profile(
 
 pub async fn service_admin_create_account(
     state: &AppState,
+    context: &AuditContext,
     req: AdminCreateAccountRequest,
 ) -> AppResult<PublicProfileResponse> {
     tracing::info!("Admin create a new account request: {req:?}.");
@@ -87,6 +108,8 @@ pub async fn service_admin_create_account(
     if let Some(phone_number) = req.phone_number.as_ref() {
         service_check_is_exists_by_phone_number(&tx, phone_number).await?;
     }
+    let role = find_active_role_by_uuid(&tx, &req.role_uuid).await?;
+    let creator_id = find_creator_id(&tx, context).await?;
 
     let hash_password = password::hash((&req.password).to_string()).await?;
     let mut user_model = user::Model {
@@ -99,8 +122,8 @@ pub async fn service_admin_create_account(
         address: req.address,
         language: Some(req.language.unwrap_or("vi".to_string())),
         status: req.status.unwrap_or(1),
-        role_id: 222,    // TODO: find role id by req.role_id and add id here
-        creator_id: 321, // TODO: add creator id by jwt
+        role_id: role.id,
+        creator_id,
         password: hash_password,
         email: (&req.email).to_string(),
         created_at: Utc::now().naive_utc(),
@@ -109,7 +132,19 @@ pub async fn service_admin_create_account(
         deleted_at: Default::default(),
     };
 
-    let data = UserEntity::create(&tx, &user_model).await;
+    user_model.id = UserEntity::create(&tx, &user_model)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "User".to_string() })?;
+    record_audit_log(
+        &tx,
+        context,
+        EAuditAction::Create,
+        EAuditEntity::User,
+        user_model.user_uuid,
+        None,
+        Some(&user_model),
+    )
+    .await?;
     tx.commit().await?;
     Ok(PublicProfileResponse::from(user_model))
 }
@@ -117,16 +152,42 @@ pub async fn service_admin_create_account(
 pub async fn service_admin_get_list(
     state: &AppState,
     param: PageQueryParam,
-) -> AppResult<Vec<PublicProfileResponse>> {
+) -> AppResult<Paginated<PublicProfileResponse>> {
     tracing::info!("Get user list with parameter: {param:?}");
-    match UserEntity::find_all(&*state.db, param).await {
-        Some(result) => Ok(result.into_iter().map(PublicProfileResponse::from).collect()),
-        None => Err(AppError::BadRequestError("Something went wrong!".to_string())),
-    }
+    let users = UserEntity::find_all(&*state.db, param).await?;
+    Ok(users.map(PublicProfileResponse::from))
+}
+
+pub async fn service_admin_export_users(
+    state: &AppState,
+    param: &ExportQueryParam,
+) -> AppResult<(Vec<&'static str>, ExportRows)> {
+    tracing::info!("Export users with parameter: {param:?}");
+    let columns = param.select_columns(&PublicProfileResponse::EXPORT_COLUMNS)?;
+    let select = UserEntity::repo_select_for_export(&param.page_query())?;
+    let picked = columns.clone();
+    let rows = fetch_in_batches(state.db.clone(), select, param.page_query(), EModule::User)
+        .map(move |users| {
+            Ok(users?
+                .into_iter()
+                .map(|user| pick_columns(&PublicProfileResponse::from(user), &picked))
+                .collect())
+        })
+        .boxed();
+    Ok((columns, rows))
 }
 
 // TODO: implement service admin update profile with role id and create new employee
 
+// Id of the authenticated user, stored as the creator of the accounts they create.
+pub async fn find_creator_id(tx: &DatabaseTransaction, context: &AuditContext) -> AppResult<i64> {
+    UserEntity::find_data_by_uuid(tx, &context.actor_uuid).await.map(|user| user.id).ok_or_else(
+        || AppError::EntityNotFoundError {
+            detail: format!("User not found by id {}", context.actor_uuid),
+        },
+    )
+}
+
 async fn service_check_is_exists_by_phone_number(
     tx: &DatabaseTransaction,
     phone_number: &str,
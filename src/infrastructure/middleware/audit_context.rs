@@ -0,0 +1,29 @@
+use crate::core::app_state::AppState;
+use crate::core::error::AppError;
+use crate::domain::audit_log::AuditContext;
+use crate::infrastructure::middleware::client_info::ClientInfo;
+use crate::util::claim::UserClaims;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+
+// Set by the request id layer of the server, or forwarded by the caller.
+const X_REQUEST_ID: &str = "x-request-id";
+
+#[async_trait::async_trait]
+impl FromRequestParts<AppState> for AuditContext {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let claims = UserClaims::from_request_parts(parts, state).await?;
+        let Ok(client_info) = ClientInfo::from_request_parts(parts, state).await;
+        let request_id = parts
+            .headers
+            .get(X_REQUEST_ID)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        Ok(Self { actor_uuid: claims.uuid, ip: client_info.ip, request_id })
+    }
+}
@@ -1,15 +1,21 @@
 use crate::core::app_state::AppState;
 use crate::core::error::AppError;
+use crate::domain::role::permission::EPermission;
 use crate::infrastructure::persistence::redis_client;
 use crate::util::claim::UserClaims;
 use crate::util::constant::ACCESS_TOKEN_DECODE_KEY;
-use axum::extract::FromRequestParts;
+use axum::extract::{FromRequestParts, Request};
 use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
 use axum::RequestPartsExt;
 use axum_extra::{
     headers::{authorization::Bearer, Authorization},
     TypedHeader,
 };
+use futures::future::BoxFuture;
+use std::convert::Infallible;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
 
 #[async_trait::async_trait]
 impl FromRequestParts<AppState> for UserClaims {
@@ -19,6 +25,10 @@ impl FromRequestParts<AppState> for UserClaims {
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
+        // Already verified by `RequirePermissionLayer`.
+        if let Some(user_claims) = parts.extensions.get::<UserClaims>() {
+            return Ok(user_claims.clone());
+        }
         match parts.extract::<TypedHeader<Authorization<Bearer>>>().await {
             Ok(header) => {
                 let TypedHeader(Authorization(bearer)) = header;
@@ -34,3 +44,66 @@ impl FromRequestParts<AppState> for UserClaims {
         }
     }
 }
+
+// Rejects the request unless the authenticated user has every listed permission.
+// Usage: `post(handler).route_layer(RequirePermissionLayer::new(&state, &[EPermission::UserCreate]))`
+#[derive(Clone)]
+pub struct RequirePermissionLayer {
+    state: AppState,
+    permissions: &'static [EPermission],
+}
+
+impl RequirePermissionLayer {
+    pub fn new(state: &AppState, permissions: &'static [EPermission]) -> Self {
+        Self { state: state.clone(), permissions }
+    }
+}
+
+impl<S> Layer<S> for RequirePermissionLayer {
+    type Service = RequirePermission<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequirePermission { inner, state: self.state.clone(), permissions: self.permissions }
+    }
+}
+
+#[derive(Clone)]
+pub struct RequirePermission<S> {
+    inner: S,
+    state: AppState,
+    permissions: &'static [EPermission],
+}
+
+impl<S> Service<Request> for RequirePermission<S>
+where
+    S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Response, Infallible>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let state = self.state.clone();
+        let permissions = self.permissions;
+        Box::pin(async move {
+            let (mut parts, body) = req.into_parts();
+            let user_claims = match UserClaims::from_request_parts(&mut parts, &state).await {
+                Ok(user_claims) => user_claims,
+                Err(err) => return Ok(err.into_response()),
+            };
+            if let Err(err) = user_claims.check_permissions(permissions) {
+                tracing::warn!("Permission denied on {}: {err}", parts.uri);
+                return Ok(err.into_response());
+            }
+            parts.extensions.insert(user_claims);
+            inner.call(Request::from_parts(parts, body)).await
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use crate::core::app_state::AppState;
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::header::USER_AGENT;
+use axum::http::request::Parts;
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+const X_REAL_IP: &str = "x-real-ip";
+
+// User agent and address of the caller, recorded on every new session.
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl FromRequestParts<AppState> for ClientInfo {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let header = |name| parts.headers.get(name).and_then(|value| value.to_str().ok());
+        let user_agent = header(USER_AGENT.as_str()).map(str::to_string);
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        // Forwarded headers are set by the client itself unless a trusted proxy is in between.
+        let forwarded = peer
+            .filter(|peer| state.config.server.is_trusted_proxy(peer))
+            .and_then(|_| {
+                header(X_FORWARDED_FOR)
+                    .and_then(|value| value.split(',').next())
+                    .or_else(|| header(X_REAL_IP))
+            })
+            .map(|value| value.trim().to_string());
+        let ip = forwarded.or_else(|| peer.as_ref().map(IpAddr::to_string));
+        Ok(Self { user_agent, ip })
+    }
+}
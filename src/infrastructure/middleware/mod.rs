@@ -1,2 +1,4 @@
+pub mod audit_context;
 pub mod authenticate;
+pub mod client_info;
 pub mod map_response;
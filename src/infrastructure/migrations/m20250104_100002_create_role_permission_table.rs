@@ -0,0 +1,23 @@
+use crate::domain::entity::RolePermissionEntity;
+use sea_orm::{DbBackend, Schema};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db_postgres = DbBackend::Postgres;
+        let schema = Schema::new(db_postgres);
+        let db = manager.get_connection();
+        let statement = db_postgres.build(&schema.create_table_from_entity(RolePermissionEntity));
+        db.execute_unprepared(statement.sql.as_str()).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.get_connection().execute_unprepared("DROP TABLE IF EXISTS role_permission").await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::domain::role::permission::EPermission;
+use sea_orm::{DbBackend, Statement};
+use sea_orm_migration::prelude::*;
+use uuid::Uuid;
+
+pub const ADMIN_ROLE: &str = "admin";
+
+const PERMISSIONS: [(EPermission, &str); 9] = [
+    (EPermission::UserCreate, "Create user accounts"),
+    (EPermission::UserRead, "Read user accounts"),
+    (EPermission::UserUpdate, "Update user accounts"),
+    (EPermission::EmployeeCreate, "Create employees"),
+    (EPermission::EmployeeRead, "Read employees"),
+    (EPermission::EmployeeUpdate, "Update employees"),
+    (EPermission::EmployeeDelete, "Delete employees"),
+    (EPermission::RoleRead, "Read roles and permissions"),
+    (EPermission::RoleWrite, "Create, update and delete roles"),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "INSERT INTO roles (role_uuid, name, description, status, create_at, update_at) \
+             VALUES ($1, $2, 'Full access to the system', 1, NOW(), NOW()) \
+             ON CONFLICT (name) DO NOTHING",
+            [Uuid::new_v4().into(), ADMIN_ROLE.into()],
+        ))
+        .await?;
+        seed_permissions(manager, &PERMISSIONS).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DELETE FROM role_permission").await?;
+        db.execute_unprepared("DELETE FROM permissions").await?;
+        db.execute_unprepared("DELETE FROM roles").await?;
+        Ok(())
+    }
+}
+
+// Inserts the permissions if they do not exist yet and grants them to the admin role.
+pub async fn seed_permissions(
+    manager: &SchemaManager<'_>,
+    permissions: &[(EPermission, &str)],
+) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    for (permission, description) in permissions {
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "INSERT INTO permissions (permission_uuid, code, description, create_at, update_at) \
+             VALUES ($1, $2, $3, NOW(), NOW()) ON CONFLICT (code) DO NOTHING",
+            [Uuid::new_v4().into(), permission.to_string().into(), (*description).into()],
+        ))
+        .await?;
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "INSERT INTO role_permission (role_id, permission_id, create_at) \
+             SELECT r.id, p.id, NOW() FROM roles r, permissions p \
+             WHERE r.name = $1 AND p.code = $2 ON CONFLICT DO NOTHING",
+            [ADMIN_ROLE.into(), permission.to_string().into()],
+        ))
+        .await?;
+    }
+    Ok(())
+}
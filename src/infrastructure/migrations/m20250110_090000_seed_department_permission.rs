@@ -0,0 +1,32 @@
+use crate::domain::role::permission::EPermission;
+use crate::infrastructure::migrations::m20250104_100003_seed_role_permission::seed_permissions;
+use sea_orm_migration::prelude::*;
+
+const PERMISSIONS: [(EPermission, &str); 2] = [
+    (EPermission::DepartmentRead, "Read departments"),
+    (EPermission::DepartmentWrite, "Create, update and delete departments"),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        seed_permissions(manager, &PERMISSIONS).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "DELETE FROM role_permission WHERE permission_id IN (SELECT id FROM permissions \
+             WHERE code IN ('department:read', 'department:write'))",
+        )
+        .await?;
+        db.execute_unprepared(
+            "DELETE FROM permissions WHERE code IN ('department:read', 'department:write')",
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use sea_orm_migration::prelude::*;
+
+// Position names only have to be unique inside their department.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("ALTER TABLE positions DROP CONSTRAINT IF EXISTS positions_name_key")
+            .await?;
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_positions_department_id_name \
+             ON positions (department_id, name)",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_positions_department_id_name").await?;
+        db.execute_unprepared(
+            "ALTER TABLE positions ADD CONSTRAINT positions_name_key UNIQUE (name)",
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE organizations \
+                 ADD COLUMN IF NOT EXISTS default_language varchar NOT NULL DEFAULT 'vi', \
+                 ADD COLUMN IF NOT EXISTS timezone varchar NOT NULL DEFAULT 'Asia/Ho_Chi_Minh', \
+                 ADD COLUMN IF NOT EXISTS working_week varchar NOT NULL \
+                 DEFAULT 'mon,tue,wed,thu,fri', \
+                 ADD COLUMN IF NOT EXISTS currency varchar NOT NULL DEFAULT 'VND'",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE organizations \
+                 DROP COLUMN IF EXISTS default_language, \
+                 DROP COLUMN IF EXISTS timezone, \
+                 DROP COLUMN IF EXISTS working_week, \
+                 DROP COLUMN IF EXISTS currency",
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::domain::entity::AuditLogEntity;
+use sea_orm::{DbBackend, Schema};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db_postgres = DbBackend::Postgres;
+        let schema = Schema::new(db_postgres);
+        let db = manager.get_connection();
+        let statement = db_postgres.build(&schema.create_table_from_entity(AuditLogEntity));
+        db.execute_unprepared(statement.sql.as_str()).await?;
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_audit_logs_entity_type_entity_uuid \
+             ON audit_logs (entity_type, entity_uuid)",
+        )
+        .await?;
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_audit_logs_actor_uuid ON audit_logs (actor_uuid)",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.get_connection().execute_unprepared("DROP TABLE IF EXISTS audit_logs").await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+// Department and position assignments keep their history, a transfer closes the previous
+// assignment instead of deleting it, so the pair of ids is no longer a primary key.
+const TABLES: [(&str, &str); 2] =
+    [("employee_department", "department_id"), ("employee_position", "position_id")];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for (table, target_column) in TABLES {
+            db.execute_unprepared(&format!(
+                "DO $$ BEGIN \
+                 IF NOT EXISTS (SELECT 1 FROM information_schema.columns \
+                 WHERE table_name = '{table}' AND column_name = 'id') THEN \
+                 ALTER TABLE {table} DROP CONSTRAINT IF EXISTS {table}_pkey; \
+                 ALTER TABLE {table} ADD COLUMN id BIGSERIAL PRIMARY KEY; \
+                 END IF; \
+                 END $$"
+            ))
+            .await?;
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} \
+                 ADD COLUMN IF NOT EXISTS effective_from DATE, \
+                 ADD COLUMN IF NOT EXISTS effective_to DATE, \
+                 ADD COLUMN IF NOT EXISTS is_primary BOOLEAN NOT NULL DEFAULT FALSE, \
+                 ADD COLUMN IF NOT EXISTS reason VARCHAR"
+            ))
+            .await?;
+            db.execute_unprepared(&format!(
+                "UPDATE {table} SET effective_from = create_at::date WHERE effective_from IS NULL"
+            ))
+            .await?;
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} ALTER COLUMN effective_from SET NOT NULL"
+            ))
+            .await?;
+            // The lowest assignment of every employee becomes the primary one.
+            db.execute_unprepared(&format!(
+                "UPDATE {table} SET is_primary = TRUE WHERE id IN (SELECT DISTINCT ON \
+                 (employee_id) id FROM {table} ORDER BY employee_id, {target_column}) \
+                 AND NOT EXISTS (SELECT 1 FROM {table} other WHERE other.employee_id = \
+                 {table}.employee_id AND other.is_primary)"
+            ))
+            .await?;
+            db.execute_unprepared(&format!(
+                "CREATE INDEX IF NOT EXISTS idx_{table}_employee_id_effective_from \
+                 ON {table} (employee_id, effective_from)"
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for (table, target_column) in TABLES {
+            // Only the assignments in effect can be kept with the old primary key.
+            db.execute_unprepared(&format!(
+                "DELETE FROM {table} WHERE effective_to IS NOT NULL"
+            ))
+            .await?;
+            db.execute_unprepared(&format!(
+                "DELETE FROM {table} WHERE id NOT IN (SELECT MIN(id) FROM {table} \
+                 GROUP BY employee_id, {target_column})"
+            ))
+            .await?;
+            db.execute_unprepared(&format!(
+                "DROP INDEX IF EXISTS idx_{table}_employee_id_effective_from"
+            ))
+            .await?;
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} DROP COLUMN IF EXISTS id, \
+                 DROP COLUMN IF EXISTS effective_from, DROP COLUMN IF EXISTS effective_to, \
+                 DROP COLUMN IF EXISTS is_primary, DROP COLUMN IF EXISTS reason"
+            ))
+            .await?;
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} ADD PRIMARY KEY (employee_id, {target_column})"
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "ALTER TABLE departments \
+             ADD COLUMN IF NOT EXISTS parent_department_id bigint NULL \
+             REFERENCES departments (id) ON DELETE SET NULL",
+        )
+        .await?;
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_departments_parent_department_id \
+             ON departments (parent_department_id)",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_departments_parent_department_id").await?;
+        db.execute_unprepared("ALTER TABLE departments DROP COLUMN IF EXISTS parent_department_id")
+            .await?;
+        Ok(())
+    }
+}
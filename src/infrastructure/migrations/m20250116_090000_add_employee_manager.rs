@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "ALTER TABLE employees \
+             ADD COLUMN IF NOT EXISTS manager_employee_id bigint NULL \
+             REFERENCES employees (id) ON DELETE SET NULL",
+        )
+        .await?;
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_employees_manager_employee_id \
+             ON employees (manager_employee_id)",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_employees_manager_employee_id").await?;
+        db.execute_unprepared("ALTER TABLE employees DROP COLUMN IF EXISTS manager_employee_id")
+            .await?;
+        Ok(())
+    }
+}
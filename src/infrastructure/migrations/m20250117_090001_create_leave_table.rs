@@ -0,0 +1,37 @@
+use crate::domain::entity::{LeaveRequestEntity, LeaveTypeEntity};
+use sea_orm::{DbBackend, Schema};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db_postgres = DbBackend::Postgres;
+        let schema = Schema::new(db_postgres);
+        let db = manager.get_connection();
+        let statement = db_postgres.build(&schema.create_table_from_entity(LeaveTypeEntity));
+        db.execute_unprepared(statement.sql.as_str()).await?;
+        let statement = db_postgres.build(&schema.create_table_from_entity(LeaveRequestEntity));
+        db.execute_unprepared(statement.sql.as_str()).await?;
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_leave_types_organization_id_name \
+             ON leave_types (organization_id, name)",
+        )
+        .await?;
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_leave_requests_employee_id_start_date \
+             ON leave_requests (employee_id, start_date)",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TABLE IF EXISTS leave_requests").await?;
+        db.execute_unprepared("DROP TABLE IF EXISTS leave_types").await?;
+        Ok(())
+    }
+}
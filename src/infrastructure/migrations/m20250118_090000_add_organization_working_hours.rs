@@ -0,0 +1,33 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE organizations \
+                 ADD COLUMN IF NOT EXISTS work_start_time time NOT NULL DEFAULT '08:00:00', \
+                 ADD COLUMN IF NOT EXISTS work_end_time time NOT NULL DEFAULT '17:00:00', \
+                 ADD COLUMN IF NOT EXISTS late_grace_minutes integer NOT NULL DEFAULT 0",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE organizations \
+                 DROP COLUMN IF EXISTS work_start_time, \
+                 DROP COLUMN IF EXISTS work_end_time, \
+                 DROP COLUMN IF EXISTS late_grace_minutes",
+            )
+            .await?;
+        Ok(())
+    }
+}
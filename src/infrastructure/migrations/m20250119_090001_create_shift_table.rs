@@ -0,0 +1,37 @@
+use crate::domain::entity::{ShiftAssignmentEntity, ShiftTemplateEntity};
+use sea_orm::{DbBackend, Schema};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db_postgres = DbBackend::Postgres;
+        let schema = Schema::new(db_postgres);
+        let db = manager.get_connection();
+        let statement = db_postgres.build(&schema.create_table_from_entity(ShiftTemplateEntity));
+        db.execute_unprepared(statement.sql.as_str()).await?;
+        let statement = db_postgres.build(&schema.create_table_from_entity(ShiftAssignmentEntity));
+        db.execute_unprepared(statement.sql.as_str()).await?;
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_shift_templates_department_id_name \
+             ON shift_templates (department_id, name)",
+        )
+        .await?;
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_shift_assignments_employee_id_start_date \
+             ON shift_assignments (employee_id, start_date)",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TABLE IF EXISTS shift_assignments").await?;
+        db.execute_unprepared("DROP TABLE IF EXISTS shift_templates").await?;
+        Ok(())
+    }
+}
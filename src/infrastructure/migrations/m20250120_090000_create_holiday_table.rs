@@ -0,0 +1,29 @@
+use crate::domain::entity::HolidayEntity;
+use sea_orm::{DbBackend, Schema};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db_postgres = DbBackend::Postgres;
+        let schema = Schema::new(db_postgres);
+        let db = manager.get_connection();
+        let statement = db_postgres.build(&schema.create_table_from_entity(HolidayEntity));
+        db.execute_unprepared(statement.sql.as_str()).await?;
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_holidays_organization_id_date \
+             ON holidays (organization_id, date)",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TABLE IF EXISTS holidays").await?;
+        Ok(())
+    }
+}
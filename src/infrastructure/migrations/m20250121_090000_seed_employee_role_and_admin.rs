@@ -0,0 +1,81 @@
+use crate::domain::role::permission::EPermission;
+use crate::infrastructure::migrations::m20250104_100003_seed_role_permission::ADMIN_ROLE;
+use crate::util::constant::{ADMIN_EMAIL_ENV, EMPLOYEE_ROLE};
+use sea_orm::{DbBackend, Statement};
+use sea_orm_migration::prelude::*;
+use uuid::Uuid;
+
+// Self service permissions of every employee.
+const EMPLOYEE_PERMISSIONS: [EPermission; 2] =
+    [EPermission::LeaveRequest, EPermission::AttendanceRecord];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "INSERT INTO roles (role_uuid, name, description, status, create_at, update_at) \
+             VALUES ($1, $2, 'Self service access of employees', 1, NOW(), NOW()) \
+             ON CONFLICT (name) DO NOTHING",
+            [Uuid::new_v4().into(), EMPLOYEE_ROLE.into()],
+        ))
+        .await?;
+        for permission in EMPLOYEE_PERMISSIONS {
+            db.execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "INSERT INTO role_permission (role_id, permission_id, create_at) \
+                 SELECT r.id, p.id, NOW() FROM roles r, permissions p \
+                 WHERE r.name = $1 AND p.code = $2 ON CONFLICT DO NOTHING",
+                [EMPLOYEE_ROLE.into(), permission.to_string().into()],
+            ))
+            .await?;
+        }
+        // Accounts created before roles existed point to no role at all.
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "UPDATE users SET role_id = (SELECT id FROM roles WHERE name = $1) \
+             WHERE role_id NOT IN (SELECT id FROM roles)",
+            [EMPLOYEE_ROLE.into()],
+        ))
+        .await?;
+        grant_first_admin(manager).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM role_permission WHERE role_id IN (SELECT id FROM roles WHERE name = $1)",
+            [EMPLOYEE_ROLE.into()],
+        ))
+        .await?;
+        db.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "DELETE FROM roles WHERE name = $1",
+            [EMPLOYEE_ROLE.into()],
+        ))
+        .await?;
+        Ok(())
+    }
+}
+
+// Grants the admin role to the account of `APP_ADMIN_EMAIL`. Without it nobody is promoted, an
+// administrator has to be granted by hand.
+async fn grant_first_admin(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    let Ok(email) = std::env::var(ADMIN_EMAIL_ENV) else {
+        tracing::warn!("{ADMIN_EMAIL_ENV} is not set, no account is granted the admin role.");
+        return Ok(());
+    };
+    db.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "UPDATE users SET role_id = (SELECT id FROM roles WHERE name = $1) WHERE email = $2",
+        [ADMIN_ROLE.into(), email.into()],
+    ))
+    .await?;
+    Ok(())
+}
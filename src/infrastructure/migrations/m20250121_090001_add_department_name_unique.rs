@@ -0,0 +1,28 @@
+use sea_orm_migration::prelude::*;
+
+// Department names only have to be unique among the active departments of an organization, so
+// the name of a deleted department can be reused.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_departments_organization_id_name \
+                 ON departments (organization_id, name) WHERE status = 1",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS idx_departments_organization_id_name")
+            .await?;
+        Ok(())
+    }
+}
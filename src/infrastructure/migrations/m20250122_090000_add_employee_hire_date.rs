@@ -0,0 +1,28 @@
+use sea_orm_migration::prelude::*;
+
+// Leave accrual, probation and attendance count from the hire date, existing employees are
+// taken as hired on the day they were created.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "ALTER TABLE employees ADD COLUMN IF NOT EXISTS hire_date date DEFAULT CURRENT_DATE",
+        )
+        .await?;
+        db.execute_unprepared("UPDATE employees SET hire_date = create_at::date").await?;
+        db.execute_unprepared("ALTER TABLE employees ALTER COLUMN hire_date SET NOT NULL").await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE employees DROP COLUMN IF EXISTS hire_date")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+// Leave type names only have to be unique among the active leave types of an organization, so
+// the name of a deactivated leave type can be reused.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_leave_types_organization_id_name").await?;
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_leave_types_organization_id_name \
+             ON leave_types (organization_id, name) WHERE status = 1",
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_leave_types_organization_id_name").await?;
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_leave_types_organization_id_name \
+             ON leave_types (organization_id, name)",
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -8,6 +8,37 @@ mod m20241230_143101_create_organization_table;
 mod m20241230_143104_create_employee_department_table;
 mod m20241230_143105_create_employee_position_table;
 mod m20241230_143106_create_employee_table;
+mod m20250104_100000_create_role_table;
+mod m20250104_100001_create_permission_table;
+mod m20250104_100002_create_role_permission_table;
+mod m20250104_100003_seed_role_permission;
+mod m20250110_090000_seed_department_permission;
+mod m20250111_090000_alter_position_name_unique;
+mod m20250111_090001_seed_position_permission;
+mod m20250112_090000_add_organization_setting;
+mod m20250112_090001_seed_organization_permission;
+mod m20250113_090000_create_audit_log_table;
+mod m20250113_090001_seed_audit_log_permission;
+mod m20250114_090000_add_employee_assignment_history;
+mod m20250115_090000_add_department_parent;
+mod m20250116_090000_add_employee_manager;
+mod m20250117_090000_add_department_head;
+mod m20250117_090001_create_leave_table;
+mod m20250117_090002_seed_leave_permission;
+mod m20250118_090000_add_organization_working_hours;
+mod m20250118_090001_create_attendance_table;
+mod m20250118_090002_seed_attendance_permission;
+mod m20250119_090000_add_organization_max_weekly_hours;
+mod m20250119_090001_create_shift_table;
+mod m20250119_090002_seed_shift_permission;
+mod m20250120_090000_create_holiday_table;
+mod m20250120_090001_seed_calendar_permission;
+mod m20250121_090000_seed_employee_role_and_admin;
+mod m20250121_090001_add_department_name_unique;
+mod m20250122_090000_add_employee_hire_date;
+mod m20250122_090001_alter_leave_type_name_unique;
+mod m20250122_090002_alter_shift_template_name_unique;
+mod m20250122_090003_alter_position_name_unique_active;
 
 pub struct Migrator;
 
@@ -23,6 +54,37 @@ impl MigratorTrait for Migrator {
             Box::new(m20241230_143106_create_employee_table::Migration),
             Box::new(m20241230_143104_create_employee_department_table::Migration),
             Box::new(m20241230_143105_create_employee_position_table::Migration),
+            Box::new(m20250104_100000_create_role_table::Migration),
+            Box::new(m20250104_100001_create_permission_table::Migration),
+            Box::new(m20250104_100002_create_role_permission_table::Migration),
+            Box::new(m20250104_100003_seed_role_permission::Migration),
+            Box::new(m20250110_090000_seed_department_permission::Migration),
+            Box::new(m20250111_090000_alter_position_name_unique::Migration),
+            Box::new(m20250111_090001_seed_position_permission::Migration),
+            Box::new(m20250112_090000_add_organization_setting::Migration),
+            Box::new(m20250112_090001_seed_organization_permission::Migration),
+            Box::new(m20250113_090000_create_audit_log_table::Migration),
+            Box::new(m20250113_090001_seed_audit_log_permission::Migration),
+            Box::new(m20250114_090000_add_employee_assignment_history::Migration),
+            Box::new(m20250115_090000_add_department_parent::Migration),
+            Box::new(m20250116_090000_add_employee_manager::Migration),
+            Box::new(m20250117_090000_add_department_head::Migration),
+            Box::new(m20250117_090001_create_leave_table::Migration),
+            Box::new(m20250117_090002_seed_leave_permission::Migration),
+            Box::new(m20250118_090000_add_organization_working_hours::Migration),
+            Box::new(m20250118_090001_create_attendance_table::Migration),
+            Box::new(m20250118_090002_seed_attendance_permission::Migration),
+            Box::new(m20250119_090000_add_organization_max_weekly_hours::Migration),
+            Box::new(m20250119_090001_create_shift_table::Migration),
+            Box::new(m20250119_090002_seed_shift_permission::Migration),
+            Box::new(m20250120_090000_create_holiday_table::Migration),
+            Box::new(m20250120_090001_seed_calendar_permission::Migration),
+            Box::new(m20250121_090000_seed_employee_role_and_admin::Migration),
+            Box::new(m20250121_090001_add_department_name_unique::Migration),
+            Box::new(m20250122_090000_add_employee_hire_date::Migration),
+            Box::new(m20250122_090001_alter_leave_type_name_unique::Migration),
+            Box::new(m20250122_090002_alter_shift_template_name_unique::Migration),
+            Box::new(m20250122_090003_alter_position_name_unique_active::Migration),
         ]
     }
 }
@@ -0,0 +1,105 @@
+use crate::domain::attendance;
+use crate::domain::entity::AttendanceEventEntity;
+use crate::domain::model::AttendanceEventModel;
+use crate::infrastructure::persistence::repo_interface::WriteRepository;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait,
+    IntoActiveModel, NotSet, QueryFilter, QueryOrder,
+};
+use uuid::Uuid;
+
+#[async_trait]
+impl WriteRepository<AttendanceEventEntity> for AttendanceEventEntity {
+    async fn create(conn: &DatabaseTransaction, model: &AttendanceEventModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        match AttendanceEventEntity::insert(active_model).exec(conn).await {
+            Ok(result) => Some(result.last_insert_id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: AttendanceEventModel) -> Option<i64> {
+        match model.into_active_model().reset_all().update(conn).await {
+            Ok(result) => Some(result.id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+impl AttendanceEventEntity {
+    pub async fn find_by_uuid<DB>(conn: &DB, uuid: &Uuid) -> Option<AttendanceEventModel>
+    where
+        DB: ConnectionTrait,
+    {
+        let event = AttendanceEventEntity::find()
+            .filter(attendance::Column::AttendanceEventUuid.eq(*uuid))
+            .one(conn)
+            .await;
+        match event {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Latest active event of the employee, used to find a day still open after midnight.
+    pub async fn find_latest_by_employee<DB>(
+        conn: &DB,
+        employee_id: i64,
+    ) -> Option<AttendanceEventModel>
+    where
+        DB: ConnectionTrait,
+    {
+        let event = AttendanceEventEntity::find()
+            .filter(attendance::Column::EmployeeId.eq(employee_id))
+            .filter(attendance::Column::Status.eq(1))
+            .order_by_desc(attendance::Column::OccurredAt)
+            .one(conn)
+            .await;
+        match event {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Active events of the employees for the days between both days, sorted by time.
+    pub async fn find_by_employees_between<DB>(
+        conn: &DB,
+        employee_ids: &[i64],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Option<Vec<AttendanceEventModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let events = AttendanceEventEntity::find()
+            .filter(attendance::Column::EmployeeId.is_in(employee_ids.to_vec()))
+            .filter(attendance::Column::Status.eq(1))
+            .filter(attendance::Column::WorkDate.between(from, to))
+            .order_by_asc(attendance::Column::OccurredAt)
+            .order_by_asc(attendance::Column::Id)
+            .all(conn)
+            .await;
+        match events {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::core::error::AppResult;
+use crate::domain::audit_log;
+use crate::domain::audit_log::request::AuditLogQueryParam;
+use crate::domain::entity::AuditLogEntity;
+use crate::domain::model::AuditLogModel;
+use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam, Paginated};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, NotSet,
+    QueryFilter,
+};
+
+// Audit entries are append only, they are never updated nor deleted by the application.
+impl AuditLogEntity {
+    pub async fn append(conn: &DatabaseTransaction, model: &AuditLogModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        match AuditLogEntity::insert(active_model).exec(conn).await {
+            Ok(result) => Some(result.last_insert_id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    pub async fn search<DB>(
+        conn: &DB,
+        query: &AuditLogQueryParam,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<AuditLogModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let mut select = AuditLogEntity::find();
+        if let Some(actor_uuid) = query.actor_uuid {
+            select = select.filter(audit_log::Column::ActorUuid.eq(actor_uuid));
+        }
+        if let Some(action) = query.action {
+            select = select.filter(audit_log::Column::Action.eq(action.to_string()));
+        }
+        if let Some(entity_type) = query.entity_type {
+            select = select.filter(audit_log::Column::EntityType.eq(entity_type.to_string()));
+        }
+        if let Some(entity_uuid) = query.entity_uuid {
+            select = select.filter(audit_log::Column::EntityUuid.eq(entity_uuid));
+        }
+        if let Some(request_id) = &query.request_id {
+            select = select.filter(audit_log::Column::RequestId.eq(request_id));
+        }
+        if let Some(from) = query.from {
+            select = select.filter(audit_log::Column::CreateAt.gte(from));
+        }
+        if let Some(to) = query.to {
+            select = select.filter(audit_log::Column::CreateAt.lt(to));
+        }
+        sort_and_paginate(conn, &mut select, query_params, EModule::AuditLog).await
+    }
+}
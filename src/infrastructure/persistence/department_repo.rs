@@ -1,16 +1,19 @@
 use crate::core::error::{AppError, AppResult};
-use crate::domain::entity::{DepartmentEntity, OrganizationEntity, PositionEntity};
+use crate::domain::entity::{DepartmentEntity, OrganizationEntity};
 use crate::domain::model::DepartmentModel;
 use crate::domain::{department, organization, position};
 use crate::infrastructure::persistence::repo_interface::{
     DeleteRepository, ReadRepository, WriteRepository,
 };
-use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam};
+use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam, Paginated};
 use async_trait::async_trait;
+use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction, EntityTrait,
-    FromQueryResult, IntoActiveModel, QueryFilter,
+    ActiveModelTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, FromQueryResult, IntoActiveModel, JoinType, NotSet, PaginatorTrait, QueryFilter,
+    QueryOrder, RelationTrait, Statement,
 };
+use sea_orm::sea_query::{Expr, IntoCondition};
 use sea_orm::{ColumnTrait, EntityOrSelect, QuerySelect};
 use std::cell::RefCell;
 use uuid::Uuid;
@@ -50,25 +53,15 @@ impl ReadRepository<DepartmentEntity> for DepartmentEntity {
         department.unwrap_or_default()
     }
 
-    async fn find_all<DB>(conn: &DB, query_params: PageQueryParam) -> Option<Vec<DepartmentModel>>
+    async fn find_all<DB>(
+        conn: &DB,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<DepartmentModel>>
     where
         DB: ConnectionTrait,
     {
-        let departments = sort_and_paginate(
-            conn,
-            &mut DepartmentEntity::find(),
-            query_params,
-            EModule::Department,
-        )
-        .await;
-        if departments.is_err() {
-            tracing::error!(
-                "Something happen when query database: {:#?}.",
-                departments.unwrap_err()
-            );
-            return None;
-        };
-        Some(departments.unwrap_or_default())
+        sort_and_paginate(conn, &mut DepartmentEntity::find(), query_params, EModule::Department)
+            .await
     }
 
     async fn find_data_by_name<DB>(conn: &DB, name: &str) -> Option<DepartmentModel>
@@ -88,33 +81,35 @@ impl ReadRepository<DepartmentEntity> for DepartmentEntity {
     }
 }
 
-// #[async_trait]
-// impl WriteRepository<DepartmentEntity> for DepartmentEntity {
-//     async fn create(conn: &DatabaseTransaction, model: &DepartmentModel) -> Option<i64> {
-//         let department = DepartmentEntity::insert(model.clone().into_active_model()).exec(conn).await;
-//         if department.is_err() {
-//             tracing::error!(
-//                 "Something happen when query database: {:#?}.",
-//                 department.unwrap_err()
-//             );
-//             return None;
-//         };
-//         Some(department.unwrap().last_insert_id)
-//     }
-//
-//     async fn update(conn: &DatabaseTransaction, model: DepartmentModel) -> Option<i64> {
-//         let department_update = model.into_active_model().save(conn).await;
-//         if department_update.is_err() {
-//             tracing::error!(
-//                 "Something happen when query database: {:#?}.",
-//                 department_update.unwrap_err()
-//             );
-//             return None;
-//         };
-//
-//         Some(department_update.unwrap().id.unwrap())
-//     }
-// }
+#[async_trait]
+impl WriteRepository<DepartmentEntity> for DepartmentEntity {
+    async fn create(conn: &DatabaseTransaction, model: &DepartmentModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        let department = DepartmentEntity::insert(active_model).exec(conn).await;
+        if department.is_err() {
+            tracing::error!(
+                "Something happen when query database: {:#?}.",
+                department.unwrap_err()
+            );
+            return None;
+        };
+        Some(department.unwrap().last_insert_id)
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: DepartmentModel) -> Option<i64> {
+        let department_update = model.into_active_model().reset_all().update(conn).await;
+        if department_update.is_err() {
+            tracing::error!(
+                "Something happen when query database: {:#?}.",
+                department_update.unwrap_err()
+            );
+            return None;
+        };
+
+        Some(department_update.unwrap().id)
+    }
+}
 
 #[async_trait]
 impl DeleteRepository<DepartmentEntity> for DepartmentEntity {
@@ -123,12 +118,13 @@ impl DeleteRepository<DepartmentEntity> for DepartmentEntity {
             .filter(department::Column::DepartmentUuid.eq(uuid))
             .one(conn)
             .await;
-        if department.is_err() {
-            return None;
-        }
-        let mut department = department.unwrap().unwrap();
+        let mut department = match department {
+            Ok(Some(department)) => department,
+            _ => return None,
+        };
         department.status = Some(0);
-        let department_delete = department.into_active_model().save(conn).await;
+        department.update_at = Utc::now().naive_utc();
+        let department_delete = department.into_active_model().reset_all().update(conn).await;
         if department_delete.is_err() {
             tracing::error!(
                 "Something happen when query database: {:#?}.",
@@ -137,40 +133,97 @@ impl DeleteRepository<DepartmentEntity> for DepartmentEntity {
             return None;
         }
 
-        Some(department_delete.unwrap().id.unwrap())
+        Some(department_delete.unwrap().id)
     }
 }
 
+// One row per (department, position) of an organization, departments without any active
+// position are returned once with empty position columns.
 #[derive(Debug, FromQueryResult)]
-struct Metadata {
+pub struct Metadata {
     pub organization_id: i64,
-    pub orgaization_name: String,
+    pub organization_name: String,
     pub department_id: i64,
+    pub parent_department_id: Option<i64>,
     pub department_uuid: Uuid,
     pub department_name: String,
-    pub position_id: i64,
-    pub position_uuid: Uuid,
-    pub position_name: String,
+    pub position_id: Option<i64>,
+    pub position_uuid: Option<Uuid>,
+    pub position_name: Option<String>,
 }
+
 impl DepartmentEntity {
-    pub async fn get_important_information(
-        conn: &DatabaseTransaction,
+    pub async fn find_by_organization<DB>(
+        conn: &DB,
+        organization_id: i64,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<DepartmentModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let mut select = DepartmentEntity::find()
+            .filter(department::Column::OrganizationId.eq(organization_id))
+            .filter(department::Column::Status.eq(1));
+        sort_and_paginate(conn, &mut select, query_params, EModule::Department).await
+    }
+
+    // Active department of the organization with the name, deleted names can be reused.
+    pub async fn find_by_organization_and_name<DB>(
+        conn: &DB,
+        organization_id: i64,
+        name: &str,
+    ) -> Option<DepartmentModel>
+    where
+        DB: ConnectionTrait,
+    {
+        let department = DepartmentEntity::find()
+            .filter(department::Column::OrganizationId.eq(organization_id))
+            .filter(department::Column::Name.eq(name))
+            .filter(department::Column::Status.eq(1))
+            .one(conn)
+            .await;
+        if department.is_err() {
+            tracing::error!(
+                "Something happen when query database: {:#?}.",
+                department.unwrap_err()
+            );
+            return None;
+        };
+        department.unwrap_or_default()
+    }
+
+    pub async fn get_important_information<DB>(
+        conn: &DB,
         organization_uuid: Uuid,
-    ) -> Option<Vec<Metadata>> {
+    ) -> Option<Vec<Metadata>>
+    where
+        DB: ConnectionTrait,
+    {
         let select = DepartmentEntity::find()
             .filter(organization::Column::OrganizationUuid.eq(organization_uuid))
-            .inner_join(PositionEntity)
+            .filter(department::Column::Status.eq(1))
+            .join(
+                JoinType::LeftJoin,
+                department::Relation::Position
+                    .def()
+                    .on_condition(|_, position| {
+                        Expr::col((position, position::Column::Status)).eq(1).into_condition()
+                    }),
+            )
             .inner_join(OrganizationEntity)
             .select_only()
             .column_as(organization::Column::OrganizationUuid, "organization_uuid")
             .column_as(organization::Column::Id, "organization_id")
             .column_as(organization::Column::Name, "organization_name")
             .column_as(department::Column::Id, "department_id")
+            .column_as(department::Column::ParentDepartmentId, "parent_department_id")
             .column_as(department::Column::DepartmentUuid, "department_uuid")
             .column_as(department::Column::Name, "department_name")
             .column_as(position::Column::Id, "position_id")
             .column_as(position::Column::PositionUuid, "position_uuid")
-            .column_as(position::Column::Name, "position_name");
+            .column_as(position::Column::Name, "position_name")
+            .order_by_asc(department::Column::Id)
+            .order_by_asc(position::Column::Id);
         let results = select.into_model::<Metadata>().all(conn).await;
         if results.is_err() {
             tracing::error!("Something happen when query database: {:#?}.", results.unwrap_err());
@@ -178,4 +231,117 @@ impl DepartmentEntity {
         }
         Some(results.unwrap_or_default())
     }
+
+    pub async fn find_by_ids<DB>(conn: &DB, ids: &[i64]) -> Option<Vec<DepartmentModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        match DepartmentEntity::find()
+            .filter(department::Column::Id.is_in(ids.to_vec()))
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Every active department of the organization, used to build the department tree.
+    pub async fn find_all_by_organization<DB>(
+        conn: &DB,
+        organization_id: i64,
+    ) -> Option<Vec<DepartmentModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        match DepartmentEntity::find()
+            .filter(department::Column::OrganizationId.eq(organization_id))
+            .filter(department::Column::Status.eq(1))
+            .order_by_asc(department::Column::Id)
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Active departments directly below the given one.
+    pub async fn find_children<DB>(conn: &DB, department_id: i64) -> Option<Vec<DepartmentModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        match DepartmentEntity::find()
+            .filter(department::Column::ParentDepartmentId.eq(department_id))
+            .filter(department::Column::Status.eq(1))
+            .order_by_asc(department::Column::Id)
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // The department itself and every department below it.
+    pub async fn find_subtree<DB>(conn: &DB, department_id: i64) -> Option<Vec<DepartmentModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let statement = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "WITH RECURSIVE subtree AS ( \
+                 SELECT * FROM departments WHERE id = $1 \
+                 UNION \
+                 SELECT child.* FROM departments child \
+                 INNER JOIN subtree parent ON child.parent_department_id = parent.id \
+             ) \
+             SELECT * FROM subtree ORDER BY id",
+            [department_id.into()],
+        );
+        match DepartmentEntity::find().from_raw_sql(statement).all(conn).await {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Departments above the given one, from the root down to its direct parent.
+    pub async fn find_ancestors<DB>(conn: &DB, department_id: i64) -> Option<Vec<DepartmentModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let statement = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "WITH RECURSIVE ancestors AS ( \
+                 SELECT parent.*, ARRAY[child.id, parent.id] AS path FROM departments parent \
+                 INNER JOIN departments child ON child.parent_department_id = parent.id \
+                 WHERE child.id = $1 \
+                 UNION ALL \
+                 SELECT parent.*, ancestor.path || parent.id FROM departments parent \
+                 INNER JOIN ancestors ancestor ON ancestor.parent_department_id = parent.id \
+                 WHERE NOT parent.id = ANY(ancestor.path) \
+             ) \
+             SELECT * FROM ancestors ORDER BY array_length(path, 1) DESC",
+            [department_id.into()],
+        );
+        match DepartmentEntity::find().from_raw_sql(statement).all(conn).await {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
 }
@@ -1,15 +1,31 @@
-use crate::domain::entity::{DepartmentEntity, EmployeeEntity};
-use crate::domain::model::EmployeeModel;
-use crate::domain::{department, employee};
+use crate::core::error::AppResult;
+use crate::domain::employee::request::EmployeeQueryParam;
+use crate::domain::employee::{
+    employee_department, employee_position, Assignment, AssignmentEntity,
+};
+use crate::domain::entity::{
+    DepartmentEntity, EmployeeDepartmentEntity, EmployeeEntity, EmployeePositionEntity,
+    PositionEntity, UserEntity,
+};
+use crate::domain::model::{
+    DepartmentModel, EmployeeDepartmentModel, EmployeeModel, EmployeePositionModel, PositionModel,
+};
+use crate::domain::{department, employee, position, user};
 use crate::infrastructure::persistence::repo_interface::{
     DeleteRepository, ReadRepository, WriteRepository,
 };
-use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam};
+use crate::util::filter_and_pagination::{
+    apply_filter, escape_like, sort_and_paginate, EModule, PageQueryParam, Paginated,
+};
 use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
 use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::extension::postgres::PgExpr;
+use sea_orm::sea_query::Expr;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseTransaction,
-    EntityTrait, IntoActiveModel, QueryFilter, QueryTrait,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseBackend,
+    DatabaseTransaction, EntityTrait, FromQueryResult, IntoActiveModel, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, QueryTrait, RelationTrait, Select, Statement,
 };
 use std::cell::RefCell;
 use std::fmt::Debug;
@@ -42,21 +58,14 @@ impl ReadRepository<EmployeeEntity> for EmployeeEntity {
         user.unwrap_or_default()
     }
 
-    async fn find_all<DB>(conn: &DB, query_params: PageQueryParam) -> Option<Vec<EmployeeModel>>
+    async fn find_all<DB>(
+        conn: &DB,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<EmployeeModel>>
     where
         DB: ConnectionTrait + Debug,
     {
-        let employees = sort_and_paginate(
-            conn,
-            &mut EmployeeEntity::find(),
-            query_params,
-            EModule::Employee,
-        ).await;
-        if employees.is_err() {
-            tracing::error!("Something happen when query database: {:#?}.", employees.unwrap_err());
-            return None;
-        };
-        Some(employees.unwrap_or_default())
+        sort_and_paginate(conn, &mut EmployeeEntity::find(), query_params, EModule::Employee).await
     }
 
     async fn find_data_by_name<DB>(conn: &DB, name: &str) -> Option<EmployeeModel>
@@ -119,3 +128,438 @@ impl DeleteRepository<EmployeeEntity> for EmployeeEntity {
         Some(employee_delete.unwrap().id.unwrap())
     }
 }
+
+impl EmployeeEntity {
+    pub async fn search<DB>(
+        conn: &DB,
+        query: &EmployeeQueryParam,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<EmployeeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let mut select = Self::search_query(query);
+        sort_and_paginate(conn, &mut select, query_params, EModule::Employee).await
+    }
+
+    // Employees matching the search and the filter of an export, the caller reads them in
+    // batches.
+    pub fn select_for_export(
+        query: &EmployeeQueryParam,
+        query_params: &PageQueryParam,
+    ) -> AppResult<Select<EmployeeEntity>> {
+        apply_filter(Self::search_query(query), query_params, EModule::Employee)
+    }
+
+    fn search_query(query: &EmployeeQueryParam) -> Select<EmployeeEntity> {
+        let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+        let mut select = EmployeeEntity::find().inner_join(UserEntity);
+        if let Some(department_uuid) = query.department_uuid {
+            select = select.filter(
+                employee::Column::Id.in_subquery(
+                    EmployeeDepartmentEntity::find()
+                        .select_only()
+                        .column(employee_department::Column::EmployeeId)
+                        .inner_join(DepartmentEntity)
+                        .filter(department::Column::DepartmentUuid.eq(department_uuid))
+                        .filter(EmployeeDepartmentEntity::active_on(as_of))
+                        .into_query(),
+                ),
+            );
+        }
+        if let Some(position_uuid) = query.position_uuid {
+            select = select.filter(
+                employee::Column::Id.in_subquery(
+                    EmployeePositionEntity::find()
+                        .select_only()
+                        .column(employee_position::Column::EmployeeId)
+                        .inner_join(PositionEntity)
+                        .filter(position::Column::PositionUuid.eq(position_uuid))
+                        .filter(EmployeePositionEntity::active_on(as_of))
+                        .into_query(),
+                ),
+            );
+        }
+        if let Some(status) = query.status {
+            select = select.filter(employee::Column::Status.eq(status));
+        }
+        if let Some(name) = &query.name {
+            let pattern = escape_like(name);
+            select = select.filter(
+                Expr::col((UserEntity, user::Column::FullName)).ilike(format!("%{pattern}%")),
+            );
+        }
+        select
+    }
+
+    // Departments of the employees on the given day, the primary assignment comes first.
+    pub async fn find_departments<DB>(
+        conn: &DB,
+        employee_ids: &[i64],
+        as_of: NaiveDate,
+    ) -> Option<Vec<(i64, DepartmentModel)>>
+    where
+        DB: ConnectionTrait,
+    {
+        let departments = EmployeeDepartmentEntity::find()
+            .filter(employee_department::Column::EmployeeId.is_in(employee_ids.to_vec()))
+            .filter(EmployeeDepartmentEntity::active_on(as_of))
+            .order_by_desc(employee_department::Column::IsPrimary)
+            .order_by_asc(employee_department::Column::Id)
+            .find_also_related(DepartmentEntity)
+            .all(conn)
+            .await;
+        if departments.is_err() {
+            tracing::error!(
+                "Something happen when query database: {:#?}.",
+                departments.unwrap_err()
+            );
+            return None;
+        };
+        Some(
+            departments
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(link, department)| Some((link.employee_id, department?)))
+                .collect(),
+        )
+    }
+
+    // Positions of the employees on the given day, the primary assignment comes first.
+    pub async fn find_positions<DB>(
+        conn: &DB,
+        employee_ids: &[i64],
+        as_of: NaiveDate,
+    ) -> Option<Vec<(i64, PositionModel)>>
+    where
+        DB: ConnectionTrait,
+    {
+        let positions = EmployeePositionEntity::find()
+            .filter(employee_position::Column::EmployeeId.is_in(employee_ids.to_vec()))
+            .filter(EmployeePositionEntity::active_on(as_of))
+            .order_by_desc(employee_position::Column::IsPrimary)
+            .order_by_asc(employee_position::Column::Id)
+            .find_also_related(PositionEntity)
+            .all(conn)
+            .await;
+        if positions.is_err() {
+            tracing::error!("Something happen when query database: {:#?}.", positions.unwrap_err());
+            return None;
+        };
+        Some(
+            positions
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(link, position)| Some((link.employee_id, position?)))
+                .collect(),
+        )
+    }
+
+    // Every department assignment of the employee, latest first.
+    pub async fn find_department_history<DB>(
+        conn: &DB,
+        employee_id: i64,
+    ) -> Option<Vec<(EmployeeDepartmentModel, DepartmentModel)>>
+    where
+        DB: ConnectionTrait,
+    {
+        let departments = EmployeeDepartmentEntity::find()
+            .filter(employee_department::Column::EmployeeId.eq(employee_id))
+            .order_by_desc(employee_department::Column::EffectiveFrom)
+            .order_by_desc(employee_department::Column::Id)
+            .find_also_related(DepartmentEntity)
+            .all(conn)
+            .await;
+        if departments.is_err() {
+            tracing::error!(
+                "Something happen when query database: {:#?}.",
+                departments.unwrap_err()
+            );
+            return None;
+        };
+        Some(
+            departments
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(link, department)| Some((link, department?)))
+                .collect(),
+        )
+    }
+
+    // Every position assignment of the employee, latest first.
+    pub async fn find_position_history<DB>(
+        conn: &DB,
+        employee_id: i64,
+    ) -> Option<Vec<(EmployeePositionModel, PositionModel)>>
+    where
+        DB: ConnectionTrait,
+    {
+        let positions = EmployeePositionEntity::find()
+            .filter(employee_position::Column::EmployeeId.eq(employee_id))
+            .order_by_desc(employee_position::Column::EffectiveFrom)
+            .order_by_desc(employee_position::Column::Id)
+            .find_also_related(PositionEntity)
+            .all(conn)
+            .await;
+        if positions.is_err() {
+            tracing::error!("Something happen when query database: {:#?}.", positions.unwrap_err());
+            return None;
+        };
+        Some(
+            positions
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(link, position)| Some((link, position?)))
+                .collect(),
+        )
+    }
+
+    // Links of the employee in effect on `from` or starting later, including future dated ones.
+    pub async fn find_assignments_since<E>(
+        conn: &DatabaseTransaction,
+        employee_id: i64,
+        from: NaiveDate,
+    ) -> Option<Vec<Assignment>>
+    where
+        E: AssignmentEntity,
+    {
+        match E::find()
+            .filter(E::employee_id_column().eq(employee_id))
+            .filter(
+                Condition::any()
+                    .add(E::effective_to_column().is_null())
+                    .add(E::effective_to_column().gte(from)),
+            )
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result.iter().map(E::to_assignment).collect()),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    pub async fn find_by_user_id<DB>(conn: &DB, user_id: i64) -> Option<EmployeeModel>
+    where
+        DB: ConnectionTrait,
+    {
+        match EmployeeEntity::find().filter(employee::Column::UserId.eq(user_id)).one(conn).await {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    pub async fn find_by_ids<DB>(conn: &DB, ids: &[i64]) -> Option<Vec<EmployeeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        match EmployeeEntity::find()
+            .filter(employee::Column::Id.is_in(ids.to_vec()))
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Reads the employees with SELECT ... FOR UPDATE in id order, holding the rows until the
+    // transaction ends.
+    pub async fn lock_by_ids(
+        conn: &DatabaseTransaction,
+        ids: &[i64],
+    ) -> Option<Vec<EmployeeModel>> {
+        match EmployeeEntity::find()
+            .filter(employee::Column::Id.is_in(ids.to_vec()))
+            .order_by_asc(employee::Column::Id)
+            .lock_exclusive()
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Active employees assigned to the department on the given day.
+    pub async fn find_by_department<DB>(
+        conn: &DB,
+        department_id: i64,
+        as_of: NaiveDate,
+    ) -> Option<Vec<EmployeeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        match EmployeeEntity::find()
+            .filter(
+                employee::Column::Id.in_subquery(
+                    EmployeeDepartmentEntity::find()
+                        .select_only()
+                        .column(employee_department::Column::EmployeeId)
+                        .filter(employee_department::Column::DepartmentId.eq(department_id))
+                        .filter(EmployeeDepartmentEntity::active_on(as_of))
+                        .into_query(),
+                ),
+            )
+            .filter(employee::Column::Status.eq(1))
+            .order_by_asc(employee::Column::Id)
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Active employees reporting directly to the manager.
+    pub async fn find_direct_reports<DB>(conn: &DB, manager_id: i64) -> Option<Vec<EmployeeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        match EmployeeEntity::find()
+            .filter(employee::Column::ManagerEmployeeId.eq(manager_id))
+            .filter(employee::Column::Status.eq(1))
+            .order_by_asc(employee::Column::Id)
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Employees reporting directly to the manager, whatever their status.
+    pub async fn find_all_direct_reports<DB>(
+        conn: &DB,
+        manager_id: i64,
+    ) -> Option<Vec<EmployeeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        match EmployeeEntity::find()
+            .filter(employee::Column::ManagerEmployeeId.eq(manager_id))
+            .order_by_asc(employee::Column::Id)
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // The employee itself and every employee reporting to it, directly or not.
+    pub async fn find_reporting_subtree<DB>(
+        conn: &DB,
+        employee_id: i64,
+    ) -> Option<Vec<EmployeeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let statement = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "WITH RECURSIVE subtree AS ( \
+                 SELECT * FROM employees WHERE id = $1 \
+                 UNION \
+                 SELECT report.* FROM employees report \
+                 INNER JOIN subtree manager ON report.manager_employee_id = manager.id \
+             ) \
+             SELECT * FROM subtree ORDER BY id",
+            [employee_id.into()],
+        );
+        match EmployeeEntity::find().from_raw_sql(statement).all(conn).await {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Managers above the employee, from its direct manager up to the top.
+    pub async fn find_chain_of_command<DB>(
+        conn: &DB,
+        employee_id: i64,
+    ) -> Option<Vec<EmployeeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let statement = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "WITH RECURSIVE chain AS ( \
+                 SELECT manager.*, ARRAY[report.id, manager.id] AS path FROM employees manager \
+                 INNER JOIN employees report ON report.manager_employee_id = manager.id \
+                 WHERE report.id = $1 \
+                 UNION ALL \
+                 SELECT manager.*, chain.path || manager.id FROM employees manager \
+                 INNER JOIN chain ON chain.manager_employee_id = manager.id \
+                 WHERE NOT manager.id = ANY(chain.path) \
+             ) \
+             SELECT * FROM chain ORDER BY array_length(path, 1)",
+            [employee_id.into()],
+        );
+        match EmployeeEntity::find().from_raw_sql(statement).all(conn).await {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Active employees holding the positions on the given day, ordered by name.
+    pub async fn find_position_holders<DB>(
+        conn: &DB,
+        position_ids: &[i64],
+        as_of: NaiveDate,
+    ) -> Option<Vec<PositionHolder>>
+    where
+        DB: ConnectionTrait,
+    {
+        let select = EmployeePositionEntity::find()
+            .filter(employee_position::Column::PositionId.is_in(position_ids.to_vec()))
+            .filter(EmployeePositionEntity::active_on(as_of))
+            .join(JoinType::InnerJoin, employee_position::Relation::Employee.def())
+            .join(JoinType::InnerJoin, employee::Relation::User.def())
+            .filter(employee::Column::Status.eq(1))
+            .select_only()
+            .column_as(employee_position::Column::PositionId, "position_id")
+            .column_as(employee::Column::EmployeeUuid, "employee_uuid")
+            .column_as(user::Column::FullName, "full_name")
+            .order_by_asc(user::Column::FullName)
+            .order_by_asc(employee::Column::Id);
+        match select.into_model::<PositionHolder>().all(conn).await {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+pub struct PositionHolder {
+    pub position_id: i64,
+    pub employee_uuid: Uuid,
+    pub full_name: String,
+}
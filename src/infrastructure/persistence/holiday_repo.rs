@@ -0,0 +1,132 @@
+use crate::core::error::AppResult;
+use crate::domain::calendar;
+use crate::domain::entity::HolidayEntity;
+use crate::domain::model::HolidayModel;
+use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
+use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam, Paginated};
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait,
+    IntoActiveModel, NotSet, QueryFilter, QueryOrder,
+};
+use std::fmt::Debug;
+use uuid::Uuid;
+
+#[async_trait]
+impl ReadRepository<HolidayEntity> for HolidayEntity {
+    async fn find_data_by_id<DB>(conn: &DB, id: i64) -> Option<HolidayModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match HolidayEntity::find_by_id(id).one(conn).await {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn find_data_by_uuid<DB>(conn: &DB, uuid: &Uuid) -> Option<HolidayModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        let holiday = HolidayEntity::find()
+            .filter(calendar::Column::HolidayUuid.eq(*uuid))
+            .one(conn)
+            .await;
+        match holiday {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn find_all<DB>(
+        conn: &DB,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<HolidayModel>>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        let mut select = HolidayEntity::find();
+        sort_and_paginate(conn, &mut select, query_params, EModule::Holiday).await
+    }
+
+    async fn find_data_by_name<DB>(conn: &DB, name: &str) -> Option<HolidayModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match HolidayEntity::find().filter(calendar::Column::Name.eq(name)).one(conn).await {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl WriteRepository<HolidayEntity> for HolidayEntity {
+    async fn create(conn: &DatabaseTransaction, model: &HolidayModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        match HolidayEntity::insert(active_model).exec(conn).await {
+            Ok(result) => Some(result.last_insert_id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: HolidayModel) -> Option<i64> {
+        match model.into_active_model().reset_all().update(conn).await {
+            Ok(result) => Some(result.id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+impl HolidayEntity {
+    pub async fn find_by_organization<DB>(
+        conn: &DB,
+        organization_id: i64,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<HolidayModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let mut select =
+            HolidayEntity::find().filter(calendar::Column::OrganizationId.eq(organization_id));
+        sort_and_paginate(conn, &mut select, query_params, EModule::Holiday).await
+    }
+
+    pub async fn find_active_by_organization<DB>(
+        conn: &DB,
+        organization_id: i64,
+    ) -> Option<Vec<HolidayModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let holidays = HolidayEntity::find()
+            .filter(calendar::Column::OrganizationId.eq(organization_id))
+            .filter(calendar::Column::Status.eq(1))
+            .order_by_asc(calendar::Column::Date)
+            .all(conn)
+            .await;
+        match holidays {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
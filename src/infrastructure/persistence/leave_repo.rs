@@ -0,0 +1,324 @@
+use crate::core::error::AppResult;
+use crate::domain::entity::{LeaveRequestEntity, LeaveTypeEntity};
+use crate::domain::leave::{leave_request, ELeaveRequestStatus};
+use crate::domain::model::{LeaveRequestModel, LeaveTypeModel};
+use crate::domain::leave;
+use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
+use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam, Paginated};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait,
+    IntoActiveModel, NotSet, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+};
+use std::fmt::Debug;
+use uuid::Uuid;
+
+#[async_trait]
+impl ReadRepository<LeaveTypeEntity> for LeaveTypeEntity {
+    async fn find_data_by_id<DB>(conn: &DB, id: i64) -> Option<LeaveTypeModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match LeaveTypeEntity::find_by_id(id).one(conn).await {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn find_data_by_uuid<DB>(conn: &DB, uuid: &Uuid) -> Option<LeaveTypeModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        let leave_type =
+            LeaveTypeEntity::find().filter(leave::Column::LeaveTypeUuid.eq(*uuid)).one(conn).await;
+        match leave_type {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn find_all<DB>(
+        conn: &DB,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<LeaveTypeModel>>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        sort_and_paginate(conn, &mut LeaveTypeEntity::find(), query_params, EModule::LeaveType)
+            .await
+    }
+
+    async fn find_data_by_name<DB>(conn: &DB, name: &str) -> Option<LeaveTypeModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match LeaveTypeEntity::find().filter(leave::Column::Name.eq(name)).one(conn).await {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl WriteRepository<LeaveTypeEntity> for LeaveTypeEntity {
+    async fn create(conn: &DatabaseTransaction, model: &LeaveTypeModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        match LeaveTypeEntity::insert(active_model).exec(conn).await {
+            Ok(result) => Some(result.last_insert_id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: LeaveTypeModel) -> Option<i64> {
+        match model.into_active_model().reset_all().update(conn).await {
+            Ok(result) => Some(result.id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+impl LeaveTypeEntity {
+    pub async fn find_by_organization<DB>(
+        conn: &DB,
+        organization_id: i64,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<LeaveTypeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let mut select =
+            LeaveTypeEntity::find().filter(leave::Column::OrganizationId.eq(organization_id));
+        sort_and_paginate(conn, &mut select, query_params, EModule::LeaveType).await
+    }
+
+    pub async fn find_active_by_organization<DB>(
+        conn: &DB,
+        organization_id: i64,
+    ) -> Option<Vec<LeaveTypeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let leave_types = LeaveTypeEntity::find()
+            .filter(leave::Column::OrganizationId.eq(organization_id))
+            .filter(leave::Column::Status.eq(1))
+            .order_by_asc(leave::Column::Id)
+            .all(conn)
+            .await;
+        match leave_types {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Active leave type of the organization with the name, deactivated names can be reused.
+    pub async fn find_by_organization_and_name<DB>(
+        conn: &DB,
+        organization_id: i64,
+        name: &str,
+    ) -> Option<LeaveTypeModel>
+    where
+        DB: ConnectionTrait,
+    {
+        let leave_type = LeaveTypeEntity::find()
+            .filter(leave::Column::OrganizationId.eq(organization_id))
+            .filter(leave::Column::Name.eq(name))
+            .filter(leave::Column::Status.eq(1))
+            .one(conn)
+            .await;
+        match leave_type {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    pub async fn find_by_ids<DB>(conn: &DB, ids: &[i64]) -> Option<Vec<LeaveTypeModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        match LeaveTypeEntity::find().filter(leave::Column::Id.is_in(ids.to_vec())).all(conn).await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl WriteRepository<LeaveRequestEntity> for LeaveRequestEntity {
+    async fn create(conn: &DatabaseTransaction, model: &LeaveRequestModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        match LeaveRequestEntity::insert(active_model).exec(conn).await {
+            Ok(result) => Some(result.last_insert_id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: LeaveRequestModel) -> Option<i64> {
+        match model.into_active_model().reset_all().update(conn).await {
+            Ok(result) => Some(result.id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+impl LeaveRequestEntity {
+    pub async fn find_by_uuid<DB>(conn: &DB, uuid: &Uuid) -> Option<LeaveRequestModel>
+    where
+        DB: ConnectionTrait,
+    {
+        let leave_request = LeaveRequestEntity::find()
+            .filter(leave_request::Column::LeaveRequestUuid.eq(*uuid))
+            .one(conn)
+            .await;
+        match leave_request {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Leave requests of the organization, whose leave types belong to it.
+    pub async fn search<DB>(
+        conn: &DB,
+        organization_id: i64,
+        employee_id: Option<i64>,
+        status: Option<ELeaveRequestStatus>,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<LeaveRequestModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let mut select = LeaveRequestEntity::find().filter(
+            leave_request::Column::LeaveTypeId.in_subquery(
+                LeaveTypeEntity::find()
+                    .select_only()
+                    .column(leave::Column::Id)
+                    .filter(leave::Column::OrganizationId.eq(organization_id))
+                    .into_query(),
+            ),
+        );
+        if let Some(employee_id) = employee_id {
+            select = select.filter(leave_request::Column::EmployeeId.eq(employee_id));
+        }
+        if let Some(status) = status {
+            select = select.filter(leave_request::Column::Status.eq(status.to_string()));
+        }
+        sort_and_paginate(conn, &mut select, query_params, EModule::LeaveRequest).await
+    }
+
+    // Pending and approved leave of the employee sharing at least one day with the given range.
+    pub async fn find_overlapping<DB>(
+        conn: &DB,
+        employee_id: i64,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Option<Vec<LeaveRequestModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let leave_requests = LeaveRequestEntity::find()
+            .filter(leave_request::Column::EmployeeId.eq(employee_id))
+            .filter(leave_request::Column::Status.is_in(Self::active_statuses()))
+            .filter(leave_request::Column::StartDate.lte(end_date))
+            .filter(leave_request::Column::EndDate.gte(start_date))
+            .all(conn)
+            .await;
+        match leave_requests {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Pending and approved leave of the employee starting on or before `until`, used to compute
+    // the balances.
+    pub async fn find_active_by_employee<DB>(
+        conn: &DB,
+        employee_id: i64,
+        until: NaiveDate,
+    ) -> Option<Vec<LeaveRequestModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let leave_requests = LeaveRequestEntity::find()
+            .filter(leave_request::Column::EmployeeId.eq(employee_id))
+            .filter(leave_request::Column::Status.is_in(Self::active_statuses()))
+            .filter(leave_request::Column::StartDate.lte(until))
+            .all(conn)
+            .await;
+        match leave_requests {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Approved leave of the employees sharing at least one day with the given range.
+    pub async fn find_approved_between<DB>(
+        conn: &DB,
+        employee_ids: &[i64],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Option<Vec<LeaveRequestModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let leave_requests = LeaveRequestEntity::find()
+            .filter(leave_request::Column::EmployeeId.is_in(employee_ids.to_vec()))
+            .filter(leave_request::Column::Status.eq(ELeaveRequestStatus::Approved.to_string()))
+            .filter(leave_request::Column::StartDate.lte(to))
+            .filter(leave_request::Column::EndDate.gte(from))
+            .all(conn)
+            .await;
+        match leave_requests {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    fn active_statuses() -> [String; 2] {
+        [ELeaveRequestStatus::Pending.to_string(), ELeaveRequestStatus::Approved.to_string()]
+    }
+}
@@ -1,8 +1,14 @@
+pub mod attendance_repo;
+pub mod audit_log_repo;
 pub mod department_repo;
 pub mod employee_repo;
+pub mod holiday_repo;
+pub mod leave_repo;
 pub mod organization_repo;
 pub mod position_repo;
 pub mod postgres;
 pub mod redis_client;
 pub mod repo_interface;
+pub mod role_repo;
+pub mod shift_repo;
 pub mod user_repo;
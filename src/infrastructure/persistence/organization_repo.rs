@@ -1,10 +1,11 @@
+use crate::core::error::AppResult;
 use crate::domain::entity::OrganizationEntity;
 use crate::domain::organization;
-use crate::infrastructure::persistence::repo_interface::ReadRepository;
-use sea_orm::{ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait, QueryFilter};
+use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
+use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait, IntoActiveModel, NotSet, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
 use uuid::Uuid;
 use crate::domain::model::OrganizationModel;
-use crate::util::filter_and_pagination::PageQueryParam;
+use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam, Paginated};
 
 #[async_trait::async_trait]
 impl ReadRepository<OrganizationEntity> for OrganizationEntity {
@@ -40,9 +41,18 @@ impl ReadRepository<OrganizationEntity> for OrganizationEntity {
         organization.unwrap_or_default()
     }
 
-    async fn find_all<DB>(conn: &DB, query_params: PageQueryParam) -> Option<Vec<OrganizationModel>>
+    async fn find_all<DB>(
+        conn: &DB,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<OrganizationModel>>
     where DB: ConnectionTrait {
-        todo!()
+        sort_and_paginate(
+            conn,
+            &mut OrganizationEntity::find(),
+            query_params,
+            EModule::Organization,
+        )
+        .await
     }
 
     async fn find_data_by_name<DB>(
@@ -50,6 +60,57 @@ impl ReadRepository<OrganizationEntity> for OrganizationEntity {
         name: &str,
     ) -> Option<OrganizationModel>
     where DB: ConnectionTrait {
-        todo!()
+        let organization =
+            OrganizationEntity::find().filter(organization::Column::Name.eq(name)).one(conn).await;
+        if organization.is_err() {
+            tracing::error!(
+                "Something happen when query database: {:#?}.",
+                organization.unwrap_err()
+            );
+            return None;
+        };
+        organization.unwrap_or_default()
+    }
+}
+
+#[async_trait::async_trait]
+impl WriteRepository<OrganizationEntity> for OrganizationEntity {
+    async fn create(conn: &DatabaseTransaction, model: &OrganizationModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        let organization = OrganizationEntity::insert(active_model).exec(conn).await;
+        if organization.is_err() {
+            tracing::error!(
+                "Something happen when query database: {:#?}.",
+                organization.unwrap_err()
+            );
+            return None;
+        };
+        Some(organization.unwrap().last_insert_id)
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: OrganizationModel) -> Option<i64> {
+        let organization_update = model.into_active_model().reset_all().update(conn).await;
+        if organization_update.is_err() {
+            tracing::error!(
+                "Something happen when query database: {:#?}.",
+                organization_update.unwrap_err()
+            );
+            return None;
+        };
+        Some(organization_update.unwrap().id)
+    }
+}
+
+impl OrganizationEntity {
+    // Reads the organization with SELECT ... FOR UPDATE, holding the row until the transaction ends.
+    pub async fn lock_by_id(conn: &DatabaseTransaction, id: i64) -> Option<OrganizationModel> {
+        match OrganizationEntity::find_by_id(id).lock_exclusive().one(conn).await {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
     }
 }
@@ -7,13 +7,14 @@ use crate::infrastructure::persistence::repo_interface::{
     DeleteRepository, ReadRepository, WriteRepository,
 };
 use async_trait::async_trait;
+use chrono::Utc;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait,
-    IntoActiveModel, QueryFilter,
+    IntoActiveModel, NotSet, QueryFilter,
 };
 use std::cell::RefCell;
 use uuid::Uuid;
-use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam};
+use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam, Paginated};
 
 #[async_trait]
 impl ReadRepository<PositionEntity> for PositionEntity {
@@ -41,21 +42,14 @@ impl ReadRepository<PositionEntity> for PositionEntity {
         user.unwrap_or_default()
     }
 
-    async fn find_all<DB>(conn: &DB, query_params: PageQueryParam) -> Option<Vec<PositionModel>>
+    async fn find_all<DB>(
+        conn: &DB,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<PositionModel>>
     where
         DB: ConnectionTrait,
     {
-        let positions = sort_and_paginate(
-            conn,
-            &mut PositionEntity::find(),
-            query_params,
-            EModule::Position,
-        ).await;
-        if positions.is_err() {
-            tracing::error!("Something happen when query database: {:#?}.", positions.unwrap_err());
-            return None;
-        };
-        Some(positions.unwrap_or_default())
+        sort_and_paginate(conn, &mut PositionEntity::find(), query_params, EModule::Position).await
     }
 
     async fn find_data_by_name<DB>(conn: &DB, name: &str) -> Option<PositionModel>
@@ -71,46 +65,46 @@ impl ReadRepository<PositionEntity> for PositionEntity {
     }
 }
 
-// #[async_trait]
-// impl WriteRepository<PositionEntity> for PositionEntity {
-//     async fn create(
-//         conn: &DatabaseTransaction,
-//         model: & PositionModel,
-//     ) -> Option<i64> {
-//         let position = PositionEntity::insert(model.clone().into_active_model()).exec(conn).await;
-//         if position.is_err() {
-//             tracing::error!("Something happen when query database: {:#?}.", position.unwrap_err());
-//             return None;
-//         };
-//         Some(position.unwrap().last_insert_id)
-//     }
-//
-//     async fn update(conn: &DatabaseTransaction, model: PositionModel) -> Option<i64> {
-//         let user_update = model.into_active_model().save(conn).await;
-//         if user_update.is_err() {
-//             tracing::error!(
-//                 "Something happen when query database: {:#?}.",
-//                 user_update.unwrap_err()
-//             );
-//             return None;
-//         };
-//
-//         Some(user_update.unwrap().id.unwrap())
-//     }
-// }
+#[async_trait]
+impl WriteRepository<PositionEntity> for PositionEntity {
+    async fn create(conn: &DatabaseTransaction, model: &PositionModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        let position = PositionEntity::insert(active_model).exec(conn).await;
+        if position.is_err() {
+            tracing::error!("Something happen when query database: {:#?}.", position.unwrap_err());
+            return None;
+        };
+        Some(position.unwrap().last_insert_id)
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: PositionModel) -> Option<i64> {
+        let position_update = model.into_active_model().reset_all().update(conn).await;
+        if position_update.is_err() {
+            tracing::error!(
+                "Something happen when query database: {:#?}.",
+                position_update.unwrap_err()
+            );
+            return None;
+        };
+
+        Some(position_update.unwrap().id)
+    }
+}
 
 #[async_trait]
 impl DeleteRepository<PositionEntity> for PositionEntity {
     async fn delete_data(conn: &DatabaseTransaction, uuid: Uuid) -> Option<i64> {
         let position =
             PositionEntity::find().filter(position::Column::PositionUuid.eq(uuid)).one(conn).await;
-        if position.is_err() {
-            return None;
-        }
-        let mut position = position.unwrap().unwrap();
+        let mut position = match position {
+            Ok(Some(position)) => position,
+            _ => return None,
+        };
 
         position.status = Some(0);
-        let position_delete = position.into_active_model().save(conn).await;
+        position.update_at = Utc::now();
+        let position_delete = position.into_active_model().reset_all().update(conn).await;
         if position_delete.is_err() {
             tracing::error!(
                 "Something happen when query database: {:#?}.",
@@ -119,8 +113,41 @@ impl DeleteRepository<PositionEntity> for PositionEntity {
             return None;
         }
 
-        Some(position_delete.unwrap().id.unwrap())
+        Some(position_delete.unwrap().id)
     }
 }
 
-impl PositionEntity {}
+impl PositionEntity {
+    pub async fn find_by_department<DB>(
+        conn: &DB,
+        department_id: i64,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<PositionModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let mut select = PositionEntity::find().filter(Column::DepartmentId.eq(department_id));
+        sort_and_paginate(conn, &mut select, query_params, EModule::Position).await
+    }
+
+    pub async fn find_by_department_and_name<DB>(
+        conn: &DB,
+        department_id: i64,
+        name: &str,
+    ) -> Option<PositionModel>
+    where
+        DB: ConnectionTrait,
+    {
+        let position = PositionEntity::find()
+            .filter(Column::DepartmentId.eq(department_id))
+            .filter(Column::Name.eq(name))
+            .filter(Column::Status.eq(1))
+            .one(conn)
+            .await;
+        if position.is_err() {
+            tracing::error!("Something happen when query database: {:#?}.", position.unwrap_err());
+            return None;
+        };
+        position.unwrap_or_default()
+    }
+}
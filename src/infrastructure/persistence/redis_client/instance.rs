@@ -5,6 +5,26 @@ use std::time::Duration;
 
 pub type RedisClient = Client;
 
+// Replaces the value of the key only when it still holds the expected one, in a single step.
+const COMPARE_AND_SET_SCRIPT: &str = r"
+local current = redis.call('GET', KEYS[1])
+if not current then
+    return -1
+end
+if current ~= ARGV[1] then
+    return 0
+end
+redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[3])
+return 1
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareAndSet {
+    Swapped,
+    Mismatch,
+    Missing,
+}
+
 pub trait RedisClientBuilder: Sized {
     fn build_from_config(config: &AppConfig) -> AppResult<Self>;
 }
@@ -17,10 +37,23 @@ pub trait RedisClientExt: RedisClientBuilder {
         value: &str,
         expire: Duration,
     ) -> impl std::future::Future<Output = AppResult<()>>;
+    fn compare_and_set(
+        &self,
+        key: &str,
+        expected: &str,
+        value: &str,
+        expire: Duration,
+    ) -> impl std::future::Future<Output = AppResult<CompareAndSet>>;
     fn exist(&self, key: &str) -> impl std::future::Future<Output = AppResult<bool>>;
     fn get(&self, key: &str) -> impl std::future::Future<Output = AppResult<Option<String>>>;
     fn del(&self, key: &str) -> impl std::future::Future<Output = AppResult<bool>>;
+    fn incr(&self, key: &str, expire: Duration) -> impl std::future::Future<Output = AppResult<i64>>;
     fn ttl(&self, key: &str) -> impl std::future::Future<Output = AppResult<i64>>;
+    fn expire(&self, key: &str, expire: Duration)
+        -> impl std::future::Future<Output = AppResult<bool>>;
+    fn sadd(&self, key: &str, member: &str) -> impl std::future::Future<Output = AppResult<bool>>;
+    fn srem(&self, key: &str, member: &str) -> impl std::future::Future<Output = AppResult<bool>>;
+    fn smembers(&self, key: &str) -> impl std::future::Future<Output = AppResult<Vec<String>>>;
 }
 
 impl RedisClientBuilder for RedisClient {
@@ -49,6 +82,28 @@ impl RedisClientExt for Client {
         Ok(())
     }
 
+    async fn compare_and_set(
+        &self,
+        key: &str,
+        expected: &str,
+        value: &str,
+        expire: Duration,
+    ) -> AppResult<CompareAndSet> {
+        let mut conn = self.get_multiplexed_async_connection().await?;
+        let result: i32 = redis::cmd("EVAL")
+            .arg(COMPARE_AND_SET_SCRIPT)
+            .arg(1)
+            .arg(&[key, expected, value, &expire.as_secs().to_string()])
+            .query_async(&mut conn)
+            .await?;
+        tracing::info!("compare and set key redis_client: {key} result: {result}");
+        Ok(match result {
+            1 => CompareAndSet::Swapped,
+            0 => CompareAndSet::Mismatch,
+            _ => CompareAndSet::Missing,
+        })
+    }
+
     async fn exist(&self, key: &str) -> AppResult<bool> {
         let mut conn = self.get_multiplexed_async_connection().await?;
         let value: bool = redis::cmd("EXISTS").arg(key).query_async(&mut conn).await?;
@@ -70,10 +125,55 @@ impl RedisClientExt for Client {
         Ok(value == 1)
     }
 
+    // Increments the counter and starts its expire time when it is created.
+    async fn incr(&self, key: &str, expire: Duration) -> AppResult<i64> {
+        let mut conn = self.get_multiplexed_async_connection().await?;
+        let value: i64 = redis::cmd("INCR").arg(key).query_async(&mut conn).await?;
+        if value == 1 {
+            let _: i32 = redis::cmd("EXPIRE")
+                .arg(&[key, &expire.as_secs().to_string()])
+                .query_async(&mut conn)
+                .await?;
+        }
+        tracing::info!("increment value: {key}");
+        Ok(value)
+    }
+
     async fn ttl(&self, key: &str) -> AppResult<i64> {
         let mut conn = self.get_multiplexed_async_connection().await?;
         let value: i64 = redis::cmd("TTL").arg(key).query_async(&mut conn).await?;
         tracing::info!("get TTL value: {key}");
         Ok(value)
     }
+
+    async fn expire(&self, key: &str, expire: Duration) -> AppResult<bool> {
+        let mut conn = self.get_multiplexed_async_connection().await?;
+        let value: i32 = redis::cmd("EXPIRE")
+            .arg(&[key, &expire.as_secs().to_string()])
+            .query_async(&mut conn)
+            .await?;
+        tracing::info!("set expire time: {key}");
+        Ok(value == 1)
+    }
+
+    async fn sadd(&self, key: &str, member: &str) -> AppResult<bool> {
+        let mut conn = self.get_multiplexed_async_connection().await?;
+        let value: i32 = redis::cmd("SADD").arg(&[key, member]).query_async(&mut conn).await?;
+        tracing::info!("add member to set: {key}");
+        Ok(value == 1)
+    }
+
+    async fn srem(&self, key: &str, member: &str) -> AppResult<bool> {
+        let mut conn = self.get_multiplexed_async_connection().await?;
+        let value: i32 = redis::cmd("SREM").arg(&[key, member]).query_async(&mut conn).await?;
+        tracing::info!("remove member from set: {key}");
+        Ok(value == 1)
+    }
+
+    async fn smembers(&self, key: &str) -> AppResult<Vec<String>> {
+        let mut conn = self.get_multiplexed_async_connection().await?;
+        let value: Vec<String> = redis::cmd("SMEMBERS").arg(key).query_async(&mut conn).await?;
+        tracing::info!("get members of set: {key}");
+        Ok(value)
+    }
 }
@@ -1,6 +1,12 @@
 use crate::core::error::AppResult;
-use crate::infrastructure::persistence::redis_client::instance::{RedisClient, RedisClientExt};
-use crate::util::constant::{EXPIRE_FORGET_PASS_CODE_SECS, EXPIRE_SESSION_CODE_SECS};
+use crate::infrastructure::persistence::redis_client::instance::{
+    CompareAndSet, RedisClient, RedisClientExt,
+};
+use crate::util::constant::{
+    EXPIRE_FORGET_PASS_ATTEMPT_SECS, EXPIRE_FORGET_PASS_CODE_SECS, EXPIRE_INVITATION_CODE_SECS,
+    EXPIRE_REFRESH_TOKEN_SECS, EXPIRE_SESSION_CODE_SECS,
+};
+use chrono::NaiveDateTime;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -19,16 +25,61 @@ pub trait RedisKey: Debug + Display {
 #[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
 pub struct SessionKey {
     pub user_id: Uuid,
+    pub session_id: Uuid,
 }
 
 impl RedisKey for SessionKey {
-    type Value = Uuid;
+    type Value = SessionValue;
     const EXPIRE_TIME: Duration = EXPIRE_SESSION_CODE_SECS;
 }
 
 impl Display for SessionKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "SESSION_KEY_{}", self.user_id)
+        write!(f, "SESSION_KEY_{}_{}", self.user_id, self.session_id)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
+pub struct SessionValue {
+    pub session_id: Uuid,
+    pub device: Option<String>,
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+// Set of the session ids of a user, so every device can be listed and revoked.
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
+pub struct UserSessionsKey {
+    pub user_id: Uuid,
+}
+
+impl RedisKey for UserSessionsKey {
+    type Value = Uuid;
+    const EXPIRE_TIME: Duration = EXPIRE_SESSION_CODE_SECS;
+}
+
+impl Display for UserSessionsKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "USER_SESSIONS_KEY_{}", self.user_id)
+    }
+}
+
+// Id (jti) of the only refresh token of a session that can still be used.
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
+pub struct RefreshTokenKey {
+    pub user_id: Uuid,
+    pub session_id: Uuid,
+}
+
+impl RedisKey for RefreshTokenKey {
+    type Value = Uuid;
+    const EXPIRE_TIME: Duration = EXPIRE_REFRESH_TOKEN_SECS;
+}
+
+impl Display for RefreshTokenKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "REFRESH_TOKEN_KEY_{}_{}", self.user_id, self.session_id)
     }
 }
 
@@ -48,6 +99,59 @@ impl Display for ForgetPasswordKey {
     }
 }
 
+// Number of codes presented for the forget password or invitation code of a user. The window
+// outlives the codes, so asking for a new code does not give new attempts.
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
+pub struct ForgetPasswordAttemptKey {
+    pub user_id: Uuid,
+}
+
+impl RedisKey for ForgetPasswordAttemptKey {
+    type Value = i64;
+    const EXPIRE_TIME: Duration = EXPIRE_FORGET_PASS_ATTEMPT_SECS;
+}
+
+impl Display for ForgetPasswordAttemptKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FORGET_PASS_ATTEMPT_KEY_{}", self.user_id)
+    }
+}
+
+// Number of forget password codes sent to a user.
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
+pub struct ForgetPasswordRequestKey {
+    pub user_id: Uuid,
+}
+
+impl RedisKey for ForgetPasswordRequestKey {
+    type Value = i64;
+    const EXPIRE_TIME: Duration = EXPIRE_FORGET_PASS_ATTEMPT_SECS;
+}
+
+impl Display for ForgetPasswordRequestKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FORGET_PASS_REQUEST_KEY_{}", self.user_id)
+    }
+}
+
+// Code sent to a user created by an administrator, it sets the first password like a forget
+// password code does.
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
+pub struct InvitationKey {
+    pub user_id: Uuid,
+}
+
+impl RedisKey for InvitationKey {
+    type Value = String;
+    const EXPIRE_TIME: Duration = EXPIRE_INVITATION_CODE_SECS;
+}
+
+impl Display for InvitationKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "INVITATION_KEY_{}", self.user_id)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Clone)]
 pub struct LoginValue {
     pub code: String,
@@ -57,12 +161,28 @@ pub async fn set<K>(client: &RedisClient, (key, value): (&K, &K::Value)) -> AppR
 where
     K: RedisKey,
 {
-    tracing::info!("Set value to redis_client key :{key:?} value :{value:?}");
+    // Values can be codes or tokens, only the key is logged.
+    tracing::info!("Set value to redis_client key :{key:?}");
     let value = serde_json::to_string(value)?;
     client.set(&key.to_string(), &value, K::EXPIRE_TIME).await?;
     Ok(())
 }
 
+pub async fn compare_and_set<K>(
+    client: &RedisClient,
+    key: &K,
+    expected: &K::Value,
+    value: &K::Value,
+) -> AppResult<CompareAndSet>
+where
+    K: RedisKey,
+{
+    tracing::info!("Compare and set redis_client key :{key:?}");
+    let expected = serde_json::to_string(expected)?;
+    let value = serde_json::to_string(value)?;
+    client.compare_and_set(&key.to_string(), &expected, &value, K::EXPIRE_TIME).await
+}
+
 pub async fn get<K>(client: &RedisClient, key: &K) -> AppResult<Option<K::Value>>
 where
     K: RedisKey,
@@ -80,11 +200,52 @@ pub async fn del(client: &RedisClient, key: &impl RedisKey) -> AppResult<bool> {
     client.del(&key.to_string()).await
 }
 
+pub async fn incr<K>(client: &RedisClient, key: &K) -> AppResult<i64>
+where
+    K: RedisKey<Value = i64>,
+{
+    tracing::info!("Increment value in redis_client key :{key:?}");
+    client.incr(&key.to_string(), K::EXPIRE_TIME).await
+}
+
 pub async fn get_tll(client: &RedisClient, key: &impl RedisKey) -> AppResult<i64> {
     tracing::info!("Get ttl key in redis_client :{key:?}");
     client.ttl(&key.to_string()).await
 }
 
+pub async fn add_member<K>(client: &RedisClient, key: &K, member: &K::Value) -> AppResult<()>
+where
+    K: RedisKey,
+{
+    tracing::info!("Add member to redis_client key :{key:?} member :{member:?}");
+    let member = serde_json::to_string(member)?;
+    client.sadd(&key.to_string(), &member).await?;
+    client.expire(&key.to_string(), K::EXPIRE_TIME).await?;
+    Ok(())
+}
+
+pub async fn remove_member<K>(client: &RedisClient, key: &K, member: &K::Value) -> AppResult<bool>
+where
+    K: RedisKey,
+{
+    tracing::info!("Remove member from redis_client key :{key:?} member :{member:?}");
+    let member = serde_json::to_string(member)?;
+    client.srem(&key.to_string(), &member).await
+}
+
+pub async fn get_members<K>(client: &RedisClient, key: &K) -> AppResult<Vec<K::Value>>
+where
+    K: RedisKey,
+{
+    tracing::info!("Get members from redis_client key :{key}");
+    Ok(client
+        .smembers(&key.to_string())
+        .await?
+        .iter()
+        .map(|v| serde_json::from_str::<K::Value>(v))
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
 pub async fn check_exist_key(redis: &RedisClient, key: &impl RedisKey) -> AppResult<bool> {
     Ok(redis.exist(&key.to_string()).await?)
 }
@@ -1,33 +1,91 @@
 use crate::core::error::{AppError, AppResult};
 use crate::infrastructure::persistence::redis_client;
 use crate::infrastructure::persistence::redis_client::instance::RedisClient;
-use crate::infrastructure::persistence::redis_client::services::SessionKey;
+use crate::infrastructure::persistence::redis_client::services::{
+    RefreshTokenKey, SessionKey, SessionValue, UserSessionsKey,
+};
 use crate::util::claim::UserClaims;
+use chrono::Utc;
 use uuid::Uuid;
 
 pub async fn check(redis: &RedisClient, claims: &UserClaims) -> AppResult<Uuid> {
-    let session_key = SessionKey { user_id: claims.uuid };
-    let session_id = redis_client::services::get(redis, &session_key).await?.ok_or_else(|| {
-        AppError::EntityNotAvailableError {
-            detail: format!("Session not available by {}", claims.sid.to_string()),
-        }
-    })?;
-    if claims.sid != session_id {
-        tracing::info!("Session id invalid so deleting it: {session_key:?}.");
-        redis_client::services::del(redis, &session_key).await?;
+    let session_key = SessionKey { user_id: claims.uuid, session_id: claims.sid };
+    if !redis_client::services::check_exist_key(redis, &session_key).await? {
+        tracing::info!("Session is not available: {session_key:?}.");
         return Err(AppError::InvalidSessionError("Session is Invalid".to_string()));
     }
     Ok(claims.uuid)
 }
 
-pub async fn set(redis: &RedisClient, user_id: Uuid) -> AppResult<Uuid> {
-    let (key, value) = generate(user_id);
+pub async fn set(
+    redis: &RedisClient,
+    user_id: Uuid,
+    device: Option<String>,
+    user_agent: Option<String>,
+    ip: Option<String>,
+) -> AppResult<Uuid> {
+    let (key, value) = generate(user_id, device, user_agent, ip);
+    redis_client::services::set(redis, (&key, &value)).await?;
+    redis_client::services::add_member(redis, &UserSessionsKey { user_id }, &key.session_id)
+        .await?;
+    Ok(key.session_id)
+}
+
+// Extends the lifetime of the session of the claims, keeping its session id.
+pub async fn refresh(redis: &RedisClient, claims: &UserClaims) -> AppResult<Uuid> {
+    let key = SessionKey { user_id: claims.uuid, session_id: claims.sid };
+    let value = redis_client::services::get(redis, &key)
+        .await?
+        .ok_or_else(|| AppError::InvalidSessionError("Session is Invalid".to_string()))?;
     redis_client::services::set(redis, (&key, &value)).await?;
-    Ok(value)
+    redis_client::services::add_member(redis, &UserSessionsKey { user_id: claims.uuid }, &claims.sid)
+        .await?;
+    Ok(claims.sid)
+}
+
+pub async fn list(redis: &RedisClient, user_id: Uuid) -> AppResult<Vec<SessionValue>> {
+    let index_key = UserSessionsKey { user_id };
+    let mut sessions = Vec::new();
+    for session_id in redis_client::services::get_members(redis, &index_key).await? {
+        let key = SessionKey { user_id, session_id };
+        match redis_client::services::get(redis, &key).await? {
+            Some(value) => sessions.push(value),
+            None => {
+                redis_client::services::remove_member(redis, &index_key, &session_id).await?;
+            },
+        }
+    }
+    sessions.sort_by_key(|session| std::cmp::Reverse(session.created_at));
+    Ok(sessions)
+}
+
+pub async fn revoke(redis: &RedisClient, user_id: Uuid, session_id: Uuid) -> AppResult<bool> {
+    tracing::info!("Revoke session {session_id} of user: {user_id}");
+    redis_client::services::remove_member(redis, &UserSessionsKey { user_id }, &session_id).await?;
+    redis_client::services::del(redis, &RefreshTokenKey { user_id, session_id }).await?;
+    redis_client::services::del(redis, &SessionKey { user_id, session_id }).await
+}
+
+pub async fn revoke_all(redis: &RedisClient, user_id: Uuid) -> AppResult<()> {
+    tracing::info!("Revoke all sessions of user: {user_id}");
+    let index_key = UserSessionsKey { user_id };
+    for session_id in redis_client::services::get_members(redis, &index_key).await? {
+        redis_client::services::del(redis, &RefreshTokenKey { user_id, session_id }).await?;
+        redis_client::services::del(redis, &SessionKey { user_id, session_id }).await?;
+    }
+    redis_client::services::del(redis, &index_key).await?;
+    Ok(())
 }
 
-pub fn generate(user_id: Uuid) -> (SessionKey, Uuid) {
+pub fn generate(
+    user_id: Uuid,
+    device: Option<String>,
+    user_agent: Option<String>,
+    ip: Option<String>,
+) -> (SessionKey, SessionValue) {
     let session_id = Uuid::new_v4();
-    let key = SessionKey { user_id };
-    (key, session_id)
+    let key = SessionKey { user_id, session_id };
+    let value =
+        SessionValue { session_id, device, user_agent, ip, created_at: Utc::now().naive_utc() };
+    (key, value)
 }
@@ -0,0 +1,219 @@
+use crate::core::error::AppResult;
+use crate::domain::entity::{PermissionEntity, RoleEntity, RolePermissionEntity};
+use crate::domain::model::{PermissionModel, RoleModel};
+use crate::domain::role;
+use crate::domain::role::{permission, role_permission};
+use crate::infrastructure::persistence::repo_interface::{
+    DeleteRepository, ReadRepository, WriteRepository,
+};
+use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam, Paginated};
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait,
+    IntoActiveModel, ModelTrait, NotSet, QueryFilter, QueryOrder,
+};
+use std::fmt::Debug;
+use uuid::Uuid;
+
+#[async_trait]
+impl ReadRepository<RoleEntity> for RoleEntity {
+    async fn find_data_by_id<DB>(conn: &DB, id: i64) -> Option<RoleModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        let role = RoleEntity::find_by_id(id).one(conn).await;
+        match role {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn find_data_by_uuid<DB>(conn: &DB, uuid: &Uuid) -> Option<RoleModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        let role = RoleEntity::find().filter(role::Column::RoleUuid.eq(*uuid)).one(conn).await;
+        match role {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn find_all<DB>(
+        conn: &DB,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<RoleModel>>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        sort_and_paginate(conn, &mut RoleEntity::find(), query_params, EModule::Role).await
+    }
+
+    async fn find_data_by_name<DB>(conn: &DB, name: &str) -> Option<RoleModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        let role = RoleEntity::find().filter(role::Column::Name.eq(name)).one(conn).await;
+        match role {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl WriteRepository<RoleEntity> for RoleEntity {
+    async fn create(conn: &DatabaseTransaction, model: &RoleModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        match RoleEntity::insert(active_model).exec(conn).await {
+            Ok(result) => Some(result.last_insert_id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: RoleModel) -> Option<i64> {
+        match model.into_active_model().reset_all().update(conn).await {
+            Ok(result) => Some(result.id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl DeleteRepository<RoleEntity> for RoleEntity {
+    async fn delete_data(conn: &DatabaseTransaction, uuid: Uuid) -> Option<i64> {
+        let role = RoleEntity::find().filter(role::Column::RoleUuid.eq(uuid)).one(conn).await;
+        let mut role = match role {
+            Ok(Some(role)) => role,
+            _ => return None,
+        };
+
+        role.status = Some(0);
+        role.update_at = Utc::now().naive_utc();
+        match role.into_active_model().reset_all().update(conn).await {
+            Ok(result) => Some(result.id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+impl RoleEntity {
+    pub async fn find_permissions<DB>(conn: &DB, role: &RoleModel) -> Option<Vec<PermissionModel>>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match role.find_related(PermissionEntity).order_by_asc(permission::Column::Code).all(conn).await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Permission codes of an active role, an unknown or deactivated role has no permission.
+    pub async fn find_permission_codes<DB>(conn: &DB, role_id: i64) -> Option<Vec<String>>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match PermissionEntity::find()
+            .inner_join(RoleEntity)
+            .filter(role::Column::Id.eq(role_id).and(role::Column::Status.eq(1)))
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result.into_iter().map(|permission| permission.code).collect()),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    pub async fn replace_permissions(
+        conn: &DatabaseTransaction,
+        role_id: i64,
+        permissions: &[PermissionModel],
+    ) -> Option<()> {
+        let delete = RolePermissionEntity::delete_many()
+            .filter(role_permission::Column::RoleId.eq(role_id))
+            .exec(conn)
+            .await;
+        if let Err(err) = delete {
+            tracing::error!("Something happen when query database: {err:#?}");
+            return None;
+        }
+        if permissions.is_empty() {
+            return Some(());
+        }
+
+        let insert = RolePermissionEntity::insert_many(permissions.iter().map(|permission| {
+            role_permission::Model {
+                role_id,
+                permission_id: permission.id,
+                create_at: Utc::now().naive_utc(),
+            }
+            .into_active_model()
+        }))
+        .exec(conn)
+        .await;
+        if let Err(err) = insert {
+            tracing::error!("Something happen when query database: {err:#?}");
+            return None;
+        }
+        Some(())
+    }
+}
+
+impl PermissionEntity {
+    pub async fn find_all_permissions<DB>(conn: &DB) -> Option<Vec<PermissionModel>>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match PermissionEntity::find().order_by_asc(permission::Column::Code).all(conn).await {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    pub async fn find_by_uuids<DB>(conn: &DB, uuids: &[Uuid]) -> Option<Vec<PermissionModel>>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match PermissionEntity::find()
+            .filter(permission::Column::PermissionUuid.is_in(uuids.iter().copied()))
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use crate::core::error::AppResult;
+use crate::domain::entity::{ShiftAssignmentEntity, ShiftTemplateEntity};
+use crate::domain::model::{ShiftAssignmentModel, ShiftTemplateModel};
+use crate::domain::shift;
+use crate::domain::shift::shift_assignment;
+use crate::infrastructure::persistence::repo_interface::{ReadRepository, WriteRepository};
+use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam, Paginated};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait,
+    IntoActiveModel, NotSet, QueryFilter, QueryOrder,
+};
+use std::fmt::Debug;
+use uuid::Uuid;
+
+#[async_trait]
+impl ReadRepository<ShiftTemplateEntity> for ShiftTemplateEntity {
+    async fn find_data_by_id<DB>(conn: &DB, id: i64) -> Option<ShiftTemplateModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match ShiftTemplateEntity::find_by_id(id).one(conn).await {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn find_data_by_uuid<DB>(conn: &DB, uuid: &Uuid) -> Option<ShiftTemplateModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        let shift_template = ShiftTemplateEntity::find()
+            .filter(shift::Column::ShiftTemplateUuid.eq(*uuid))
+            .one(conn)
+            .await;
+        match shift_template {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn find_all<DB>(
+        conn: &DB,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<ShiftTemplateModel>>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        let mut select = ShiftTemplateEntity::find();
+        sort_and_paginate(conn, &mut select, query_params, EModule::ShiftTemplate).await
+    }
+
+    async fn find_data_by_name<DB>(conn: &DB, name: &str) -> Option<ShiftTemplateModel>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match ShiftTemplateEntity::find().filter(shift::Column::Name.eq(name)).one(conn).await {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl WriteRepository<ShiftTemplateEntity> for ShiftTemplateEntity {
+    async fn create(conn: &DatabaseTransaction, model: &ShiftTemplateModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        match ShiftTemplateEntity::insert(active_model).exec(conn).await {
+            Ok(result) => Some(result.last_insert_id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: ShiftTemplateModel) -> Option<i64> {
+        match model.into_active_model().reset_all().update(conn).await {
+            Ok(result) => Some(result.id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+impl ShiftTemplateEntity {
+    pub async fn find_by_department<DB>(
+        conn: &DB,
+        department_id: i64,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<ShiftTemplateModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let mut select =
+            ShiftTemplateEntity::find().filter(shift::Column::DepartmentId.eq(department_id));
+        sort_and_paginate(conn, &mut select, query_params, EModule::ShiftTemplate).await
+    }
+
+    pub async fn find_active_by_department<DB>(
+        conn: &DB,
+        department_id: i64,
+    ) -> Option<Vec<ShiftTemplateModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let shift_templates = ShiftTemplateEntity::find()
+            .filter(shift::Column::DepartmentId.eq(department_id))
+            .filter(shift::Column::Status.eq(1))
+            .order_by_asc(shift::Column::StartTime)
+            .order_by_asc(shift::Column::Id)
+            .all(conn)
+            .await;
+        match shift_templates {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    pub async fn find_by_department_and_name<DB>(
+        conn: &DB,
+        department_id: i64,
+        name: &str,
+    ) -> Option<ShiftTemplateModel>
+    where
+        DB: ConnectionTrait,
+    {
+        let shift_template = ShiftTemplateEntity::find()
+            .filter(shift::Column::DepartmentId.eq(department_id))
+            .filter(shift::Column::Name.eq(name))
+            .filter(shift::Column::Status.eq(1))
+            .one(conn)
+            .await;
+        match shift_template {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    pub async fn find_by_ids<DB>(conn: &DB, ids: &[i64]) -> Option<Vec<ShiftTemplateModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        match ShiftTemplateEntity::find()
+            .filter(shift::Column::Id.is_in(ids.to_vec()))
+            .all(conn)
+            .await
+        {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl WriteRepository<ShiftAssignmentEntity> for ShiftAssignmentEntity {
+    async fn create(conn: &DatabaseTransaction, model: &ShiftAssignmentModel) -> Option<i64> {
+        let mut active_model = model.clone().into_active_model();
+        active_model.id = NotSet;
+        match ShiftAssignmentEntity::insert(active_model).exec(conn).await {
+            Ok(result) => Some(result.last_insert_id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    async fn update(conn: &DatabaseTransaction, model: ShiftAssignmentModel) -> Option<i64> {
+        match model.into_active_model().reset_all().update(conn).await {
+            Ok(result) => Some(result.id),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
+
+impl ShiftAssignmentEntity {
+    pub async fn find_by_uuid<DB>(conn: &DB, uuid: &Uuid) -> Option<ShiftAssignmentModel>
+    where
+        DB: ConnectionTrait,
+    {
+        let assignment = ShiftAssignmentEntity::find()
+            .filter(shift_assignment::Column::ShiftAssignmentUuid.eq(*uuid))
+            .one(conn)
+            .await;
+        match assignment {
+            Ok(result) => result,
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Active assignments of the employee sharing at least one day with the given range.
+    pub async fn find_by_employee_between<DB>(
+        conn: &DB,
+        employee_id: i64,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Option<Vec<ShiftAssignmentModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let assignments = ShiftAssignmentEntity::find()
+            .filter(shift_assignment::Column::EmployeeId.eq(employee_id))
+            .filter(shift_assignment::Column::Status.eq(1))
+            .filter(shift_assignment::Column::StartDate.lte(to))
+            .filter(shift_assignment::Column::EndDate.gte(from))
+            .all(conn)
+            .await;
+        match assignments {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Active assignments of the shift not ended before the given day.
+    pub async fn find_by_template_from<DB>(
+        conn: &DB,
+        shift_template_id: i64,
+        from: NaiveDate,
+    ) -> Option<Vec<ShiftAssignmentModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let assignments = ShiftAssignmentEntity::find()
+            .filter(shift_assignment::Column::ShiftTemplateId.eq(shift_template_id))
+            .filter(shift_assignment::Column::Status.eq(1))
+            .filter(shift_assignment::Column::EndDate.gte(from))
+            .order_by_asc(shift_assignment::Column::Id)
+            .all(conn)
+            .await;
+        match assignments {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Active assignments of the shifts sharing at least one day with the given range.
+    pub async fn find_by_templates_between<DB>(
+        conn: &DB,
+        shift_template_ids: &[i64],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Option<Vec<ShiftAssignmentModel>>
+    where
+        DB: ConnectionTrait,
+    {
+        let assignments = ShiftAssignmentEntity::find()
+            .filter(shift_assignment::Column::ShiftTemplateId.is_in(shift_template_ids.to_vec()))
+            .filter(shift_assignment::Column::Status.eq(1))
+            .filter(shift_assignment::Column::StartDate.lte(to))
+            .filter(shift_assignment::Column::EndDate.gte(from))
+            .order_by_asc(shift_assignment::Column::Id)
+            .all(conn)
+            .await;
+        match assignments {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+}
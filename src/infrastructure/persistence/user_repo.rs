@@ -7,19 +7,20 @@ use crate::infrastructure::persistence::repo_interface::{
     DeleteRepository, ReadRepository, WriteRepository,
 };
 use crate::util;
-use crate::util::filter_and_pagination::{sort_and_paginate, EModule, PageQueryParam};
+use crate::util::filter_and_pagination::{
+    apply_filter, sort_and_paginate, EModule, PageQueryParam, Paginated,
+};
 use async_trait::async_trait;
 use chrono::Utc;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction,
-    EntityTrait, IntoActiveModel, NotSet, QueryFilter, Set,
+    EntityTrait, IntoActiveModel, NotSet, QueryFilter, Select, Set,
 };
 use std::cell::RefCell;
 use std::fmt::Debug;
 use std::mem;
 use std::ops::Deref;
 use std::rc::Rc;
-use tera::ast::Set;
 use uuid::Uuid;
 
 #[async_trait]
@@ -29,7 +30,7 @@ impl ReadRepository<UserEntity> for UserEntity {
         DB: ConnectionTrait + Debug,
     {
         match UserEntity::find_by_id(id).one(conn).await {
-            Ok(result) => Some(result.unwrap()),
+            Ok(result) => result,
             Err(err) => {
                 tracing::error!("Something happen when query database: {err:#?}");
                 None
@@ -50,17 +51,14 @@ impl ReadRepository<UserEntity> for UserEntity {
         }
     }
 
-    async fn find_all<DB>(conn: &DB, query_params: PageQueryParam) -> Option<Vec<UserModel>>
+    async fn find_all<DB>(
+        conn: &DB,
+        query_params: PageQueryParam,
+    ) -> AppResult<Paginated<UserModel>>
     where
         DB: ConnectionTrait + Debug,
     {
-        let users =
-            sort_and_paginate(conn, &mut UserEntity::find(), query_params, EModule::User).await;
-        if users.is_err() {
-            tracing::error!("Something happen when query database: {:#?}.", users.unwrap_err());
-            return None;
-        };
-        Some(users.unwrap_or_default())
+        sort_and_paginate(conn, &mut UserEntity::find(), query_params, EModule::User).await
     }
 
     async fn find_data_by_name<DB>(_conn: &DB, _name: &str) -> Option<UserModel>
@@ -86,8 +84,8 @@ impl<'a> WriteRepository<UserEntity> for UserEntity {
     }
 
     async fn update(conn: &DatabaseTransaction, model: UserModel) -> Option<i64> {
-        match model.into_active_model().save(conn).await {
-            Ok(result) => Some(result.id.unwrap()),
+        match model.into_active_model().reset_all().update(conn).await {
+            Ok(result) => Some(result.id),
             Err(err) => {
                 tracing::error!("Something happen when query database: {err:#?}");
                 None
@@ -183,4 +181,23 @@ impl UserEntity {
             },
         }
     }
+
+    #[tracing::instrument]
+    pub async fn repo_find_by_ids<DB>(conn: &DB, ids: &[i64]) -> Option<Vec<UserModel>>
+    where
+        DB: ConnectionTrait + Debug,
+    {
+        match UserEntity::find().filter(user::Column::Id.is_in(ids.to_vec())).all(conn).await {
+            Ok(result) => Some(result),
+            Err(err) => {
+                tracing::error!("Something happen when query database: {err:#?}");
+                None
+            },
+        }
+    }
+
+    // Users matching the filter of an export, the caller reads them in batches.
+    pub fn repo_select_for_export(param: &PageQueryParam) -> AppResult<Select<UserEntity>> {
+        apply_filter(UserEntity::find(), param, EModule::User)
+    }
 }
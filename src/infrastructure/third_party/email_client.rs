@@ -0,0 +1,91 @@
+use crate::core::configure::email::EmailTransport;
+use crate::core::configure::AppConfig;
+use crate::core::error::AppResult;
+use crate::infrastructure::third_party::template::EmailTemplate;
+use crate::util::constant::TEMPLATE_ENGIN;
+use crate::util::dir::get_project_root;
+use lettre::message::{Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncFileTransport, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+pub struct EmailClient {
+    from: Mailbox,
+    transport: Transport,
+}
+
+enum Transport {
+    Smtp(AsyncSmtpTransport<Tokio1Executor>),
+    File(AsyncFileTransport<Tokio1Executor>),
+    Stdout,
+}
+
+pub trait EmailClientBuilder: Sized {
+    fn build_from_config(config: &AppConfig) -> AppResult<Self>;
+}
+
+pub trait EmailClientExt: EmailClientBuilder {
+    fn send_email(&self, message: Message) -> impl std::future::Future<Output = AppResult>;
+    fn send_template<T: EmailTemplate + Sync>(
+        &self,
+        to: &str,
+        template: &T,
+    ) -> impl std::future::Future<Output = AppResult>;
+}
+
+impl EmailClientBuilder for EmailClient {
+    fn build_from_config(config: &AppConfig) -> AppResult<Self> {
+        let email = &config.email;
+        let transport = match email.transport {
+            EmailTransport::Smtp => Transport::Smtp(
+                AsyncSmtpTransport::<Tokio1Executor>::relay(&email.host)?
+                    .port(email.port)
+                    .credentials(Credentials::new(email.username.clone(), email.password.clone()))
+                    .build(),
+            ),
+            EmailTransport::File => {
+                let dir = get_project_root()?.join(&email.file_dir);
+                std::fs::create_dir_all(&dir)?;
+                Transport::File(AsyncFileTransport::<Tokio1Executor>::new(dir))
+            },
+            EmailTransport::Stdout => Transport::Stdout,
+        };
+        tracing::info!("Email client use transport: {}", email.transport);
+        Ok(Self { from: email.from.parse()?, transport })
+    }
+}
+
+impl EmailClientExt for EmailClient {
+    async fn send_email(&self, message: Message) -> AppResult {
+        match &self.transport {
+            Transport::Smtp(smtp) => {
+                let response = smtp.send(message).await?;
+                tracing::info!("Send email by smtp response code: {}", response.code());
+            },
+            Transport::File(file) => {
+                let email_id = file.send(message).await?;
+                tracing::info!("Write email to file with id: {email_id}");
+            },
+            // The body may carry secrets such as reset codes, so only the envelope is logged.
+            Transport::Stdout => {
+                tracing::info!(
+                    "Skip sending email by stdout transport, envelope: {:?}, subject: {:?}",
+                    message.envelope(),
+                    message.headers().get_raw("Subject")
+                );
+            },
+        }
+        Ok(())
+    }
+
+    async fn send_template<T: EmailTemplate + Sync>(&self, to: &str, template: &T) -> AppResult {
+        let html = template.render_html(&TEMPLATE_ENGIN)?;
+        let text = template.render_text(&TEMPLATE_ENGIN)?;
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to.parse()?)
+            .subject(T::SUBJECT)
+            .multipart(MultiPart::alternative_plain_html(text, html))?;
+        tracing::info!("Send email {} to: {to}", T::NAME);
+        self.send_email(message).await
+    }
+}
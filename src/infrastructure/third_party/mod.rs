@@ -1,2 +1,3 @@
 pub mod email_client;
+pub mod template;
 pub mod token;
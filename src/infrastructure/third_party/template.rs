@@ -0,0 +1,59 @@
+use crate::core::error::AppResult;
+use serde::Serialize;
+use tera::{Context, Tera};
+
+pub struct TemplateEngine {
+    tera: Tera,
+}
+
+impl TemplateEngine {
+    pub fn new(path: &str) -> AppResult<Self> {
+        let tera = Tera::new(path)?;
+        tracing::info!("Loaded templates: {:?}", tera.get_template_names().collect::<Vec<_>>());
+        Ok(Self { tera })
+    }
+
+    pub fn render<T: Serialize>(&self, name: &str, data: &T) -> AppResult<String> {
+        let context = Context::from_serialize(data)?;
+        Ok(self.tera.render(name, &context)?)
+    }
+}
+
+pub trait EmailTemplate: Serialize + Sized {
+    const SUBJECT: &'static str;
+    // Loads `{NAME}.html` and `{NAME}.txt` from the template directory.
+    const NAME: &'static str;
+
+    fn render_html(&self, engine: &TemplateEngine) -> AppResult<String> {
+        engine.render(&format!("{}.html", Self::NAME), self)
+    }
+
+    fn render_text(&self, engine: &TemplateEngine) -> AppResult<String> {
+        engine.render(&format!("{}.txt", Self::NAME), self)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ForgetPasswordTemplate {
+    pub full_name: String,
+    pub code: String,
+    pub expire_in_minutes: u64,
+}
+
+impl EmailTemplate for ForgetPasswordTemplate {
+    const SUBJECT: &'static str = "Reset your UPTOP ERP password";
+    const NAME: &'static str = "forget_password";
+}
+
+#[derive(Debug, Serialize)]
+pub struct InvitationTemplate {
+    pub full_name: String,
+    pub email: String,
+    pub code: String,
+    pub expire_in_hours: u64,
+}
+
+impl EmailTemplate for InvitationTemplate {
+    const SUBJECT: &'static str = "Welcome to UPTOP ERP";
+    const NAME: &'static str = "invitation";
+}
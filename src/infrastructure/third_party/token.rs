@@ -2,8 +2,10 @@ use crate::core::app_state::AppState;
 use crate::core::error::{AppError, AppResult};
 use crate::domain::authenticate::request::RefreshTokenRequest;
 use crate::domain::authenticate::response::TokenResponse;
-use crate::domain::entity::UserEntity;
+use crate::domain::entity::{RoleEntity, UserEntity};
 use crate::infrastructure::persistence::redis_client;
+use crate::infrastructure::persistence::redis_client::instance::{CompareAndSet, RedisClient};
+use crate::infrastructure::persistence::redis_client::services::RefreshTokenKey;
 use crate::infrastructure::persistence::repo_interface::ReadRepository;
 use crate::util::claim::UserClaims;
 use crate::util::constant::{
@@ -25,21 +27,101 @@ pub async fn service_refresh(
         return Err(AppError::EntityNotFoundError { detail: "User not found".to_string() });
     }
     let user_clone = Arc::new(user.unwrap());
-    let session_id = redis_client::session::set(&state.redis, user_clone.user_uuid).await?;
-    tracing::info!("Set new session for user: {}", user_clone.user_uuid);
-    let resp = service_generate_tokens(user_clone.user_uuid, user_clone.role_id, session_id)?;
+    // A deactivated user can not log in, nor keep a session open by refreshing it.
+    if user_clone.status != 1 {
+        tracing::warn!("Refresh token presented by inactive user: {}", user_clone.user_uuid);
+        redis_client::session::revoke_all(&state.redis, user_clone.user_uuid).await?;
+        return Err(AppError::InvalidSessionError("User is not active".to_string()));
+    }
+    // Permissions are read from the role again, as on login, so role changes apply on refresh.
+    let permissions = RoleEntity::find_permission_codes(&*state.db, user_clone.role_id)
+        .await
+        .ok_or_else(|| AppError::EntityNotAvailableError { detail: "Role".to_string() })?;
+    let (resp, refresh_claims) = generate_tokens(
+        user_clone.user_uuid,
+        user_clone.role_id,
+        permissions,
+        user_claims.sid,
+    )?;
+    rotate_refresh_token(&state.redis, &user_claims, &refresh_claims).await?;
+    let session_id = redis_client::session::refresh(&state.redis, &user_claims).await?;
+    tracing::info!("Extend session {session_id} for user: {}", user_clone.user_uuid);
     tracing::info!("Refresh token success: {user_claims:?}");
     Ok(resp)
 }
 
-pub fn service_generate_tokens(
+pub async fn service_revoke_token(state: &AppState, req: RefreshTokenRequest) -> AppResult {
+    let user_claims = UserClaims::decode(&req.token, &REFRESH_TOKEN_DECODE_KEY)?.claims;
+    tracing::info!("Revoke refresh token: {user_claims:?}");
+    verify_refresh_token(&state.redis, &user_claims).await?;
+    redis_client::session::revoke(&state.redis, user_claims.uuid, user_claims.sid).await?;
+    Ok(())
+}
+
+pub async fn service_generate_tokens(
+    redis: &RedisClient,
     user_id: Uuid,
     role: i64,
+    permissions: Vec<String>,
     session_id: Uuid,
 ) -> AppResult<TokenResponse> {
-    let access_token = UserClaims::new(EXPIRE_BEARER_TOKEN_SECS, user_id, session_id, role)
-        .encode(&ACCESS_TOKEN_ENCODE_KEY)?;
-    let refresh_token = UserClaims::new(EXPIRE_REFRESH_TOKEN_SECS, user_id, session_id, role)
-        .encode(&REFRESH_TOKEN_ENCODE_KEY)?;
-    Ok(TokenResponse::new(access_token, refresh_token, EXPIRE_BEARER_TOKEN_SECS.as_secs()))
+    let (resp, refresh_claims) = generate_tokens(user_id, role, permissions, session_id)?;
+    let key = RefreshTokenKey { user_id, session_id };
+    redis_client::services::set(redis, (&key, &refresh_claims.jti)).await?;
+    Ok(resp)
+}
+
+fn generate_tokens(
+    user_id: Uuid,
+    role: i64,
+    permissions: Vec<String>,
+    session_id: Uuid,
+) -> AppResult<(TokenResponse, UserClaims)> {
+    let access_token =
+        UserClaims::new(EXPIRE_BEARER_TOKEN_SECS, user_id, session_id, role, permissions.clone())
+            .encode(&ACCESS_TOKEN_ENCODE_KEY)?;
+    let refresh_claims =
+        UserClaims::new(EXPIRE_REFRESH_TOKEN_SECS, user_id, session_id, role, permissions);
+    let refresh_token = refresh_claims.encode(&REFRESH_TOKEN_ENCODE_KEY)?;
+    let resp = TokenResponse::new(access_token, refresh_token, EXPIRE_BEARER_TOKEN_SECS.as_secs());
+    Ok((resp, refresh_claims))
+}
+
+// Only the latest refresh token of a session is accepted. The stored jti is compared and replaced
+// by the new one in a single step, so two refreshes with the same token can not both succeed.
+// Presenting an older one means the token family leaked, so the whole session is revoked.
+async fn rotate_refresh_token(
+    redis: &RedisClient,
+    claims: &UserClaims,
+    new_claims: &UserClaims,
+) -> AppResult {
+    let key = RefreshTokenKey { user_id: claims.uuid, session_id: claims.sid };
+    match redis_client::services::compare_and_set(redis, &key, &claims.jti, &new_claims.jti).await?
+    {
+        CompareAndSet::Swapped => Ok(()),
+        CompareAndSet::Mismatch => reject_reused_token(redis, claims).await,
+        CompareAndSet::Missing => {
+            Err(AppError::InvalidSessionError("Refresh token is Invalid".to_string()))
+        },
+    }
+}
+
+async fn verify_refresh_token(redis: &RedisClient, claims: &UserClaims) -> AppResult {
+    let key = RefreshTokenKey { user_id: claims.uuid, session_id: claims.sid };
+    match redis_client::services::get(redis, &key).await? {
+        Some(jti) if jti == claims.jti => Ok(()),
+        Some(_) => reject_reused_token(redis, claims).await,
+        None => Err(AppError::InvalidSessionError("Refresh token is Invalid".to_string())),
+    }
+}
+
+async fn reject_reused_token(redis: &RedisClient, claims: &UserClaims) -> AppResult {
+    tracing::warn!(
+        "Security event: refresh token {} reused for user {} session {}, revoke the token family.",
+        claims.jti,
+        claims.uuid,
+        claims.sid
+    );
+    redis_client::session::revoke(redis, claims.uuid, claims.sid).await?;
+    Err(AppError::InvalidSessionError("Refresh token has been reused".to_string()))
 }
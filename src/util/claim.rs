@@ -1,4 +1,5 @@
 use crate::core::error::{AppError, AppResult};
+use crate::domain::role::permission::EPermission;
 use axum::RequestPartsExt;
 use chrono::Utc;
 use jsonwebtoken::Header;
@@ -19,18 +20,44 @@ pub struct UserClaims {
     pub exp: i64,
     pub uuid: Uuid,
     pub sid: Uuid,
+    #[serde(default)]
+    pub jti: Uuid,
     pub role: i64,
+    #[serde(default)]
+    pub permissions: Vec<String>,
 }
 
 impl UserClaims {
-    pub fn new(duration: Duration, user_uid: Uuid, session_id: Uuid, role: i64) -> Self {
+    pub fn new(
+        duration: Duration,
+        user_uid: Uuid,
+        session_id: Uuid,
+        role: i64,
+        permissions: Vec<String>,
+    ) -> Self {
         let now = Utc::now().timestamp();
         Self {
             iat: now,
             exp: now + (duration.as_secs() as i64),
             uuid: user_uid,
             sid: session_id,
+            jti: Uuid::new_v4(),
             role,
+            permissions,
+        }
+    }
+
+    pub fn has_permission(&self, permission: EPermission) -> bool {
+        self.permissions.contains(&permission.to_string())
+    }
+
+    pub fn check_permissions(&self, permissions: &[EPermission]) -> AppResult {
+        match permissions.iter().find(|permission| !self.has_permission(**permission)) {
+            Some(missing) => Err(AppError::PermissionDeniedError(format!(
+                "User {} does not have permission {missing}",
+                self.uuid
+            ))),
+            None => Ok(()),
         }
     }
 
@@ -1,5 +1,7 @@
 use crate::core::configure;
 use crate::core::configure::env::get_env_source;
+use crate::core::configure::get_static_dir;
+use crate::infrastructure::third_party::template::TemplateEngine;
 use jsonwebtoken::{DecodingKey, EncodingKey};
 use std::sync::LazyLock;
 use std::time::Duration;
@@ -7,11 +9,14 @@ use std::time::Duration;
 pub const MAX_RETRY: u32 = 10;
 pub const ENV_PREFIX: &str = "APP";
 pub const CODE_LEN: usize = 5;
+pub const MAX_FORGET_PASS_CODE_ATTEMPTS: i64 = 5;
+pub const MAX_FORGET_PASS_REQUESTS: i64 = 3;
 pub const CLIENT_TIMEOUT: Duration = Duration::from_secs(120);
 pub const EXPIRE_SESSION_CODE_SECS: Duration = Duration::from_secs(2000);
 pub const EXPIRE_INVITATION_CODE_SECS: Duration = Duration::from_secs(86000);
 pub const EXPIRE_BLOCKED_EMAIL_SECS: Duration = Duration::from_secs(300);
 pub const EXPIRE_FORGET_PASS_CODE_SECS: Duration = Duration::from_secs(300);
+pub const EXPIRE_FORGET_PASS_ATTEMPT_SECS: Duration = Duration::from_secs(3600);
 pub const EXPIRE_BEARER_TOKEN_SECS: Duration = Duration::from_secs(1800);
 pub const EXPIRE_REFRESH_TOKEN_SECS: Duration = Duration::from_secs(86400);
 pub const QUEUE_EMPTY_DELAY_SECS: Duration = Duration::from_secs(60);
@@ -21,6 +26,15 @@ pub const AUTHORIZATION: &str = "Authorization";
 pub const BEARER: &str = "Bearer";
 pub const APP_DOMAIN: &str = "";
 pub const APP_EMAIL_ADDR: &str = "";
+pub const DEFAULT_LANGUAGE: &str = "vi";
+pub const EMPLOYEE_ROLE: &str = "employee";
+pub const ADMIN_EMAIL_ENV: &str = "APP_ADMIN_EMAIL";
+pub const DEFAULT_TIMEZONE: &str = "Asia/Ho_Chi_Minh";
+pub const DEFAULT_WORKING_WEEK: &str = "mon,tue,wed,thu,fri";
+pub const DEFAULT_CURRENCY: &str = "VND";
+pub const DEFAULT_WORK_START_HOUR: u32 = 8;
+pub const DEFAULT_WORK_END_HOUR: u32 = 17;
+pub const DEFAULT_MAX_WEEKLY_HOURS: i32 = 48;
 pub const MINIMUM_DELAY_TIME: Duration = Duration::from_millis(120);
 // pub static IMAGES_PATH: Lazy<PathBuf> = Lazy::new(|| get_static_dir().unwrap().join("images"));
 // pub static APP_IMAGE: Lazy<PathBuf> = Lazy::new(|| get_static_dir().unwrap().join("images/logo.jpg"));
@@ -46,7 +60,7 @@ pub static ACCESS_TOKEN_DECODE_KEY: LazyLock<DecodingKey> = LazyLock::new(|| {
     DecodingKey::from_rsa_pem(key.as_bytes()).unwrap()
 });
 // pub static API_DOC: LazyLock<utoipa::openapi::OpenApi> = LazyLock::new(ApiDoc::openapi);
-// pub static TEMPLATE_ENGIN: Lazy<TemplateEngine> = Lazy::new(|| {
-//     let path = get_static_dir().unwrap().join("template/**/*").into_os_string().into_string().unwrap();
-//     TemplateEngine::new(&path).unwrap()
-// });
+pub static TEMPLATE_ENGIN: LazyLock<TemplateEngine> = LazyLock::new(|| {
+    let path = get_static_dir().unwrap().join("template/**/*").into_os_string().into_string().unwrap();
+    TemplateEngine::new(&path).unwrap()
+});
@@ -0,0 +1,269 @@
+use crate::core::error::{AppError, AppResult};
+use crate::infrastructure::persistence::postgres::DatabaseClient;
+use crate::util::filter_and_pagination::{
+    sort_and_paginate, CursorKey, Direction, EModule, PageQueryParam, MAX_PAGE_SIZE,
+};
+use anyhow::anyhow;
+use axum::body::Body;
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+use rust_xlsxwriter::{Format, Workbook};
+use sea_orm::{EntityTrait, FromQueryResult, Select};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use strum::{Display, EnumString};
+use utoipa::{IntoParams, ToSchema};
+
+// Number of rows read from the database at a time while exporting, the largest page allowed.
+pub const EXPORT_BATCH_SIZE: u64 = MAX_PAGE_SIZE;
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Display, EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EExportFormat {
+    #[default]
+    Json,
+    Csv,
+    Xlsx,
+}
+
+impl EExportFormat {
+    // Uses the `format` query parameter, then the first known media type of the `Accept` header.
+    pub fn negotiate(format: Option<Self>, accept: Option<&str>) -> Self {
+        if let Some(format) = format {
+            return format;
+        }
+        accept
+            .into_iter()
+            .flat_map(|accept| accept.split(','))
+            .map(|media_type| media_type.split(';').next().unwrap_or_default().trim())
+            .find_map(|media_type| match media_type {
+                "application/json" => Some(Self::Json),
+                "text/csv" => Some(Self::Csv),
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => {
+                    Some(Self::Xlsx)
+                },
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams, Clone, Default)]
+pub struct ExportQueryParam {
+    // Wins over the `Accept` header, JSON when neither names a known format.
+    pub format: Option<EExportFormat>,
+    // Comma separated columns in the order of the file, every column by default.
+    #[param(example = json!("email,full_name,status"))]
+    pub columns: Option<String>,
+    // Rows are ordered by creation time and id, ascending by default.
+    pub sort_direction: Option<Direction>,
+    // Same syntax as the `filter` of the list endpoints.
+    #[param(allow_reserved, example = json!("status:in:0|1"))]
+    pub filter: Option<String>,
+}
+
+impl ExportQueryParam {
+    // Page parameters carrying the filter and direction, used to read the rows batch by batch.
+    pub fn page_query(&self) -> PageQueryParam {
+        PageQueryParam {
+            page_num: 0,
+            page_size: EXPORT_BATCH_SIZE,
+            cursor: Some(String::new()),
+            sort_by: None,
+            sort_direction: self.sort_direction,
+            filter: self.filter.clone(),
+        }
+    }
+
+    // Requested columns checked against the exportable ones, every column when none is given.
+    pub fn select_columns(&self, available: &[&'static str]) -> AppResult<Vec<&'static str>> {
+        let Some(columns) = self.columns.as_deref().filter(|columns| !columns.trim().is_empty())
+        else {
+            return Ok(available.to_vec());
+        };
+        columns
+            .split(',')
+            .map(str::trim)
+            .map(|name| {
+                available.iter().copied().find(|column| *column == name).ok_or_else(|| {
+                    AppError::BadRequestError(format!("Column `{name}` can not be exported"))
+                })
+            })
+            .collect()
+    }
+}
+
+// Batches of exported rows, each row holding one value per selected column.
+pub type ExportRows = BoxStream<'static, AppResult<Vec<Vec<Value>>>>;
+
+// Reads every row of the already filtered query, `EXPORT_BATCH_SIZE` rows at a time, so that a
+// large table is never loaded at once. Batches follow the cursor of the previous one, rows
+// written while exporting are neither skipped nor repeated.
+pub fn fetch_in_batches<E, M>(
+    db: Arc<DatabaseClient>,
+    select: Select<E>,
+    param: PageQueryParam,
+    module_name: EModule,
+) -> BoxStream<'static, AppResult<Vec<M>>>
+where
+    E: EntityTrait<Model = M>,
+    M: FromQueryResult + CursorKey + Send + Sync + 'static,
+{
+    let param = PageQueryParam { cursor: Some(String::new()), filter: None, ..param };
+    stream::try_unfold(Some(param), move |param| {
+        let db = db.clone();
+        let mut select = select.clone();
+        async move {
+            let Some(param) = param else {
+                return Ok(None);
+            };
+            let page = sort_and_paginate(&*db, &mut select, param.clone(), module_name).await?;
+            if page.items.is_empty() {
+                return Ok(None);
+            }
+            let next = page
+                .pagination
+                .next_cursor
+                .map(|cursor| PageQueryParam { cursor: Some(cursor), ..param });
+            Ok(Some((page.items, next)))
+        }
+    })
+    .boxed()
+}
+
+// Values of the selected columns of a serialized row, missing columns are left empty.
+pub fn pick_columns<T: Serialize>(row: &T, columns: &[&str]) -> Vec<Value> {
+    let row = serde_json::to_value(row).unwrap_or_default();
+    columns.iter().map(|column| row.get(*column).cloned().unwrap_or_default()).collect()
+}
+
+// Streams CSV and JSON files as the rows are read, an XLSX file is a zip archive and is only
+// sent once every row is written.
+pub async fn export_response(
+    format: EExportFormat,
+    name: &str,
+    columns: Vec<&'static str>,
+    rows: ExportRows,
+) -> AppResult<Response> {
+    let body = match format {
+        EExportFormat::Csv => Body::from_stream(csv_stream(columns, rows)),
+        EExportFormat::Json => Body::from_stream(json_stream(columns, rows)),
+        EExportFormat::Xlsx => Body::from(write_xlsx(&columns, rows).await?),
+    };
+    let disposition = format!("attachment; filename=\"{name}.{format}\"");
+    let headers = [
+        (header::CONTENT_TYPE, format.content_type().to_string()),
+        (header::CONTENT_DISPOSITION, disposition),
+    ];
+    Ok((headers, body).into_response())
+}
+
+fn csv_stream(
+    columns: Vec<&'static str>,
+    rows: ExportRows,
+) -> BoxStream<'static, AppResult<Vec<u8>>> {
+    let header = csv_record(columns.iter().map(|column| column.to_string()));
+    stream::once(async move { header })
+        .chain(rows.map(|batch| {
+            let mut chunk = Vec::new();
+            for row in batch? {
+                chunk.extend(csv_record(row.iter().map(cell_text))?);
+            }
+            Ok(chunk)
+        }))
+        .boxed()
+}
+
+fn csv_record(values: impl Iterator<Item = String>) -> AppResult<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(values)
+        .map_err(|err| AppError::UnknownError(anyhow!("Can not write csv file: {err}")))?;
+    writer
+        .into_inner()
+        .map_err(|err| AppError::UnknownError(anyhow!("Can not write csv file: {err}")))
+}
+
+fn json_stream(
+    columns: Vec<&'static str>,
+    rows: ExportRows,
+) -> BoxStream<'static, AppResult<Vec<u8>>> {
+    let body = rows.enumerate().map(move |(index, batch)| {
+        let objects = batch?
+            .into_iter()
+            .map(|row| {
+                let object = columns.iter().map(|column| column.to_string()).zip(row).collect();
+                Value::Object(object).to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let separator = if index == 0 { "" } else { "," };
+        Ok(format!("{separator}{objects}").into_bytes())
+    });
+    stream::once(async { Ok(b"[".to_vec()) })
+        .chain(body)
+        .chain(stream::once(async { Ok(b"]".to_vec()) }))
+        .boxed()
+}
+
+async fn write_xlsx(columns: &[&'static str], mut rows: ExportRows) -> AppResult<Vec<u8>> {
+    let invalid = |err: rust_xlsxwriter::XlsxError| {
+        AppError::UnknownError(anyhow!("Can not write xlsx file: {err}"))
+    };
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    let bold = Format::new().set_bold();
+    for (index, column) in columns.iter().enumerate() {
+        sheet.write_string_with_format(0, index as u16, *column, &bold).map_err(invalid)?;
+    }
+    let mut line = 1;
+    while let Some(batch) = rows.next().await {
+        for row in batch? {
+            for (index, value) in row.iter().enumerate() {
+                let index = index as u16;
+                match value {
+                    Value::Null => {},
+                    Value::Bool(value) => {
+                        sheet.write_boolean(line, index, *value).map_err(invalid)?;
+                    },
+                    Value::Number(value) => {
+                        let value = value.as_f64().unwrap_or_default();
+                        sheet.write_number(line, index, value).map_err(invalid)?;
+                    },
+                    _ => {
+                        sheet.write_string(line, index, cell_text(value)).map_err(invalid)?;
+                    },
+                }
+            }
+            line += 1;
+        }
+    }
+    workbook.save_to_buffer().map_err(invalid)
+}
+
+// Text of a cell, a string that a spreadsheet would run as a formula is prefixed with `'`.
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) if value.starts_with(['=', '+', '-', '@', '\t', '\r']) => {
+            format!("'{value}")
+        },
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
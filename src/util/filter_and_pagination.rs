@@ -1,13 +1,23 @@
 use crate::core::error::{AppError, AppResult};
-use crate::domain::{employee, user};
+use crate::core::response::PageResponse;
+use crate::domain::calendar;
+use crate::domain::leave::leave_request;
+use crate::domain::shift;
+use crate::domain::{audit_log, department, employee, leave, organization, position, role, user};
 use crate::infrastructure::migrations::SimpleExpr;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use futures::AsyncReadExt;
 use sea_orm::{
     entity, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, FromQueryResult,
     PaginatorTrait, QueryFilter, QueryOrder, Select,
 };
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sea_orm::sea_query::{Expr, Value};
+use sea_orm::{Condition, Order, QuerySelect};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::str::FromStr;
 use strum::Display;
 use utoipa::{IntoParams, ToSchema};
 
@@ -21,57 +31,164 @@ pub enum Direction {
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams, Clone)]
 pub struct PageQueryParam {
+    #[serde(default)]
     pub page_num: u64,
     pub page_size: u64,
+    // Opaque cursor of `next_cursor`/`prev_cursor`, an empty value starts a cursor listing.
+    // `page_num` and `sort_by` are ignored in this mode, rows are ordered by creation time and id.
+    pub cursor: Option<String>,
+    // Comma separated `column[:asc|desc]` items, e.g. `status:desc,create_at`.
+    #[param(example = json!("create_at:desc"))]
     pub sort_by: Option<String>,
     pub sort_direction: Option<Direction>,
-    #[param(allow_reserved, example = json!("column:eq:value,column:eq:value"))]
+    // Comma separated `column:operator:value` clauses, list values are separated by `|`.
+    #[param(
+        allow_reserved,
+        example = json!("status:in:0|1,created_at:between:2024-01-01|2024-12-31")
+    )]
     pub filter: Option<String>,
 }
 
-enum Either<A, B> {
-    UserColumn(A),
-    EmployeeColumn(B),
+// One page of a list together with the counters of the whole result set.
+#[derive(Debug, Clone)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub pagination: PageResponse,
 }
 
-pub fn get_simple_expression(query_string: &str, module_name: EModule) -> Option<SimpleExpr> {
-    let parts = query_string.split(':').collect::<Vec<_>>();
+impl<T> Paginated<T> {
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Paginated<U> {
+        Paginated { items: self.items.into_iter().map(f).collect(), pagination: self.pagination }
+    }
+}
+
+// Type of the value a filter column is compared with, used to parse the raw query string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EValueType {
+    String,
+    Integer,
+    Uuid,
+    Date,
+    DateTime,
+    Bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum EFilterOperator {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    In,
+    Between,
+    Contains,
+    StartsWith,
+    IsNull,
+}
+
+// Separator of the values of `in` and `between`, e.g. `status:in:0|1`.
+const LIST_SEPARATOR: char = '|';
+
+// Most rows of one page, a larger page size is cut down to it.
+pub const MAX_PAGE_SIZE: u64 = 100;
+
+// Parses one `column:operator:value` clause into a condition on a whitelisted column of the module.
+pub fn get_simple_expression(query_string: &str, module_name: EModule) -> AppResult<SimpleExpr> {
+    let invalid = |reason: String| {
+        AppError::BadRequestError(format!("Invalid filter clause `{query_string}`: {reason}"))
+    };
+
+    let parts = query_string.splitn(3, ':').collect::<Vec<_>>();
     if parts.len() != 3 {
-        return None;
+        return Err(invalid("expected format column:operator:value".to_string()));
     }
+    let (column, value_type) = module_name
+        .filter_column(parts[0])
+        .ok_or_else(|| invalid(format!("column `{}` can not be filtered", parts[0])))?;
+    let operator = EFilterOperator::from_str(parts[1])
+        .map_err(|_| invalid(format!("unknown operator `{}`", parts[1])))?;
+    let raw = parts[2];
+    let column = Expr::expr(column);
 
-    let column: Either<user::Column, employee::Column> = match module_name {
-        EModule::User => match parts[0] {
-            "email" => Either::UserColumn(user::Column::Email),
-            "full_name" => Either::UserColumn(user::Column::FullName),
-            "id" => Either::UserColumn(user::Column::Id),
-            _ => return None,
+    let expression = match operator {
+        EFilterOperator::Eq => column.eq(parse_value(raw, value_type).map_err(invalid)?),
+        EFilterOperator::Ne => column.ne(parse_value(raw, value_type).map_err(invalid)?),
+        EFilterOperator::Lt => column.lt(parse_value(raw, value_type).map_err(invalid)?),
+        EFilterOperator::Lte => column.lte(parse_value(raw, value_type).map_err(invalid)?),
+        EFilterOperator::Gt => column.gt(parse_value(raw, value_type).map_err(invalid)?),
+        EFilterOperator::Gte => column.gte(parse_value(raw, value_type).map_err(invalid)?),
+        EFilterOperator::In => {
+            let values = raw
+                .split(LIST_SEPARATOR)
+                .map(|value| parse_value(value, value_type))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(invalid)?;
+            column.is_in(values)
         },
-        EModule::Employee => match parts[0] {
-            "userId" => Either::EmployeeColumn(employee::Column::UserId),
-            _ => return None,
+        EFilterOperator::Between => {
+            let values = raw.split(LIST_SEPARATOR).collect::<Vec<_>>();
+            if values.len() != 2 {
+                return Err(invalid(format!(
+                    "between expects two values separated by `{LIST_SEPARATOR}`"
+                )));
+            }
+            let from = parse_value(values[0], value_type).map_err(invalid)?;
+            let to = parse_value(values[1], value_type).map_err(invalid)?;
+            column.between(from, to)
+        },
+        EFilterOperator::Contains | EFilterOperator::StartsWith => {
+            if value_type != EValueType::String {
+                return Err(invalid(format!("{operator} only applies to text columns")));
+            }
+            let pattern = escape_like(raw);
+            match operator {
+                EFilterOperator::Contains => column.like(format!("%{pattern}%")),
+                _ => column.like(format!("{pattern}%")),
+            }
+        },
+        EFilterOperator::IsNull => match raw.parse::<bool>() {
+            Ok(true) => column.is_null(),
+            Ok(false) => column.is_not_null(),
+            Err(_) => return Err(invalid("is_null expects true or false".to_string())),
         },
-
-        _ => return None,
     };
+    Ok(expression)
+}
 
-    match column {
-        Either::UserColumn(user) => match parts[1] {
-            "eq" => Some(user.eq(parts[2])),
-            "lte" => Some(user.lte(parts[2].parse::<i32>().unwrap())),
-            "lt" => Some(user.lt(parts[2].parse::<i32>().unwrap())),
-            "ne" => Some(user.ne(parts[2])),
-            "gte" => Some(user.gte(parts[2].parse::<i32>().unwrap())),
-            "gt" => Some(user.gt(parts[2].parse::<i32>().unwrap())),
-            "contains" => Some(user.contains(parts[2])),
-            "null" => Some(user.if_null(parts[2])),
-            _ => None,
+fn parse_value(value: &str, value_type: EValueType) -> Result<Value, String> {
+    let error = |type_name: &str| format!("`{value}` is not a valid {type_name}");
+    match value_type {
+        EValueType::String => Ok(value.to_string().into()),
+        EValueType::Integer => value.parse::<i64>().map(Value::from).map_err(|_| error("number")),
+        EValueType::Uuid => {
+            uuid::Uuid::parse_str(value).map(Value::from).map_err(|_| error("uuid"))
         },
-        Either::EmployeeColumn(employee) => Some(employee.eq(parts[2])),
-        _ => None,
+        EValueType::Bool => value.parse::<bool>().map(Value::from).map_err(|_| error("boolean")),
+        EValueType::Date => {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d").map(Value::from).map_err(|_| error("date"))
+        },
+        EValueType::DateTime => NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+            .or_else(|_| {
+                NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .map(|date| date.and_time(NaiveTime::MIN))
+            })
+            .map(Value::from)
+            .map_err(|_| error("datetime")),
     }
 }
 
+// Escapes the wildcards of LIKE patterns, so user input only matches literally.
+pub fn escape_like(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+fn column<C: ColumnTrait>(column: C, value_type: EValueType) -> Option<(SimpleExpr, EValueType)> {
+    Some((Expr::col(column.as_column_ref()).into(), value_type))
+}
+
 #[derive(Clone, Copy)]
 pub enum EModule {
     User,
@@ -79,43 +196,561 @@ pub enum EModule {
     Department,
     Position,
     Organization,
+    Role,
+    AuditLog,
+    LeaveType,
+    LeaveRequest,
+    ShiftTemplate,
+    Holiday,
+}
+
+impl EModule {
+    // Creation time and primary key columns used as the key of cursor pagination.
+    fn cursor_columns(&self) -> (SimpleExpr, SimpleExpr) {
+        let key = |name| self.filter_column(name).map(|(column, _)| column);
+        match (key(self.default_sort_column()), key("id")) {
+            (Some(created_at), Some(id)) => (created_at, id),
+            _ => unreachable!("every module exposes its creation time and id"),
+        }
+    }
+
+    // Positions and organizations store their creation time with a time zone.
+    fn cursor_value(&self, created_at: NaiveDateTime) -> Value {
+        match self {
+            EModule::Position | EModule::Organization => created_at.and_utc().into(),
+            _ => created_at.into(),
+        }
+    }
+
+    // Column used when the request does not give a `sort_by`.
+    pub fn default_sort_column(&self) -> &'static str {
+        match self {
+            EModule::User => "created_at",
+            _ => "create_at",
+        }
+    }
+
+    // Columns of each module that can be used in a filter clause or in `sort_by`.
+    pub fn filter_column(&self, name: &str) -> Option<(SimpleExpr, EValueType)> {
+        use EValueType::*;
+        match self {
+            EModule::User => match name {
+                "id" => column(user::Column::Id, Integer),
+                "user_uuid" => column(user::Column::UserUuid, Uuid),
+                "role_id" => column(user::Column::RoleId, Integer),
+                "email" => column(user::Column::Email, String),
+                "full_name" => column(user::Column::FullName, String),
+                "gender" => column(user::Column::Gender, String),
+                "phone_number" => column(user::Column::PhoneNumber, String),
+                "language" => column(user::Column::Language, String),
+                "status" => column(user::Column::Status, Integer),
+                "last_login" => column(user::Column::LastLogin, DateTime),
+                "created_at" => column(user::Column::CreatedAt, DateTime),
+                "updated_at" => column(user::Column::UpdatedAt, DateTime),
+                "deleted_at" => column(user::Column::DeletedAt, DateTime),
+                _ => None,
+            },
+            EModule::Employee => match name {
+                "id" => column(employee::Column::Id, Integer),
+                "employee_uuid" => column(employee::Column::EmployeeUuid, Uuid),
+                "user_id" | "userId" => column(employee::Column::UserId, Integer),
+                "manager_employee_id" => column(employee::Column::ManagerEmployeeId, Integer),
+                "status" => column(employee::Column::Status, Integer),
+                "create_at" => column(employee::Column::CreateAt, DateTime),
+                "update_at" => column(employee::Column::UpdateAt, DateTime),
+                _ => None,
+            },
+            EModule::Department => match name {
+                "id" => column(department::Column::Id, Integer),
+                "department_uuid" => column(department::Column::DepartmentUuid, Uuid),
+                "organization_id" => column(department::Column::OrganizationId, Integer),
+                "parent_department_id" => {
+                    column(department::Column::ParentDepartmentId, Integer)
+                },
+                "head_employee_id" => column(department::Column::HeadEmployeeId, Integer),
+                "name" => column(department::Column::Name, String),
+                "status" => column(department::Column::Status, Integer),
+                "create_at" => column(department::Column::CreateAt, DateTime),
+                "update_at" => column(department::Column::UpdateAt, DateTime),
+                _ => None,
+            },
+            EModule::Position => match name {
+                "id" => column(position::Column::Id, Integer),
+                "position_uuid" => column(position::Column::PositionUuid, Uuid),
+                "department_id" => column(position::Column::DepartmentId, Integer),
+                "name" => column(position::Column::Name, String),
+                "description" => column(position::Column::Description, String),
+                "status" => column(position::Column::Status, Integer),
+                "create_at" => column(position::Column::CreateAt, DateTime),
+                "update_at" => column(position::Column::UpdateAt, DateTime),
+                _ => None,
+            },
+            EModule::Organization => match name {
+                "id" => column(organization::Column::Id, Integer),
+                "organization_uuid" => column(organization::Column::OrganizationUuid, Uuid),
+                "name" => column(organization::Column::Name, String),
+                "description" => column(organization::Column::Description, String),
+                "default_language" => column(organization::Column::DefaultLanguage, String),
+                "timezone" => column(organization::Column::Timezone, String),
+                "currency" => column(organization::Column::Currency, String),
+                "create_at" => column(organization::Column::CreateAt, DateTime),
+                "update_at" => column(organization::Column::UpdateAt, DateTime),
+                _ => None,
+            },
+            EModule::Role => match name {
+                "id" => column(role::Column::Id, Integer),
+                "role_uuid" => column(role::Column::RoleUuid, Uuid),
+                "name" => column(role::Column::Name, String),
+                "description" => column(role::Column::Description, String),
+                "status" => column(role::Column::Status, Integer),
+                "create_at" => column(role::Column::CreateAt, DateTime),
+                "update_at" => column(role::Column::UpdateAt, DateTime),
+                _ => None,
+            },
+            EModule::AuditLog => match name {
+                "id" => column(audit_log::Column::Id, Integer),
+                "audit_log_uuid" => column(audit_log::Column::AuditLogUuid, Uuid),
+                "actor_uuid" => column(audit_log::Column::ActorUuid, Uuid),
+                "action" => column(audit_log::Column::Action, String),
+                "entity_type" => column(audit_log::Column::EntityType, String),
+                "entity_uuid" => column(audit_log::Column::EntityUuid, Uuid),
+                "ip" => column(audit_log::Column::Ip, String),
+                "request_id" => column(audit_log::Column::RequestId, String),
+                "create_at" => column(audit_log::Column::CreateAt, DateTime),
+                _ => None,
+            },
+            EModule::LeaveType => match name {
+                "id" => column(leave::Column::Id, Integer),
+                "leave_type_uuid" => column(leave::Column::LeaveTypeUuid, Uuid),
+                "organization_id" => column(leave::Column::OrganizationId, Integer),
+                "name" => column(leave::Column::Name, String),
+                "accrual_period" => column(leave::Column::AccrualPeriod, String),
+                "status" => column(leave::Column::Status, Integer),
+                "create_at" => column(leave::Column::CreateAt, DateTime),
+                "update_at" => column(leave::Column::UpdateAt, DateTime),
+                _ => None,
+            },
+            EModule::LeaveRequest => match name {
+                "id" => column(leave_request::Column::Id, Integer),
+                "leave_request_uuid" => column(leave_request::Column::LeaveRequestUuid, Uuid),
+                "employee_id" => column(leave_request::Column::EmployeeId, Integer),
+                "leave_type_id" => column(leave_request::Column::LeaveTypeId, Integer),
+                "start_date" => column(leave_request::Column::StartDate, Date),
+                "end_date" => column(leave_request::Column::EndDate, Date),
+                "status" => column(leave_request::Column::Status, String),
+                "create_at" => column(leave_request::Column::CreateAt, DateTime),
+                "update_at" => column(leave_request::Column::UpdateAt, DateTime),
+                _ => None,
+            },
+            EModule::ShiftTemplate => match name {
+                "id" => column(shift::Column::Id, Integer),
+                "shift_template_uuid" => column(shift::Column::ShiftTemplateUuid, Uuid),
+                "department_id" => column(shift::Column::DepartmentId, Integer),
+                "name" => column(shift::Column::Name, String),
+                "days_of_week" => column(shift::Column::DaysOfWeek, String),
+                "status" => column(shift::Column::Status, Integer),
+                "create_at" => column(shift::Column::CreateAt, DateTime),
+                "update_at" => column(shift::Column::UpdateAt, DateTime),
+                _ => None,
+            },
+            EModule::Holiday => match name {
+                "id" => column(calendar::Column::Id, Integer),
+                "holiday_uuid" => column(calendar::Column::HolidayUuid, Uuid),
+                "organization_id" => column(calendar::Column::OrganizationId, Integer),
+                "name" => column(calendar::Column::Name, String),
+                "date" => column(calendar::Column::Date, Date),
+                "recurring" => column(calendar::Column::Recurring, Bool),
+                "status" => column(calendar::Column::Status, Integer),
+                "create_at" => column(calendar::Column::CreateAt, DateTime),
+                "update_at" => column(calendar::Column::UpdateAt, DateTime),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl CursorKey for user::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.created_at, self.id)
+    }
+}
+
+impl CursorKey for employee::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at, self.id)
+    }
+}
+
+impl CursorKey for department::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at, self.id)
+    }
+}
+
+impl CursorKey for position::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at.naive_utc(), self.id)
+    }
+}
+
+impl CursorKey for organization::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at.naive_utc(), self.id)
+    }
+}
+
+impl CursorKey for role::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at, self.id)
+    }
+}
+
+impl CursorKey for audit_log::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at, self.id)
+    }
+}
+
+impl CursorKey for leave::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at, self.id)
+    }
+}
+
+impl CursorKey for leave_request::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at, self.id)
+    }
+}
+
+impl CursorKey for shift::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at, self.id)
+    }
+}
+
+impl CursorKey for calendar::Model {
+    fn cursor_key(&self) -> (NaiveDateTime, i64) {
+        (self.create_at, self.id)
+    }
 }
 
+// Applies the filter and sort of the query parameters, then fetches the requested page together
+// with its counters, or the page after the cursor when one is given.
 pub async fn sort_and_paginate<E, M, DB>(
     conn: &DB,
     select: &mut Select<E>,
     param: PageQueryParam,
     module_name: EModule,
-) -> AppResult<Vec<M>>
+) -> AppResult<Paginated<M>>
 where
     E: EntityTrait<Model = M>,
-    M: FromQueryResult + Sized + Send + Sync,
+    M: FromQueryResult + CursorKey + Sized + Send + Sync,
     DB: ConnectionTrait,
 {
-    let mut select_clone_object = select.clone();
-    if let Some(filter) = param.filter {
-        let filters = filter.split(',').collect::<Vec<_>>();
-        for item in filters {
-            let columns = get_simple_expression(item, module_name);
-            let columns_data = match columns {
-                None => {
-                    return Err(AppError::BadRequestError(
-                        "query string not correct format".to_string(),
-                    ));
-                },
-                Some(value) => value,
-            };
+    let param = PageQueryParam { page_size: check_page_size(param.page_size)?, ..param };
+    let select_clone_object = apply_filter(select.clone(), &param, module_name)?;
+    if let Some(cursor) = &param.cursor {
+        return paginate_by_cursor(conn, select_clone_object, cursor, &param, module_name).await;
+    }
+    let select_clone_object = apply_sort(select_clone_object, &param, module_name)?;
+
+    let paginator = select_clone_object.paginate(conn, param.page_size);
+    let counter = paginator.num_items_and_pages().await?;
+    let items = paginator.fetch_page(param.page_num).await?;
+    Ok(Paginated {
+        items,
+        pagination: PageResponse {
+            total_items: Some(counter.number_of_items),
+            total_pages: Some(counter.number_of_pages),
+            page_num: Some(param.page_num),
+            page_size: param.page_size,
+            ..Default::default()
+        },
+    })
+}
 
-            select_clone_object = select_clone_object.filter(columns_data);
+// An empty page can not be counted nor followed, a page is at most `MAX_PAGE_SIZE` rows long.
+fn check_page_size(page_size: u64) -> AppResult<u64> {
+    if page_size < 1 {
+        return Err(AppError::BadRequestError("The page size must be at least 1".to_string()));
+    }
+    Ok(page_size.min(MAX_PAGE_SIZE))
+}
+
+// Applies the filter of the query parameters without paginating, e.g. for an export.
+pub fn apply_filter<E: EntityTrait>(
+    mut select: Select<E>,
+    param: &PageQueryParam,
+    module_name: EModule,
+) -> AppResult<Select<E>> {
+    if let Some(filter) = &param.filter {
+        for item in filter.split(',') {
+            select = select.filter(get_simple_expression(item, module_name)?);
         }
     }
+    Ok(select)
+}
+
+fn apply_sort<E: EntityTrait>(
+    mut select: Select<E>,
+    param: &PageQueryParam,
+    module_name: EModule,
+) -> AppResult<Select<E>> {
+    for (column, order) in get_sort_columns(param, module_name)? {
+        select = select.order_by(column, order);
+    }
+    Ok(select)
+}
+
+// Position of a row in the `(created_at, id)` order of its module.
+#[derive(Debug, Serialize, Deserialize)]
+struct Cursor {
+    created_at: NaiveDateTime,
+    id: i64,
+    // Whether the cursor points to the rows before it.
+    backward: bool,
+}
+
+impl Cursor {
+    fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    fn decode(value: &str) -> AppResult<Self> {
+        URL_SAFE_NO_PAD
+            .decode(value)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| AppError::BadRequestError(format!("Invalid cursor `{value}`")))
+    }
+}
+
+// Models listed with cursor pagination expose their `(created_at, id)` key.
+pub trait CursorKey {
+    fn cursor_key(&self) -> (NaiveDateTime, i64);
+}
+
+async fn paginate_by_cursor<E, M, DB>(
+    conn: &DB,
+    select: Select<E>,
+    cursor: &str,
+    param: &PageQueryParam,
+    module_name: EModule,
+) -> AppResult<Paginated<M>>
+where
+    E: EntityTrait<Model = M>,
+    M: FromQueryResult + CursorKey + Sized + Send + Sync,
+    DB: ConnectionTrait,
+{
+    let cursor = if cursor.is_empty() { None } else { Some(Cursor::decode(cursor)?) };
+    let backward = cursor.as_ref().is_some_and(|cursor| cursor.backward);
+    let descending = matches!(param.sort_direction, Some(Direction::DESC));
+    // Reading backward walks the rows in the opposite order, they are reversed afterwards.
+    let order = if descending != backward { Order::Desc } else { Order::Asc };
+
+    let (created_at, id) = module_name.cursor_columns();
+    let mut select = select
+        .order_by(created_at.clone(), order.clone())
+        .order_by(id.clone(), order.clone())
+        .limit(param.page_size + 1);
+    if let Some(cursor) = &cursor {
+        let created_at_value = module_name.cursor_value(cursor.created_at);
+        let created_at = Expr::expr(created_at);
+        let id = Expr::expr(id);
+        let condition = match order {
+            Order::Desc => Condition::any()
+                .add(created_at.clone().lt(created_at_value.clone()))
+                .add(created_at.eq(created_at_value).and(id.lt(cursor.id))),
+            _ => Condition::any()
+                .add(created_at.clone().gt(created_at_value.clone()))
+                .add(created_at.eq(created_at_value).and(id.gt(cursor.id))),
+        };
+        select = select.filter(condition);
+    }
+
+    let mut items = select.all(conn).await?;
+    let has_more = items.len() as u64 > param.page_size;
+    items.truncate(param.page_size as usize);
+    if backward {
+        items.reverse();
+    }
 
-    select_clone_object = match param.sort_direction {
-        Some(Direction::DESC) => select_clone_object.order_by_desc(user::Column::CreatedAt),
-        _ => select_clone_object.order_by_asc(user::Column::CreatedAt),
+    let to_cursor = |item: &M, backward: bool| {
+        let (created_at, id) = item.cursor_key();
+        Cursor { created_at, id, backward }.encode()
     };
+    let (has_next, has_prev) =
+        if backward { (true, has_more) } else { (has_more, cursor.is_some()) };
+    let next_cursor = items.last().filter(|_| has_next).map(|item| to_cursor(item, false));
+    let prev_cursor = items.first().filter(|_| has_prev).map(|item| to_cursor(item, true));
+    Ok(Paginated {
+        items,
+        pagination: PageResponse {
+            page_size: param.page_size,
+            next_cursor,
+            prev_cursor,
+            ..Default::default()
+        },
+    })
+}
+
+// Parses `sort_by` as comma separated `column[:asc|desc]` items, a column without direction uses
+// `sort_direction`. The primary key is always appended so that pages stay stable.
+fn get_sort_columns(
+    param: &PageQueryParam,
+    module_name: EModule,
+) -> AppResult<Vec<(SimpleExpr, Order)>> {
+    let default_order = match param.sort_direction {
+        Some(Direction::DESC) => Order::Desc,
+        _ => Order::Asc,
+    };
+    let sort_by = param.sort_by.as_deref().unwrap_or(module_name.default_sort_column());
+
+    let mut columns = Vec::new();
+    for item in sort_by.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+        let (name, direction) = match item.split_once(':') {
+            Some((name, direction)) => (name, Some(direction)),
+            None => (item, None),
+        };
+        let order = match direction.map(str::to_lowercase).as_deref() {
+            None => default_order.clone(),
+            Some("asc") => Order::Asc,
+            Some("desc") => Order::Desc,
+            Some(direction) => {
+                return Err(AppError::BadRequestError(format!(
+                    "Invalid sort `{item}`: unknown direction `{direction}`"
+                )));
+            },
+        };
+        let (column, _) = module_name.filter_column(name).ok_or_else(|| {
+            AppError::BadRequestError(format!(
+                "Invalid sort `{item}`: column `{name}` can not be sorted"
+            ))
+        })?;
+        columns.push((column, order));
+    }
+
+    if let Some((id, _)) = module_name.filter_column("id") {
+        columns.push((id, default_order));
+    }
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    fn user_sql(filter: &str) -> AppResult<String> {
+        let param = PageQueryParam {
+            page_num: 0,
+            page_size: 10,
+            cursor: None,
+            sort_by: None,
+            sort_direction: None,
+            filter: Some(filter.to_string()),
+        };
+        let select = apply_filter(user::Entity::find(), &param, EModule::User)?;
+        Ok(select.build(DbBackend::Postgres).to_string())
+    }
+
+    #[test]
+    fn test_filter_comparison_operators() {
+        assert!(user_sql("status:eq:1").unwrap().contains(r#""status" = 1"#));
+        assert!(user_sql("status:ne:0").unwrap().contains(r#""status" <> 0"#));
+        assert!(user_sql("status:gte:1").unwrap().contains(r#""status" >= 1"#));
+        assert!(user_sql("email:eq:a@b.vn").unwrap().contains(r#""email" = 'a@b.vn'"#));
+    }
+
+    #[test]
+    fn test_filter_list_operators() {
+        assert!(user_sql("status:in:0|1").unwrap().contains(r#""status" IN (0, 1)"#));
+        let sql = user_sql("created_at:between:2024-01-01|2024-12-31").unwrap();
+        let sql = sql.replace(".000000", "");
+        let expected = r#""created_at" BETWEEN '2024-01-01 00:00:00' AND '2024-12-31 00:00:00'"#;
+        assert!(sql.contains(expected));
+    }
 
-    let models: Vec<M> =
-        select_clone_object.paginate(conn, param.page_size).fetch_page(param.page_num).await?;
-    Ok(models)
+    #[test]
+    fn test_filter_combines_clauses() {
+        let sql = user_sql("status:eq:1,full_name:starts_with:An").unwrap();
+        assert!(sql.contains(r#""status" = 1 AND "users"."full_name" LIKE 'An%'"#));
+    }
+
+    #[test]
+    fn test_filter_escapes_like_wildcards() {
+        assert_eq!(escape_like(r"50%_off\"), r"50\%\_off\\");
+        let sql = user_sql("full_name:contains:50%_off").unwrap();
+        assert!(sql.contains(r"LIKE E'%50\\%\\_off%'"));
+    }
+
+    #[test]
+    fn test_filter_is_null() {
+        assert!(user_sql("deleted_at:is_null:true").unwrap().contains(r#""deleted_at" IS NULL"#));
+        assert!(user_sql("deleted_at:is_null:false").unwrap().contains("IS NOT NULL"));
+        assert!(user_sql("deleted_at:is_null:maybe").is_err());
+    }
+
+    #[test]
+    fn test_filter_rejects_columns_outside_the_whitelist() {
+        assert!(user_sql("password:eq:secret").is_err());
+        assert!(user_sql("users.password:eq:secret").is_err());
+        assert!(get_simple_expression("email:eq:a@b.vn", EModule::Role).is_err());
+    }
+
+    #[test]
+    fn test_filter_rejects_malformed_clauses() {
+        assert!(user_sql("status").is_err());
+        assert!(user_sql("status:eq").is_err());
+        assert!(user_sql("status:like:1").is_err());
+        assert!(user_sql("status:eq:one").is_err());
+        assert!(user_sql("status:contains:1").is_err());
+        assert!(user_sql("created_at:between:2024-01-01").is_err());
+        assert!(user_sql("user_uuid:eq:not-a-uuid").is_err());
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value("42", EValueType::Integer), Ok(Value::from(42i64)));
+        assert_eq!(parse_value("true", EValueType::Bool), Ok(Value::from(true)));
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(parse_value("2024-02-29", EValueType::Date), Ok(Value::from(date)));
+        assert_eq!(
+            parse_value("2024-02-29", EValueType::DateTime),
+            Ok(Value::from(date.and_time(NaiveTime::MIN)))
+        );
+        assert!(parse_value("2024-02-30", EValueType::Date).is_err());
+        assert!(parse_value("2024-02-29T25:00:00", EValueType::DateTime).is_err());
+    }
+
+    #[test]
+    fn test_page_size_is_at_least_one_and_capped() {
+        assert!(check_page_size(0).is_err());
+        assert_eq!(check_page_size(1).ok(), Some(1));
+        assert_eq!(check_page_size(MAX_PAGE_SIZE).ok(), Some(MAX_PAGE_SIZE));
+        assert_eq!(check_page_size(MAX_PAGE_SIZE + 1).ok(), Some(MAX_PAGE_SIZE));
+        assert_eq!(check_page_size(u64::MAX).ok(), Some(MAX_PAGE_SIZE));
+    }
+
+    #[test]
+    fn test_sort_columns() {
+        let param = PageQueryParam {
+            page_num: 0,
+            page_size: 10,
+            cursor: None,
+            sort_by: Some("status:desc,email".to_string()),
+            sort_direction: None,
+            filter: None,
+        };
+        let columns = get_sort_columns(&param, EModule::User).unwrap();
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[0].1, Order::Desc);
+        assert_eq!(columns[1].1, Order::Asc);
+
+        let param = PageQueryParam { sort_by: Some("password".to_string()), ..param };
+        assert!(get_sort_columns(&param, EModule::User).is_err());
+        let param = PageQueryParam { sort_by: Some("email:up".to_string()), ..param };
+        assert!(get_sort_columns(&param, EModule::User).is_err());
+    }
 }
@@ -16,3 +16,12 @@ pub fn argon_verify(
     let parsed_hash = PasswordHash::new(hash.as_ref())?;
     Argon2::default().verify_password(content.as_ref().as_bytes(), &parsed_hash)
 }
+
+// Compares in a time that only depends on the lengths, so a guess leaks nothing about the secret.
+pub fn constant_time_eq(left: impl AsRef<[u8]>, right: impl AsRef<[u8]>) -> bool {
+    let (left, right) = (left.as_ref(), right.as_ref());
+    if left.len() != right.len() {
+        return false;
+    }
+    left.iter().zip(right).fold(0u8, |diff, (l, r)| diff | (l ^ r)) == 0
+}
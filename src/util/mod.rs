@@ -5,6 +5,7 @@ pub mod assertion;
 pub mod claim;
 pub mod constant;
 pub mod dir;
+pub mod export;
 pub mod file;
 pub mod hash;
 pub mod password;